@@ -0,0 +1,413 @@
+//! `AlertBuilder` is a builder pattern for creating alerts.
+
+use std::ffi::CString;
+
+use crate::core::internals::*;
+use crate::core::*;
+use crate::sys;
+
+use super::{Alert, AlertSeverity};
+
+/// `AlertBuilder` is a builder pattern for creating alerts.
+///
+/// The alert condition is specified with the [`QueryBuilderImpl`] API. An alert
+/// instance is created for each entity that matches the condition.
+///
+/// These are typically constructed via [`World::alert()`]
+pub struct AlertBuilder<'a, T>
+where
+    T: QueryTuple,
+{
+    desc: sys::ecs_alert_desc_t,
+    term_builder: TermBuilder,
+    strings: Vec<CString>,
+    severity_filter_count: usize,
+    world: WorldRef<'a>,
+    _phantom: std::marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> AlertBuilder<'a, T>
+where
+    T: QueryTuple,
+{
+    /// Create a new alert builder
+    pub(crate) fn new(world: &'a World) -> Self {
+        let mut obj = Self {
+            desc: Default::default(),
+            term_builder: TermBuilder::default(),
+            strings: Vec::new(),
+            severity_filter_count: 0,
+            world: world.into(),
+            _phantom: std::marker::PhantomData,
+        };
+
+        obj.desc.entity = unsafe { sys::ecs_entity_init(obj.world_ptr_mut(), &Default::default()) };
+
+        T::populate(&mut obj);
+        obj
+    }
+
+    /// Create a new alert builder with a name
+    pub(crate) fn new_named(world: &'a World, name: &str) -> Self {
+        let name = compact_str::format_compact!("{}\0", name);
+
+        let mut obj = Self {
+            desc: Default::default(),
+            term_builder: TermBuilder::default(),
+            strings: Vec::new(),
+            severity_filter_count: 0,
+            world: world.into(),
+            _phantom: std::marker::PhantomData,
+        };
+
+        let entity_desc: sys::ecs_entity_desc_t = sys::ecs_entity_desc_t {
+            name: name.as_ptr() as *const _,
+            sep: SEPARATOR.as_ptr(),
+            root_sep: SEPARATOR.as_ptr(),
+            ..Default::default()
+        };
+        obj.desc.entity = unsafe { sys::ecs_entity_init(obj.world_ptr_mut(), &entity_desc) };
+
+        T::populate(&mut obj);
+        obj
+    }
+
+    /// Store a string in the builder so that it outlives the alert description.
+    fn store_str(&mut self, value: &str) -> *const i8 {
+        let value = CString::new(value).expect("alert string contains a nul byte");
+        // the heap allocation of a `CString` does not move when the string is moved
+        let ptr = value.as_ptr();
+        self.strings.push(value);
+        ptr
+    }
+
+    /// Set the alert message template.
+    ///
+    /// The message may refer to variables in the query, for example
+    /// `"$this has Position but not Velocity"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message template.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::message`
+    #[doc(alias = "alert_builder_i::message")]
+    pub fn message(&mut self, message: &str) -> &mut Self {
+        self.desc.message = self.store_str(message);
+        self
+    }
+
+    /// Set the brief description of the alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `brief` - The description.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::brief`
+    #[doc(alias = "alert_builder_i::brief")]
+    pub fn brief(&mut self, brief: &str) -> &mut Self {
+        self.desc.brief = self.store_str(brief);
+        self
+    }
+
+    /// Set the human-readable name of the alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc_name` - The name.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::doc_name`
+    #[doc(alias = "alert_builder_i::doc_name")]
+    pub fn doc_name(&mut self, doc_name: &str) -> &mut Self {
+        self.desc.doc_name = self.store_str(doc_name);
+        self
+    }
+
+    /// Set the default severity of the alert. Defaults to [`AlertSeverity::Error`].
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The severity.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::severity`
+    #[doc(alias = "alert_builder_i::severity")]
+    pub fn severity(&mut self, severity: AlertSeverity) -> &mut Self {
+        self.severity_id(severity)
+    }
+
+    /// Set the default severity of the alert by entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The severity entity.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::severity`
+    #[doc(alias = "alert_builder_i::severity")]
+    pub fn severity_id(&mut self, severity: impl Into<Entity>) -> &mut Self {
+        self.desc.severity = *severity.into();
+        self
+    }
+
+    /// Set how long an alert must be inactive before it is cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The retain period, in seconds.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::retain_period`
+    #[doc(alias = "alert_builder_i::retain_period")]
+    pub fn retain_period(&mut self, period: FTime) -> &mut Self {
+        self.desc.retain_period = period;
+        self
+    }
+
+    /// Add a severity filter. When the matched entity has the specified id, the
+    /// alert uses the severity of the filter instead of the default severity.
+    ///
+    /// At most four severity filters can be added to an alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The severity to use when the filter matches.
+    /// * `with` - The (component) id to match.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::severity_filter`
+    #[doc(alias = "alert_builder_i::severity_filter")]
+    pub fn severity_filter_id(
+        &mut self,
+        severity: impl Into<Entity>,
+        with: impl IntoId,
+    ) -> &mut Self {
+        ecs_assert!(
+            self.severity_filter_count < sys::ECS_ALERT_MAX_SEVERITY_FILTERS as usize,
+            FlecsErrorCode::InvalidParameter,
+            "Maximum number of severity filters reached"
+        );
+
+        let filter = &mut self.desc.severity_filters[self.severity_filter_count];
+        filter.severity = *severity.into();
+        filter.with = *with.into();
+        self.severity_filter_count += 1;
+        self
+    }
+
+    /// Add a severity filter. When the matched entity has component `C`, the
+    /// alert uses the severity of the filter instead of the default severity.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The component or pair to match.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The severity to use when the filter matches.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::severity_filter`
+    #[doc(alias = "alert_builder_i::severity_filter")]
+    pub fn severity_filter<C: ComponentOrPairId>(&mut self, severity: AlertSeverity) -> &mut Self {
+        let world = self.world;
+        self.severity_filter_id(severity, C::get_id(world))
+    }
+
+    /// Add a severity filter that matches the specified id on a query variable
+    /// instead of on `$this`.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The severity to use when the filter matches.
+    /// * `with` - The (component) id to match.
+    /// * `var` - The name of the query variable, without the `$` character.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::severity_filter`
+    #[doc(alias = "alert_builder_i::severity_filter")]
+    pub fn severity_filter_var(
+        &mut self,
+        severity: impl Into<Entity>,
+        with: impl IntoId,
+        var: &str,
+    ) -> &mut Self {
+        self.severity_filter_id(severity, with);
+        let var = self.store_str(var);
+        self.desc.severity_filters[self.severity_filter_count - 1].var = var;
+        self
+    }
+
+    /// Alert when the value of a member is out of range.
+    ///
+    /// The alert uses the warning and error ranges of the member, which can be
+    /// set with [`Component::warning_range()`] and [`Component::error_range()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - The member entity.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::member`
+    #[doc(alias = "alert_builder_i::member")]
+    pub fn member_id(&mut self, member: impl Into<Entity>) -> &mut Self {
+        self.desc.member = *member.into();
+        self
+    }
+
+    /// Alert when the value of a member of component `C` is out of range.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The component that has the member.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field in `C`.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::member`
+    #[doc(alias = "alert_builder_i::member")]
+    pub fn member<C: ComponentId>(&mut self, name: &str) -> &mut Self {
+        let world = self.world;
+        let id = C::id(world);
+        let member = EntityView::new_from(world, id)
+            .try_lookup(name)
+            .map(|e| e.id);
+
+        ecs_assert!(
+            member.is_some(),
+            FlecsErrorCode::InvalidParameter,
+            "member '{}' not found in type '{}'",
+            name,
+            std::any::type_name::<C>()
+        );
+
+        if let Some(member) = member {
+            self.desc.id = id;
+            self.member_id(member);
+        }
+        self
+    }
+
+    /// Set the (component) id of the member to monitor. If not set, this
+    /// defaults to the parent entity of the member.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The (component) id.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::id`
+    #[doc(alias = "alert_builder_i::id")]
+    pub fn member_of_id(&mut self, id: impl IntoId) -> &mut Self {
+        self.desc.id = *id.into();
+        self
+    }
+
+    /// Set the query variable from which to fetch the member. If not set, the
+    /// member is fetched from `$this`.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - The name of the query variable, without the `$` character.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `alert_builder_i::var`
+    #[doc(alias = "alert_builder_i::var")]
+    pub fn member_var(&mut self, var: &str) -> &mut Self {
+        self.desc.var = self.store_str(var);
+        self
+    }
+}
+
+#[doc(hidden)]
+impl<'a, T: QueryTuple> internals::QueryConfig<'a> for AlertBuilder<'a, T> {
+    #[inline(always)]
+    fn term_builder(&self) -> &TermBuilder {
+        &self.term_builder
+    }
+
+    #[inline(always)]
+    fn term_builder_mut(&mut self) -> &mut TermBuilder {
+        &mut self.term_builder
+    }
+
+    #[inline(always)]
+    fn query_desc(&self) -> &sys::ecs_query_desc_t {
+        &self.desc.filter
+    }
+
+    #[inline(always)]
+    fn query_desc_mut(&mut self) -> &mut sys::ecs_query_desc_t {
+        &mut self.desc.filter
+    }
+    #[inline(always)]
+    fn count_generic_terms(&self) -> i32 {
+        T::COUNT
+    }
+}
+
+impl<'a, T: QueryTuple> TermBuilderImpl<'a> for AlertBuilder<'a, T> {}
+
+impl<'a, T: QueryTuple> QueryBuilderImpl<'a> for AlertBuilder<'a, T> {}
+
+impl<'a, T> Builder<'a> for AlertBuilder<'a, T>
+where
+    T: QueryTuple,
+{
+    type BuiltType = Alert<'a>;
+
+    /// Build the `AlertBuilder` into an alert
+    ///
+    /// See also
+    ///
+    /// * C API: `ecs_alert_init`
+    #[doc(alias = "ecs_alert_init")]
+    fn build(&mut self) -> Self::BuiltType {
+        let world = self.world;
+        let entity = unsafe { sys::ecs_alert_init(world.world_ptr_mut(), &self.desc) };
+
+        ecs_assert!(
+            entity != 0,
+            FlecsErrorCode::InvalidParameter,
+            "failed to create alert"
+        );
+
+        for string_parts in self.term_builder.str_ptrs_to_free.iter() {
+            unsafe {
+                String::from_raw_parts(
+                    string_parts.ptr as *mut u8,
+                    string_parts.len,
+                    string_parts.capacity,
+                );
+            }
+        }
+
+        Alert {
+            entity: EntityView::new_from(world, entity),
+        }
+    }
+}
+
+impl<'a, T: QueryTuple> WorldProvider<'a> for AlertBuilder<'a, T> {
+    fn world(&self) -> WorldRef<'a> {
+        self.world
+    }
+}
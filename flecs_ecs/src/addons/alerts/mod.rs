@@ -0,0 +1,500 @@
+//! Alerts are entities that are created for entities matching a query, for example
+//! to detect invalid or unexpected states in the world.
+//!
+//! Alerts are created with an [`AlertBuilder`], which is typically obtained
+//! through [`World::alert()`]. The alert condition is specified with the regular
+//! query builder API. For each entity matching the condition an alert instance is
+//! created, which is a child entity of the alert that stores the generated
+//! [`message`](AlertInstance::message) and the [`source`](AlertInstance::source)
+//! entity that raised the alert. Alerts are evaluated periodically when the world
+//! is progressed.
+//!
+//! The active alerts of an entity can be inspected with [`EntityView::each_alert()`].
+//! Rust closures can be invoked when an alert becomes active or clears with
+//! [`Alert::on_start()`] and [`Alert::on_clear()`].
+//!
+//! Like the [`metrics`](crate::addons::metrics) addon, the alerts addon identifies
+//! its builtin components by ids that are shared between worlds. When using more
+//! than one world, import the [`Alerts`] module before creating other entities or
+//! components.
+
+mod alert_builder;
+
+pub use alert_builder::*;
+
+use std::ffi::CStr;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::core::*;
+use crate::sys;
+
+#[cfg(feature = "flecs_module")]
+use super::module::Module;
+
+/// The severity of an alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlertSeverity {
+    /// Informational alert.
+    Info,
+    /// Warning alert.
+    Warning,
+    /// Error alert. This is the default severity of an alert.
+    Error,
+    /// Critical alert.
+    Critical,
+}
+
+impl AlertSeverity {
+    /// Returns the entity that represents this alert severity.
+    ///
+    /// The alerts module must be imported for the returned entity to be valid.
+    pub fn id(self) -> Entity {
+        Entity::new(unsafe {
+            match self {
+                AlertSeverity::Info => sys::EcsAlertInfo,
+                AlertSeverity::Warning => sys::EcsAlertWarning,
+                AlertSeverity::Error => sys::EcsAlertError,
+                AlertSeverity::Critical => sys::EcsAlertCritical,
+            }
+        })
+    }
+
+    /// Returns the alert severity that is represented by the entity, if any.
+    pub fn from_id(id: impl Into<Entity>) -> Option<Self> {
+        let id = *id.into();
+        [
+            AlertSeverity::Info,
+            AlertSeverity::Warning,
+            AlertSeverity::Error,
+            AlertSeverity::Critical,
+        ]
+        .into_iter()
+        .find(|severity| *severity.id() == id)
+    }
+}
+
+impl From<AlertSeverity> for Entity {
+    #[inline]
+    fn from(severity: AlertSeverity) -> Self {
+        severity.id()
+    }
+}
+
+/// [`Alert`] is a wrapper around an entity that was created with [`AlertBuilder::build()`].
+#[derive(Clone, Copy)]
+pub struct Alert<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'a> Deref for Alert<'a> {
+    type Target = EntityView<'a>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.entity
+    }
+}
+
+impl<'a> DerefMut for Alert<'a> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.entity
+    }
+}
+
+impl<'a> From<Alert<'a>> for Entity {
+    #[inline]
+    fn from(alert: Alert<'a>) -> Self {
+        alert.id
+    }
+}
+
+impl<'a> Alert<'a> {
+    /// Create a new alert handle from an existing alert entity.
+    pub fn new_from(world: impl WorldProvider<'a>, entity: impl Into<Entity>) -> Self {
+        let entity = EntityView::new_from(world, entity);
+        ecs_assert!(
+            entity.has_id(unsafe { sys::FLECS_IDEcsAlertID_ }),
+            FlecsErrorCode::InvalidParameter,
+            "Entity is not an alert"
+        );
+        Alert { entity }
+    }
+
+    /// Returns the default severity of the alert.
+    pub fn severity(&self) -> AlertSeverity {
+        let world = self.entity.world;
+        let severity = unsafe {
+            sys::ecs_get_target(
+                world.world_ptr(),
+                *self.entity.id,
+                sys::FLECS_IDEcsAlertID_,
+                0,
+            )
+        };
+        AlertSeverity::from_id(severity).unwrap_or(AlertSeverity::Error)
+    }
+
+    /// Iterate the instances of this alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked for each alert instance.
+    pub fn each_instance(&self, mut func: impl FnMut(AlertInstance<'a>)) {
+        let world = self.entity.world;
+        self.entity.each_child(|child| {
+            if child.has::<flecs::alerts::Instance>() {
+                func(AlertInstance {
+                    entity: EntityView::new_from(world, child.id),
+                });
+            }
+        });
+    }
+
+    /// Returns the number of instances of this alert.
+    pub fn instance_count(&self) -> usize {
+        let mut count = 0;
+        self.each_instance(|_| count += 1);
+        count
+    }
+
+    /// Invoke a closure when this alert becomes active for an entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked with the new alert instance.
+    ///
+    /// # Returns
+    ///
+    /// The observer that invokes the closure.
+    pub fn on_start(&self, mut func: impl FnMut(AlertInstance) + 'static) -> Observer<'a> {
+        ObserverBuilder::<flecs::OnSet, &flecs::metrics::Source>::new(self.entity.world)
+            .with::<flecs::alerts::Instance>()
+            .filter()
+            .with_first::<flecs::ChildOf>(self.entity.id)
+            .filter()
+            .each_entity(move |entity, _| func(AlertInstance { entity }))
+    }
+
+    /// Invoke a closure when this alert clears for an entity.
+    ///
+    /// An alert clears when its instance is removed, which happens when the
+    /// source entity no longer matches the alert (after the retain period, if
+    /// one was set), or when the source entity is deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked with the alert instance that is removed.
+    ///
+    /// # Returns
+    ///
+    /// The observer that invokes the closure.
+    pub fn on_clear(&self, mut func: impl FnMut(AlertInstance) + 'static) -> Observer<'a> {
+        ObserverBuilder::<flecs::OnRemove, &flecs::alerts::Instance>::new(self.entity.world)
+            .with_first::<flecs::ChildOf>(self.entity.id)
+            .filter()
+            .each_entity(move |entity, _| func(AlertInstance { entity }))
+    }
+}
+
+/// [`AlertInstance`] is a wrapper around an alert instance entity, which is created
+/// for each entity that matches an alert.
+#[derive(Clone, Copy)]
+pub struct AlertInstance<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'a> Deref for AlertInstance<'a> {
+    type Target = EntityView<'a>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.entity
+    }
+}
+
+impl<'a> From<AlertInstance<'a>> for Entity {
+    #[inline]
+    fn from(instance: AlertInstance<'a>) -> Self {
+        instance.id
+    }
+}
+
+impl<'a> AlertInstance<'a> {
+    /// Returns the message generated from the message template of the alert.
+    ///
+    /// The message is generated when the alert is evaluated, so it will not be
+    /// available until the frame after the instance was created.
+    pub fn message(&self) -> Option<String> {
+        let world = self.entity.world;
+        let instance = unsafe {
+            sys::ecs_get_id(
+                world.world_ptr(),
+                *self.entity.id,
+                sys::FLECS_IDEcsAlertInstanceID_,
+            ) as *const flecs::alerts::Instance
+        };
+
+        if instance.is_null() || unsafe { (*instance).message.is_null() } {
+            return None;
+        }
+
+        let message = unsafe { CStr::from_ptr((*instance).message) };
+        Some(message.to_string_lossy().into_owned())
+    }
+
+    /// Returns the entity that raised the alert.
+    pub fn source(&self) -> EntityView<'a> {
+        let source = self
+            .entity
+            .try_cloned::<&flecs::metrics::Source>()
+            .map(|s| s.entity)
+            .unwrap_or_default();
+        EntityView::new_from(self.entity.world, source)
+    }
+
+    /// Returns the current severity of the alert instance.
+    pub fn severity(&self) -> AlertSeverity {
+        let world = self.entity.world;
+        let severity = unsafe {
+            sys::ecs_get_target(
+                world.world_ptr(),
+                *self.entity.id,
+                sys::FLECS_IDEcsAlertID_,
+                0,
+            )
+        };
+        AlertSeverity::from_id(severity).unwrap_or(AlertSeverity::Error)
+    }
+
+    /// Returns how long (in seconds) the alert has been active.
+    pub fn duration(&self) -> f64 {
+        self.entity
+            .try_cloned::<&flecs::metrics::Value>()
+            .map(|v| v.value)
+            .unwrap_or_default()
+    }
+
+    /// Returns the alert this instance belongs to.
+    pub fn alert(&self) -> Alert<'a> {
+        let world = self.entity.world;
+        let parent = unsafe {
+            sys::ecs_get_target(world.world_ptr(), *self.entity.id, flecs::ChildOf::ID, 0)
+        };
+        Alert {
+            entity: EntityView::new_from(world, parent),
+        }
+    }
+}
+
+impl<'a> EntityView<'a> {
+    /// Returns the number of active alerts for the entity.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `entity_view::alert_count`
+    #[doc(alias = "entity_view::alert_count")]
+    pub fn alert_count(self) -> i32 {
+        unsafe { sys::ecs_get_alert_count(self.world.world_ptr(), *self.id, 0) }
+    }
+
+    /// Returns whether the specified alert is active for the entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `alert` - The alert to test for.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `entity_view::alert_count`
+    #[doc(alias = "entity_view::alert_count")]
+    pub fn has_alert(self, alert: impl Into<Entity>) -> bool {
+        unsafe { sys::ecs_get_alert_count(self.world.world_ptr(), *self.id, *alert.into()) != 0 }
+    }
+
+    /// Returns the instance of the specified alert for the entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `alert` - The alert to get the instance for.
+    ///
+    /// # Returns
+    ///
+    /// The alert instance if the alert is active for the entity, otherwise `None`.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_get_alert`
+    #[doc(alias = "ecs_get_alert")]
+    pub fn try_alert(self, alert: impl Into<Entity>) -> Option<AlertInstance<'a>> {
+        let instance =
+            unsafe { sys::ecs_get_alert(self.world.world_ptr(), *self.id, *alert.into()) };
+        if instance == 0 {
+            return None;
+        }
+        Some(AlertInstance {
+            entity: EntityView::new_from(self.world, instance),
+        })
+    }
+
+    /// Iterate the active alerts of the entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked for each active alert instance.
+    pub fn each_alert(self, mut func: impl FnMut(AlertInstance<'a>)) {
+        let world = self.world;
+        let active = unsafe {
+            sys::ecs_get_id(world.world_ptr(), *self.id, sys::FLECS_IDEcsAlertsActiveID_)
+                as *const flecs::alerts::AlertsActive
+        };
+
+        if active.is_null() {
+            return;
+        }
+
+        let mut instances = Vec::new();
+        unsafe {
+            let mut it = sys::ecs_map_iter(&(*active).alerts);
+            while sys::ecs_map_next(&mut it) {
+                // the map value is the alert instance, the key is the alert
+                instances.push(*it.res.add(1));
+            }
+        }
+
+        for instance in instances {
+            func(AlertInstance {
+                entity: EntityView::new_from(world, instance),
+            });
+        }
+    }
+}
+
+/// Alerts mixin implementation
+impl World {
+    /// Create a new alert builder for a new entity.
+    ///
+    /// This imports the [`Alerts`] module if it has not been imported yet.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Components` - The components to match on.
+    ///
+    /// # See also
+    ///
+    /// * [`addons::alerts`](crate::addons::alerts)
+    /// * C++ API: `world::alert`
+    #[doc(alias = "world::alert")]
+    pub fn alert<Components>(&self) -> AlertBuilder<'_, Components>
+    where
+        Components: QueryTuple,
+    {
+        self.import::<Alerts>();
+        AlertBuilder::<Components>::new(self)
+    }
+
+    /// Create a new alert builder for a new named entity.
+    ///
+    /// This imports the [`Alerts`] module if it has not been imported yet.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Components` - The components to match on.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the alert.
+    ///
+    /// # See also
+    ///
+    /// * [`addons::alerts`](crate::addons::alerts)
+    /// * C++ API: `world::alert`
+    #[doc(alias = "world::alert")]
+    pub fn alert_named<'a, Components>(&'a self, name: &str) -> AlertBuilder<'a, Components>
+    where
+        Components: QueryTuple,
+    {
+        self.import::<Alerts>();
+        AlertBuilder::<Components>::new_named(self, name)
+    }
+}
+
+/// Module that imports the alerts addon.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Alerts;
+
+#[cfg(feature = "flecs_module")]
+impl Module for Alerts {
+    fn module(world: &World) {
+        // Import the C module (which imports the C metrics module) before the
+        // module is moved to its Rust path, the same way `Metrics` does. See
+        // `World::import_c_module`.
+        world.import_c_module(Some(sys::FlecsAlertsImport), c"FlecsAlerts", unsafe {
+            sys::FLECS_IDFlecsAlertsID_
+        });
+        world.import::<super::metrics::Metrics>();
+        world.module::<Alerts>("flecs::rust::alerts");
+        world.component::<flecs::alerts::Instance>();
+        world.component::<flecs::alerts::AlertsActive>();
+    }
+}
+
+///////////////////////////
+// trait implementations
+///////////////////////////
+
+impl flecs_ecs::core::DataComponent for Alerts {}
+
+impl flecs_ecs::core::ComponentType<flecs_ecs::core::Struct> for Alerts {}
+
+impl flecs_ecs::core::component_registration::registration_traits::ComponentInfo for Alerts {
+    const IS_GENERIC: bool = false;
+    const IS_ENUM: bool = false;
+    const IS_TAG: bool = false;
+    type TagType =
+        flecs_ecs::core::component_registration::registration_traits::FlecsFirstIsNotATag;
+    const IMPLS_CLONE: bool = true;
+    const IMPLS_DEFAULT: bool = true;
+    const IS_REF: bool = false;
+    const IS_MUT: bool = false;
+}
+impl flecs_ecs::core::component_registration::registration_traits::ComponentId for Alerts
+where
+    Self: 'static,
+{
+    type UnderlyingType = Alerts;
+    type UnderlyingEnumType = flecs_ecs::core::component_registration::NoneEnum;
+    #[inline(always)]
+    fn index() -> u32 {
+        static INDEX: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(u32::MAX);
+        Self::get_or_init_index(&INDEX)
+    }
+    fn __register_lifecycle_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        flecs_ecs::core::lifecycle_traits::register_lifecycle_actions::<Alerts>(type_hooks);
+    }
+    fn __register_default_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_DEFAULT: bool = Alerts::IMPLS_DEFAULT;
+        if IMPLS_DEFAULT {
+            flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,Alerts>as flecs_ecs::core::component_registration::registration_traits::FlecsDefaultType> ::Type, >(type_hooks);
+        }
+    }
+    fn __register_clone_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_CLONE: bool = Alerts::IMPLS_CLONE;
+        if IMPLS_CLONE {
+            flecs_ecs::core::lifecycle_traits::register_copy_lifecycle_action:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_CLONE,Alerts>as flecs_ecs::core::component_registration::registration_traits::FlecsCloneType> ::Type, >(type_hooks);
+        } else {
+            flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<Alerts>(
+                type_hooks,
+            );
+        }
+    }
+
+    fn __register_or_get_id<'a, const MANUAL_REGISTRATION_CHECK: bool>(
+        world: impl WorldProvider<'a>,
+    ) -> sys::ecs_entity_t {
+        Self::__register_or_get_id_named::<MANUAL_REGISTRATION_CHECK>(world, "flecs::alerts")
+    }
+}
@@ -0,0 +1,687 @@
+//! The HTTP addon provides a minimal HTTP server that runs in the same process
+//! as the world.
+//!
+//! An [`HttpServer`] dispatches requests to Rust closures registered with
+//! [`HttpServer::route()`]. Requests are received on a background thread, but
+//! handlers are only invoked from [`HttpServer::dequeue()`], which is called
+//! each frame by [`World::progress()`] once the server is started. This means
+//! that handlers can safely access the world.
+//!
+//! With the `flecs_rest` feature, requests that don't match a route can be
+//! forwarded to the REST endpoints with [`HttpServer::with_rest()`], so that
+//! project specific endpoints can run on the same port as the explorer API.
+
+use std::ffi::{c_void, CStr, CString};
+
+use crate::core::*;
+use crate::sys;
+
+/// HTTP request method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Options,
+}
+
+impl HttpMethod {
+    fn from_raw(method: sys::ecs_http_method_t) -> Option<Self> {
+        match method {
+            sys::ecs_http_method_t_EcsHttpGet => Some(HttpMethod::Get),
+            sys::ecs_http_method_t_EcsHttpPost => Some(HttpMethod::Post),
+            sys::ecs_http_method_t_EcsHttpPut => Some(HttpMethod::Put),
+            sys::ecs_http_method_t_EcsHttpDelete => Some(HttpMethod::Delete),
+            sys::ecs_http_method_t_EcsHttpOptions => Some(HttpMethod::Options),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the method as used in a request, for example `"GET"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Options => "OPTIONS",
+        }
+    }
+}
+
+/// A request received by an [`HttpServer`].
+pub struct HttpRequest<'a> {
+    req: &'a sys::ecs_http_request_t,
+}
+
+impl<'a> HttpRequest<'a> {
+    pub(crate) fn new(req: &'a sys::ecs_http_request_t) -> Self {
+        HttpRequest { req }
+    }
+
+    fn to_str(ptr: *const std::ffi::c_char) -> Option<&'a str> {
+        if ptr.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(ptr).to_str().ok() }
+        }
+    }
+
+    /// Returns the id of the request.
+    pub fn id(&self) -> u64 {
+        self.req.id
+    }
+
+    /// Returns the request method, or `None` if the method is not supported.
+    pub fn method(&self) -> Option<HttpMethod> {
+        HttpMethod::from_raw(self.req.method)
+    }
+
+    /// Returns the decoded request path, without the leading `/` and without
+    /// the query parameters.
+    pub fn path(&self) -> &'a str {
+        Self::to_str(self.req.path).unwrap_or("")
+    }
+
+    /// Returns the request body, if any.
+    pub fn body(&self) -> Option<&'a str> {
+        Self::to_str(self.req.body).filter(|body| !body.is_empty())
+    }
+
+    /// Find a header in the request.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_get_header`
+    #[doc(alias = "ecs_http_get_header")]
+    pub fn header(&self, name: &str) -> Option<&'a str> {
+        let name = compact_str::format_compact!("{}\0", name);
+        Self::to_str(unsafe { sys::ecs_http_get_header(self.req, name.as_ptr() as *const _) })
+    }
+
+    /// Find a query parameter in the request. The returned value is decoded.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_get_param`
+    #[doc(alias = "ecs_http_get_param")]
+    pub fn param(&self, name: &str) -> Option<&'a str> {
+        let name = compact_str::format_compact!("{}\0", name);
+        Self::to_str(unsafe { sys::ecs_http_get_param(self.req, name.as_ptr() as *const _) })
+    }
+
+    /// Iterate the headers of the request as `(name, value)` pairs.
+    pub fn headers(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        let req = self.req;
+        req.headers[..req.header_count as usize]
+            .iter()
+            .filter_map(|kv| Some((Self::to_str(kv.key)?, Self::to_str(kv.value)?)))
+    }
+
+    /// Iterate the query parameters of the request as `(name, value)` pairs.
+    pub fn params(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        let req = self.req;
+        req.params[..req.param_count as usize]
+            .iter()
+            .filter_map(|kv| Some((Self::to_str(kv.key)?, Self::to_str(kv.value)?)))
+    }
+}
+
+/// A reply to an HTTP request.
+///
+/// Replies are returned by route handlers and by [`HttpServer::request()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpReply {
+    /// The HTTP status code. Defaults to `200`.
+    pub code: i32,
+    /// The HTTP status message. Defaults to `"OK"`.
+    pub status: String,
+    /// The content type of the body. Defaults to `"application/json"`.
+    pub content_type: String,
+    /// Additional headers as `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+    /// The body of the reply.
+    pub body: String,
+}
+
+impl Default for HttpReply {
+    fn default() -> Self {
+        HttpReply {
+            code: 200,
+            status: "OK".to_string(),
+            content_type: "application/json".to_string(),
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    }
+}
+
+impl HttpReply {
+    /// Create a reply with the specified status code and status message.
+    pub fn new(code: i32, status: &str) -> Self {
+        HttpReply {
+            code,
+            status: status.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a `200 OK` reply with the specified body.
+    pub fn ok(body: impl Into<String>) -> Self {
+        HttpReply {
+            body: body.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a `404 Not Found` reply.
+    pub fn not_found() -> Self {
+        Self::new(404, "Resource not found")
+    }
+
+    /// Set the content type of the reply.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = content_type.to_string();
+        self
+    }
+
+    /// Add a header to the reply.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Set the body of the reply.
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Returns whether the status code indicates success (2xx).
+    pub fn is_ok(&self) -> bool {
+        (200..300).contains(&self.code)
+    }
+
+    /// Create a reply from a C reply, freeing the buffers of the C reply.
+    pub(crate) fn from_raw(reply: &mut sys::ecs_http_reply_t) -> Self {
+        unsafe fn c_str(ptr: *const std::ffi::c_char) -> String {
+            if ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        }
+
+        unsafe fn strbuf(buf: &mut sys::ecs_strbuf_t) -> String {
+            let ptr = sys::ecs_strbuf_get(buf);
+            if ptr.is_null() {
+                return String::new();
+            }
+            let value = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+            sys::ecs_os_api.free_.expect("os api is missing")(ptr as *mut c_void);
+            value
+        }
+
+        unsafe {
+            let headers = strbuf(&mut reply.headers)
+                .split("\r\n")
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .collect();
+
+            HttpReply {
+                code: reply.code,
+                status: c_str(reply.status),
+                content_type: c_str(reply.content_type),
+                headers,
+                body: strbuf(&mut reply.body),
+            }
+        }
+    }
+
+    /// Write the reply to a C reply. The status and content type strings are
+    /// stored in `strings`, which must outlive the C reply.
+    fn write_raw(&self, reply: &mut sys::ecs_http_reply_t, strings: &mut Vec<CString>) {
+        let mut store = |value: &str| {
+            let value = CString::new(value).unwrap_or_default();
+            // the heap allocation of a `CString` does not move when the string is moved
+            let ptr = value.as_ptr();
+            strings.push(value);
+            ptr
+        };
+
+        reply.code = self.code;
+        reply.status = store(&self.status);
+        reply.content_type = store(&self.content_type);
+
+        unsafe {
+            sys::ecs_strbuf_appendstrn(
+                &mut reply.body,
+                self.body.as_ptr() as *const _,
+                self.body.len() as i32,
+            );
+
+            for (name, value) in &self.headers {
+                let header = format!("{}: {}\r\n", name, value);
+                sys::ecs_strbuf_appendstrn(
+                    &mut reply.headers,
+                    header.as_ptr() as *const _,
+                    header.len() as i32,
+                );
+            }
+        }
+    }
+}
+
+type RouteHandler = Box<dyn FnMut(&HttpRequest, &World) -> HttpReply>;
+
+struct Route {
+    method: HttpMethod,
+    path_prefix: String,
+    handler: RouteHandler,
+}
+
+struct HttpServerCtx {
+    world: *mut sys::ecs_world_t,
+    server: *mut sys::ecs_http_server_t,
+    rest_server: *mut sys::ecs_http_server_t,
+    routes: Vec<Route>,
+    reply_strings: Vec<CString>,
+    is_running: bool,
+    #[cfg(feature = "flecs_pipeline")]
+    dequeue_system: Entity,
+}
+
+/// Returns whether a part of a request line contains a `\r` or `\n`, which
+/// would end the request line and turn the rest into headers or requests.
+pub(crate) fn has_line_break(value: &str) -> bool {
+    value.contains(['\r', '\n'])
+}
+
+/// Percent-encode a string for use in a request line, leaving the characters in
+/// `keep` as is.
+fn url_encode(value: &str, keep: &[u8]) -> String {
+    let mut result = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) || keep.contains(&byte) {
+            result.push(byte as char);
+        } else {
+            result.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    result
+}
+
+unsafe extern "C" fn http_server_reply(
+    request: *const sys::ecs_http_request_t,
+    reply: *mut sys::ecs_http_reply_t,
+    ctx: *mut c_void,
+) -> bool {
+    let ctx = &mut *(ctx as *mut HttpServerCtx);
+    let request = &*request;
+    let reply = &mut *reply;
+    let req = HttpRequest::new(request);
+
+    // the strings of the previous reply have been sent at this point
+    ctx.reply_strings.clear();
+
+    if let Some(method) = req.method() {
+        let path = req.path();
+        let route = ctx
+            .routes
+            .iter_mut()
+            .find(|route| route.method == method && path.starts_with(&route.path_prefix));
+
+        if let Some(route) = route {
+            let world = WorldRef::from_ptr(ctx.world);
+            let result = (route.handler)(&req, &world);
+            result.write_raw(reply, &mut ctx.reply_strings);
+            return true;
+        }
+    }
+
+    if !ctx.rest_server.is_null() {
+        return forward_to_rest(ctx.rest_server, &req, reply);
+    }
+
+    false
+}
+
+/// Forward a request that did not match a route to the REST server.
+unsafe fn forward_to_rest(
+    rest_server: *mut sys::ecs_http_server_t,
+    req: &HttpRequest,
+    reply: &mut sys::ecs_http_reply_t,
+) -> bool {
+    let Some(method) = req.method() else {
+        return false;
+    };
+
+    let mut request = format!("{} /{}", method.as_str(), url_encode(req.path(), b"/"));
+    for (i, (name, value)) in req.params().enumerate() {
+        request.push(if i == 0 { '?' } else { '&' });
+        request.push_str(&url_encode(name, b""));
+        request.push('=');
+        request.push_str(&url_encode(value, b""));
+    }
+    request.push_str(" HTTP/1.1\r\n");
+
+    let body = req.body().unwrap_or("");
+    for (name, value) in req.headers() {
+        if !name.eq_ignore_ascii_case("Content-Length") {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+    }
+    request.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+
+    sys::ecs_http_server_http_request(
+        rest_server,
+        request.as_ptr() as *const _,
+        request.len() as sys::ecs_size_t,
+        reply,
+    );
+
+    reply.code != 404
+}
+
+/// An HTTP server that dispatches requests to Rust closures.
+///
+/// The server is stopped and destroyed when the `HttpServer` is dropped.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::http::*;
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+/// world.entity_named("Sun");
+///
+/// let mut server = world.http_server(27751);
+/// server.route(HttpMethod::Get, "/count", |req, world| {
+///     HttpReply::ok(format!("{}", world.count_id(flecs::Wildcard::ID)))
+/// });
+///
+/// // requests can be dispatched without starting the server
+/// let reply = server.request(HttpMethod::Get, "/count", None);
+/// assert_eq!(reply.code, 200);
+/// ```
+pub struct HttpServer<'a> {
+    world: WorldRef<'a>,
+    ctx: *mut HttpServerCtx,
+}
+
+impl<'a> HttpServer<'a> {
+    /// Create a new HTTP server for the world. The server does not accept
+    /// connections until [`HttpServer::start()`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - The port to listen on.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_server_init`
+    #[doc(alias = "ecs_http_server_init")]
+    pub fn new(world: impl WorldProvider<'a>, port: u16) -> Self {
+        let world = world.world();
+        let ctx = Box::into_raw(Box::new(HttpServerCtx {
+            world: world.real_world().ptr_mut(),
+            server: std::ptr::null_mut(),
+            rest_server: std::ptr::null_mut(),
+            routes: Vec::new(),
+            reply_strings: Vec::new(),
+            is_running: false,
+            #[cfg(feature = "flecs_pipeline")]
+            dequeue_system: Entity::null(),
+        }));
+
+        let desc = sys::ecs_http_server_desc_t {
+            callback: Some(http_server_reply),
+            ctx: ctx as *mut c_void,
+            port,
+            ..Default::default()
+        };
+
+        let server = unsafe { sys::ecs_http_server_init(&desc) };
+        ecs_assert!(
+            !server.is_null(),
+            FlecsErrorCode::InternalError,
+            "failed to create HTTP server"
+        );
+        unsafe { (*ctx).server = server };
+
+        HttpServer { world, ctx }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    fn ctx(&self) -> &mut HttpServerCtx {
+        unsafe { &mut *self.ctx }
+    }
+
+    /// Add a route. Requests with the specified method of which the path starts
+    /// with `path_prefix` are passed to `handler`. Routes are matched in the
+    /// order in which they were added.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method of the request.
+    /// * `path_prefix` - The prefix of the request path, for example `"/debug/"`.
+    /// * `handler` - The function that creates the reply.
+    pub fn route(
+        &mut self,
+        method: HttpMethod,
+        path_prefix: &str,
+        handler: impl FnMut(&HttpRequest, &World) -> HttpReply + 'static,
+    ) -> &mut Self {
+        self.ctx().routes.push(Route {
+            method,
+            path_prefix: path_prefix.trim_start_matches('/').to_string(),
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    /// Forward requests that don't match a route to the REST endpoints of the
+    /// world, so that routes can run on the same port as the REST API.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_rest_server_init`
+    #[cfg(feature = "flecs_rest")]
+    #[doc(alias = "ecs_rest_server_init")]
+    pub fn with_rest(&mut self) -> &mut Self {
+        let ctx = self.ctx();
+        if ctx.rest_server.is_null() {
+            ctx.rest_server = unsafe { sys::ecs_rest_server_init(ctx.world, &Default::default()) };
+            ecs_assert!(
+                !ctx.rest_server.is_null(),
+                FlecsErrorCode::InternalError,
+                "failed to create REST server"
+            );
+        }
+        self
+    }
+
+    /// Start the server. After this operation the server accepts connections.
+    ///
+    /// When the `flecs_pipeline` feature is enabled, this also creates a system
+    /// that calls [`HttpServer::dequeue()`] each frame.
+    ///
+    /// # Returns
+    ///
+    /// Whether the server was started.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_server_start`
+    #[doc(alias = "ecs_http_server_start")]
+    pub fn start(&mut self) -> bool {
+        let ctx = self.ctx();
+        if ctx.is_running {
+            return true;
+        }
+
+        if unsafe { sys::ecs_http_server_start(ctx.server) } != 0 {
+            return false;
+        }
+        ctx.is_running = true;
+
+        #[cfg(feature = "flecs_pipeline")]
+        {
+            if ctx.dequeue_system == 0 {
+                let server = ctx.server;
+                ctx.dequeue_system = self
+                    .world
+                    .system::<()>()
+                    .kind::<flecs::pipeline::OnStore>()
+                    .immediate(true)
+                    .run(move |it| unsafe {
+                        sys::ecs_http_server_dequeue(server, it.delta_time());
+                    })
+                    .id();
+            } else {
+                self.world.entity_from_id(ctx.dequeue_system).enable_self();
+            }
+        }
+
+        true
+    }
+
+    /// Stop the server. After this operation the server no longer accepts
+    /// connections.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_server_stop`
+    #[doc(alias = "ecs_http_server_stop")]
+    pub fn stop(&mut self) {
+        let ctx = self.ctx();
+        if !ctx.is_running {
+            return;
+        }
+
+        #[cfg(feature = "flecs_pipeline")]
+        self.world.entity_from_id(ctx.dequeue_system).disable_self();
+
+        unsafe { sys::ecs_http_server_stop(ctx.server) };
+        ctx.is_running = false;
+    }
+
+    /// Returns whether the server is started.
+    pub fn is_running(&self) -> bool {
+        self.ctx().is_running
+    }
+
+    /// Process received requests. This invokes the route handlers for the
+    /// requests that were received since the last call.
+    ///
+    /// This is called automatically by [`World::progress()`] when the
+    /// `flecs_pipeline` feature is enabled. The server must be started.
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_time` - The time elapsed since the last call.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_server_dequeue`
+    #[doc(alias = "ecs_http_server_dequeue")]
+    pub fn dequeue(&self, delta_time: FTime) {
+        let ctx = self.ctx();
+        ecs_assert!(
+            ctx.is_running,
+            FlecsErrorCode::InvalidOperation,
+            "HTTP server is not started"
+        );
+        unsafe { sys::ecs_http_server_dequeue(ctx.server, delta_time) };
+    }
+
+    /// Dispatch a request to the server without a connection. This invokes
+    /// the route handlers directly and works without starting the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method of the request.
+    /// * `path` - The request path including query parameters, for example
+    ///   `"/debug/entity?name=Sun"`. Query parameters must be URL encoded.
+    /// * `body` - The request body, if any.
+    ///
+    /// A `400 Bad Request` reply is returned without dispatching the request
+    /// if the path contains a `\r` or `\n`, as these would end the request
+    /// line.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_server_http_request`
+    #[doc(alias = "ecs_http_server_http_request")]
+    pub fn request(&self, method: HttpMethod, path: &str, body: Option<&str>) -> HttpReply {
+        if has_line_break(path) {
+            return HttpReply::new(400, "Bad Request").with_content_type("");
+        }
+
+        let body = body.unwrap_or("");
+        let request = format!(
+            "{} {} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+            method.as_str(),
+            path,
+            body.len(),
+            body
+        );
+
+        let mut reply = sys::ecs_http_reply_t::default();
+        unsafe {
+            sys::ecs_http_server_http_request(
+                self.ctx().server,
+                request.as_ptr() as *const _,
+                request.len() as sys::ecs_size_t,
+                &mut reply,
+            );
+        }
+        HttpReply::from_raw(&mut reply)
+    }
+}
+
+impl<'a> Drop for HttpServer<'a> {
+    fn drop(&mut self) {
+        self.stop();
+
+        let ctx = unsafe { Box::from_raw(self.ctx) };
+
+        #[cfg(feature = "flecs_pipeline")]
+        if ctx.dequeue_system != 0 && self.world.is_alive(ctx.dequeue_system) {
+            self.world.entity_from_id(ctx.dequeue_system).destruct();
+        }
+
+        unsafe {
+            #[cfg(feature = "flecs_rest")]
+            if !ctx.rest_server.is_null() {
+                sys::ecs_rest_server_fini(ctx.rest_server);
+            }
+            sys::ecs_http_server_fini(ctx.server);
+        }
+    }
+}
+
+impl<'a> WorldProvider<'a> for HttpServer<'a> {
+    fn world(&self) -> WorldRef<'a> {
+        self.world
+    }
+}
+
+impl World {
+    /// Create a new HTTP server for the world. The server does not accept
+    /// connections until [`HttpServer::start()`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - The port to listen on.
+    ///
+    /// # See also
+    ///
+    /// * [`HttpServer`]
+    pub fn http_server(&self, port: u16) -> HttpServer<'_> {
+        HttpServer::new(self, port)
+    }
+}
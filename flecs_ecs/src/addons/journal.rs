@@ -0,0 +1,776 @@
+//! The journal records the operations that are applied to a world, such as
+//! creating entities, adding and removing components, setting component values
+//! and deleting entities. Recorded entries can be replayed on another world,
+//! which is useful for debugging desync issues and for reducing a problem to a
+//! minimal sequence of operations.
+//!
+//! The journal is recorded by observers of the world, so recording one world
+//! doesn't affect other worlds. Since observers see the effects of operations,
+//! an operation is recorded as the changes it makes to entities:
+//!
+//! * Entities created with [`World::entity()`] and deleted with
+//!   [`EntityView::destruct()`] are recorded when they are created and deleted,
+//!   whether they have components or not. Entities that are created in other
+//!   ways, such as by instantiating a prefab, are recorded as created when they
+//!   get their first component.
+//! * Operations that apply to many entities, such as `delete_with` and
+//!   `remove_all`, are recorded as an entry for each entity they change.
+//! * Side effects of an operation, such as the deletion of children when a
+//!   parent is deleted, or the instantiation of a prefab, are recorded as well.
+//!
+//! Operations are recorded when they are applied to the world, so operations
+//! that are done while the world is deferred are recorded when the command
+//! queue is merged.
+//!
+//! Component values are recorded with the reflection layer, so values are only
+//! recorded for components with reflection data. Components, systems and
+//! observers should be created before recording starts, as the operations that
+//! are applied to builtin entities are not recorded.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::ffi::CStr;
+use std::fmt::{Display, Formatter};
+
+use crate::core::*;
+use crate::sys;
+use flecs_ecs_derive::Component;
+
+/// An id that is referenced by a journal entry.
+///
+/// Besides the id in the recorded world, a journal id stores the paths of the
+/// named entities it refers to. This allows a replay to resolve components and
+/// other named entities in worlds where they have a different id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalId {
+    /// The id in the recorded world.
+    pub id: Id,
+    /// The path of the id, or of the relationship if the id is a pair.
+    pub first: Option<String>,
+    /// The path of the target if the id is a pair.
+    pub second: Option<String>,
+    /// The entity of the id, or of the relationship if the id is a pair. Unlike
+    /// the elements of a pair, this includes the generation of the entity.
+    pub first_entity: Entity,
+    /// The entity of the target if the id is a pair, including its generation.
+    pub second_entity: Entity,
+}
+
+/// An operation recorded by the journal.
+///
+/// See [`World::journal_begin()`] for how to record entries, and
+/// [`World::journal_replay()`] for how to apply them to a world.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalEntry {
+    /// An entity was created.
+    New {
+        /// The created entity.
+        entity: Entity,
+    },
+    /// An id was added to an entity.
+    Add {
+        /// The entity the id was added to.
+        entity: Entity,
+        /// The added id.
+        id: JournalId,
+    },
+    /// An id was removed from an entity.
+    Remove {
+        /// The entity the id was removed from.
+        entity: Entity,
+        /// The removed id.
+        id: JournalId,
+    },
+    /// A component value was set. For the `(Identifier, Name)` pair the value
+    /// is the name of the entity.
+    Set {
+        /// The entity the value was set for.
+        entity: Entity,
+        /// The component id.
+        id: JournalId,
+        /// The value serialized as JSON, or `None` if the component has no
+        /// reflection data.
+        value: Option<String>,
+    },
+    /// An entity was deleted.
+    Delete {
+        /// The deleted entity.
+        entity: Entity,
+    },
+}
+
+impl Display for JournalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let element = |path: &Option<String>, id: u64| match path {
+            Some(path) => path.clone(),
+            None => format!("#{}", id),
+        };
+
+        if ecs_is_pair(self.id) {
+            write!(
+                f,
+                "({}, {})",
+                element(&self.first, *ecs_first(self.id)),
+                element(&self.second, *ecs_second(self.id))
+            )
+        } else {
+            write!(f, "{}", element(&self.first, *self.id))
+        }
+    }
+}
+
+impl Display for JournalEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JournalEntry::New { entity } => write!(f, "new #{}", **entity),
+            JournalEntry::Add { entity, id } => write!(f, "add #{} {}", **entity, id),
+            JournalEntry::Remove { entity, id } => write!(f, "remove #{} {}", **entity, id),
+            JournalEntry::Set {
+                entity,
+                id,
+                value: Some(value),
+            } => write!(f, "set #{} {} {}", **entity, id, value),
+            JournalEntry::Set {
+                entity,
+                id,
+                value: None,
+            } => write!(f, "set #{} {}", **entity, id),
+            JournalEntry::Delete { entity } => write!(f, "delete #{}", **entity),
+        }
+    }
+}
+
+type JournalSink = Box<dyn FnMut(&JournalEntry)>;
+
+/// The state of a journal that is recording a world.
+pub(crate) struct JournalRecorder {
+    // taken while the sink is invoked, so operations done by the sink are not recorded
+    sink: Option<JournalSink>,
+    // entity that owns the observers of the recorder
+    observers: Entity,
+    // entities recorded as created, which haven't been deleted yet
+    created: HashSet<u64>,
+    // ids removed from entities that lost all of their components in the
+    // current operation, which are recorded when the operation is done
+    vacated: HashMap<u64, Vec<JournalId>>,
+}
+
+/// Enqueued when an entity loses all of its components. The event is delivered
+/// when the operation is done, at which point it's known whether the entity was
+/// deleted or only had its components removed.
+#[derive(Component)]
+struct JournalVacated {
+    entity: u64,
+}
+
+const PATH_SEP: &CStr = c"::";
+
+fn name_pair() -> u64 {
+    ecs_pair(ECS_IDENTIFIER, ECS_NAME)
+}
+
+/// Returns the path of an entity, if it has a name.
+unsafe fn entity_path(world: *const sys::ecs_world_t, entity: u64) -> Option<String> {
+    if entity == 0 || sys::ecs_get_name(world, entity).is_null() {
+        return None;
+    }
+    let path = sys::ecs_get_path_w_sep(world, 0, entity, PATH_SEP.as_ptr(), std::ptr::null());
+    if path.is_null() {
+        return None;
+    }
+    let result = CStr::from_ptr(path).to_string_lossy().into_owned();
+    sys::ecs_os_api.free_.expect("os api is missing")(path as *mut std::ffi::c_void);
+    Some(result)
+}
+
+/// Returns the alive entity for an element of a pair, which includes the
+/// generation that isn't stored in the pair.
+unsafe fn pair_element(world: *const sys::ecs_world_t, element: u64) -> u64 {
+    match sys::ecs_get_alive(world, element) {
+        0 => element,
+        alive => alive,
+    }
+}
+
+unsafe fn journal_id(world: *const sys::ecs_world_t, id: u64) -> JournalId {
+    let id = Id(id);
+    if ecs_is_pair(id) {
+        let first = pair_element(world, *ecs_first(id));
+        let second = pair_element(world, *ecs_second(id));
+        JournalId {
+            id,
+            first: entity_path(world, first),
+            second: entity_path(world, second),
+            first_entity: Entity(first),
+            second_entity: Entity(second),
+        }
+    } else {
+        let entity = *id & RUST_ECS_COMPONENT_MASK;
+        JournalId {
+            id,
+            first: entity_path(world, entity),
+            second: None,
+            first_entity: Entity(entity),
+            second_entity: Entity(0),
+        }
+    }
+}
+
+/// Returns the reflected type of an id, if it has one.
+unsafe fn value_type(world: *const sys::ecs_world_t, id: u64) -> Option<u64> {
+    let type_id = sys::ecs_get_typeid(world, id);
+    if type_id != 0 && sys::ecs_has_id(world, type_id, ECS_META_TYPE) {
+        Some(type_id)
+    } else {
+        None
+    }
+}
+
+/// Returns true if operations on an entity should not be recorded. Builtin
+/// entities and components are created in both the recorded and the replaying
+/// world, and are not recorded.
+unsafe fn is_ignored_entity(world: *const sys::ecs_world_t, entity: u64) -> bool {
+    if (entity as u32 as u64) < FLECS_HI_COMPONENT_ID {
+        return true;
+    }
+
+    let table = sys::ecs_get_table(world, entity);
+    !table.is_null() && sys::ecs_table_has_flags(table, sys::EcsTableHasBuiltins)
+}
+
+/// Returns true if an operation on an entity with an id should not be recorded.
+unsafe fn is_ignored(world: *const sys::ecs_world_t, entity: u64, id: u64) -> bool {
+    is_ignored_entity(world, entity)
+        || (!ecs_is_pair(id) && (id <= ECS_POLY || id == ECS_MODULE))
+        || (ecs_is_pair(id) && *ecs_first(id) == ECS_POLY)
+}
+
+/// Returns the recorder of a world, if the world is recording.
+unsafe fn recorder<'a>(world: *mut sys::ecs_world_t) -> Option<&'a mut JournalRecorder> {
+    let ctx = World::get_context(world);
+    if ctx.is_null() || sys::ecs_is_fini(world) {
+        return None;
+    }
+    (*ctx).journal.as_deref_mut()
+}
+
+/// Deliver an entry to the sink of a world, if the world is recording.
+unsafe fn record(world: *mut sys::ecs_world_t, entry: impl FnOnce() -> Option<JournalEntry>) {
+    let Some(mut sink) = recorder(world).and_then(|recorder| recorder.sink.take()) else {
+        return;
+    };
+
+    if let Some(entry) = entry() {
+        sink(&entry);
+    }
+
+    // the recorder may have been replaced or stopped by the sink
+    if let Some(recorder) = recorder(world) {
+        if recorder.sink.is_none() {
+            recorder.sink = Some(sink);
+        }
+    }
+}
+
+/// Returns true if the world is recording, and the sink isn't being invoked.
+unsafe fn is_recording(world: *mut sys::ecs_world_t) -> bool {
+    recorder(world).is_some_and(|recorder| recorder.sink.is_some())
+}
+
+/// Returns true if a table is missing or has no ids, which is the case for
+/// entities that have no components.
+unsafe fn is_empty_table(table: *const sys::ecs_table_t) -> bool {
+    table.is_null() || (*sys::ecs_table_get_type(table)).count == 0
+}
+
+/// Record an add operation. Invoked by the `OnAdd` observers of the recorder.
+fn record_add(mut it: TableIter<false, flecs::OnAdd>, row: usize) {
+    let world = it.world().world_ptr_mut();
+    let entity = *it.entity(row).id();
+    let id = *it.event_id().id();
+    let previous = it.iter_mut().other_table;
+    unsafe {
+        if !is_recording(world) || is_ignored(world, entity, id) {
+            return;
+        }
+
+        // an entity without components is recorded as created when it gets
+        // its first component
+        let created = is_empty_table(previous)
+            && recorder(world).is_some_and(|recorder| recorder.created.insert(entity));
+        if created {
+            record(world, || {
+                Some(JournalEntry::New {
+                    entity: Entity(entity),
+                })
+            });
+        }
+
+        record(world, || {
+            Some(JournalEntry::Add {
+                entity: Entity(entity),
+                id: journal_id(world, id),
+            })
+        });
+    }
+}
+
+/// Record a remove operation. Invoked by the `OnRemove` observers of the recorder.
+fn record_remove(mut it: TableIter<false, flecs::OnRemove>, row: usize) {
+    let world = it.world().world_ptr_mut();
+    let entity = *it.entity(row).id();
+    let id = *it.event_id().id();
+    let next = it.iter_mut().other_table;
+    unsafe {
+        if !is_recording(world) || is_ignored(world, entity, id) {
+            return;
+        }
+
+        if !next.is_null() {
+            record(world, || {
+                Some(JournalEntry::Remove {
+                    entity: Entity(entity),
+                    id: journal_id(world, id),
+                })
+            });
+            return;
+        }
+
+        // The entity loses all of its components, because it is deleted,
+        // cleared or had its last component removed. Which of these it is is
+        // known once the operation is done, which is when the command queue of
+        // the operation is merged.
+        let id = journal_id(world, id);
+        let Some(recorder) = recorder(world) else {
+            return;
+        };
+        match recorder.vacated.entry(entity) {
+            Entry::Occupied(mut ids) => ids.get_mut().push(id),
+            Entry::Vacant(ids) => {
+                ids.insert(vec![id]);
+                EntityView::new_from(it.world(), recorder.observers)
+                    .enqueue(JournalVacated { entity });
+            }
+        }
+    }
+}
+
+/// Record the operation that removed all components of an entity, or that
+/// deleted an entity created by the recording.
+fn record_vacated(world: *mut sys::ecs_world_t, entity: u64) {
+    unsafe {
+        let Some(recorder) = recorder(world) else {
+            return;
+        };
+        let ids = recorder.vacated.remove(&entity);
+
+        if sys::ecs_is_alive(world, entity) {
+            for id in ids.into_iter().flatten() {
+                record(world, || {
+                    Some(JournalEntry::Remove {
+                        entity: Entity(entity),
+                        id,
+                    })
+                });
+            }
+        } else if recorder.created.remove(&entity) || ids.is_some() {
+            record(world, || {
+                Some(JournalEntry::Delete {
+                    entity: Entity(entity),
+                })
+            });
+        }
+    }
+}
+
+/// Record the creation of an entity by [`World::entity()`], so that entities
+/// without components are recorded as well.
+pub(crate) fn record_new(world: *mut sys::ecs_world_t, entity: u64) {
+    unsafe {
+        if !is_recording(world) || is_ignored_entity(world, entity) {
+            return;
+        }
+
+        // the entity is already recorded if it got a component while created
+        let created = recorder(world).is_some_and(|recorder| recorder.created.insert(entity));
+        if created {
+            record(world, || {
+                Some(JournalEntry::New {
+                    entity: Entity(entity),
+                })
+            });
+        }
+    }
+}
+
+/// Record the deletion of an entity by [`EntityView::destruct()`], so that
+/// entities without components are recorded as well. Must be called after the
+/// entity is deleted.
+pub(crate) fn record_delete(world: WorldRef, entity: u64) {
+    unsafe {
+        let world_ptr = world.world_ptr_mut();
+        if !is_recording(world_ptr) {
+            return;
+        }
+        let Some(recorder) = recorder(world_ptr) else {
+            return;
+        };
+        if !recorder.created.contains(&entity) {
+            return;
+        }
+
+        // the deletion is done when the command queue is merged if the world
+        // is deferred, so it's recorded when the enqueued event is delivered.
+        // Entities with components are recorded as deleted once, by the first
+        // event that is delivered for them.
+        EntityView::new_from(world, recorder.observers).enqueue(JournalVacated { entity });
+    }
+}
+
+/// Record a set operation. Invoked by the `OnSet` observers of the recorder.
+fn record_set(it: TableIter<false, flecs::OnSet>, row: usize) {
+    let world = it.world().world_ptr_mut();
+    let entity = it.entity(row).id();
+    let id = *it.event_id().id();
+    unsafe {
+        if !is_recording(world) || is_ignored(world, *entity, id) {
+            return;
+        }
+
+        record(world, || {
+            let value = if id == name_pair() {
+                let name = sys::ecs_get_name(world, *entity);
+                (!name.is_null()).then(|| CStr::from_ptr(name).to_string_lossy().into_owned())
+            } else {
+                value_type(world, id).and_then(|type_id| {
+                    let ptr = sys::ecs_get_id(world, *entity, id);
+                    if ptr.is_null() {
+                        return None;
+                    }
+                    let json = sys::ecs_ptr_to_json(world, type_id, ptr);
+                    if json.is_null() {
+                        return None;
+                    }
+                    let value = CStr::from_ptr(json).to_string_lossy().into_owned();
+                    sys::ecs_os_api.free_.expect("os api is missing")(
+                        json as *mut std::ffi::c_void,
+                    );
+                    Some(value)
+                })
+            };
+
+            Some(JournalEntry::Set {
+                entity,
+                id: journal_id(world, id),
+                value,
+            })
+        });
+    }
+}
+
+impl World {
+    /// Start recording the operations applied to the world. Each recorded
+    /// operation is passed to `sink` as a [`JournalEntry`].
+    ///
+    /// The sink is invoked while the operation is in progress, and must not
+    /// modify the world. Operations done by the sink are not recorded.
+    ///
+    /// Recording stops when [`World::journal_end()`] is called. Starting a new
+    /// recording while the world is recording replaces the sink.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::journal::*;
+    /// use flecs_ecs::prelude::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Component)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>().meta();
+    ///
+    /// let entries = Arc::new(Mutex::new(Vec::new()));
+    /// let sink = entries.clone();
+    /// world.journal_begin(move |entry| sink.lock().unwrap().push(entry.clone()));
+    ///
+    /// let e = world.entity().set(Position { x: 10.0, y: 20.0 });
+    /// e.destruct();
+    ///
+    /// world.journal_end();
+    ///
+    /// // replay the entries on a world with the same components
+    /// let replay_world = World::new();
+    /// replay_world.component::<Position>().meta();
+    /// let replay = replay_world
+    ///     .journal_replay(entries.lock().unwrap().iter())
+    ///     .unwrap();
+    /// assert!(!replay.entity(e).unwrap().is_alive());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::journal_end()`]
+    /// * [`World::journal_replay()`]
+    pub fn journal_begin(&self, sink: impl FnMut(&JournalEntry) + 'static) {
+        let ctx = self.world_ctx_mut();
+        if let Some(recorder) = ctx.journal.as_mut() {
+            recorder.sink = Some(Box::new(sink));
+            return;
+        }
+
+        // create the observers before the recorder is installed, so they aren't recorded
+        // events can't be emitted on empty entities, so the entity gets a tag
+        let observers = self
+            .entity()
+            .add::<flecs::Private>()
+            .observe_payload_entity::<JournalVacated>(|observers, vacated| {
+                record_vacated(observers.world().world_ptr_mut(), vacated.entity);
+            });
+        for id in [
+            flecs::Wildcard::ID,
+            ecs_pair(flecs::Wildcard::ID, flecs::Wildcard::ID),
+        ] {
+            let on_add = self
+                .observer::<flecs::OnAdd, ()>()
+                .with_id(id)
+                .each_iter(|it, row, _| record_add(it, row));
+            let on_remove = self
+                .observer::<flecs::OnRemove, ()>()
+                .with_id(id)
+                .each_iter(|it, row, _| record_remove(it, row));
+            let on_set = self
+                .observer::<flecs::OnSet, ()>()
+                .with_id(id)
+                .each_iter(|it, row, _| record_set(it, row));
+            for observer in [on_add.id(), on_remove.id(), on_set.id()] {
+                unsafe {
+                    sys::ecs_add_id(
+                        self.ptr_mut(),
+                        *observer,
+                        ecs_pair(ECS_CHILD_OF, *observers.id()),
+                    );
+                }
+            }
+        }
+
+        ctx.journal = Some(Box::new(JournalRecorder {
+            sink: Some(Box::new(sink)),
+            observers: observers.id(),
+            created: HashSet::new(),
+            vacated: HashMap::new(),
+        }));
+    }
+
+    /// Stop recording the operations applied to the world.
+    ///
+    /// # See also
+    ///
+    /// * [`World::journal_begin()`]
+    pub fn journal_end(&self) {
+        if let Some(recorder) = self.world_ctx_mut().journal.take() {
+            unsafe { sys::ecs_delete(self.ptr_mut(), *recorder.observers) };
+        }
+    }
+
+    /// Returns true if the operations applied to the world are recorded.
+    ///
+    /// # See also
+    ///
+    /// * [`World::journal_begin()`]
+    pub fn is_journal_active(&self) -> bool {
+        self.world_ctx().journal.is_some()
+    }
+
+    /// Apply recorded journal entries to this world.
+    ///
+    /// Entities created by the recorded operations are created as new entities
+    /// in this world. Other entities are resolved by path, so components must
+    /// be registered with the same name in both worlds.
+    ///
+    /// # Returns
+    ///
+    /// The replay, which can be used to look up the entities created by the
+    /// replay, or to apply more entries. Returns an error if an entry refers to
+    /// an entity that can't be resolved, or has a value that can't be
+    /// deserialized. The entries before it remain applied.
+    ///
+    /// # See also
+    ///
+    /// * [`World::journal_begin()`]
+    /// * [`JournalReplay`]
+    pub fn journal_replay<'e>(
+        &self,
+        entries: impl IntoIterator<Item = &'e JournalEntry>,
+    ) -> Result<JournalReplay<'_>, FlecsError> {
+        let mut replay = JournalReplay::new(self);
+        for entry in entries {
+            replay.apply(entry)?;
+        }
+        Ok(replay)
+    }
+}
+
+/// Applies journal entries to a world, and keeps track of the entities that
+/// were created by the replay.
+///
+/// # See also
+///
+/// * [`World::journal_replay()`]
+pub struct JournalReplay<'a> {
+    world: WorldRef<'a>,
+    // created entities, by the entity in the recorded world
+    entities: HashMap<u64, u64>,
+}
+
+impl<'a> JournalReplay<'a> {
+    /// Create a replay for a world.
+    pub fn new(world: impl WorldProvider<'a>) -> Self {
+        JournalReplay {
+            world: world.world(),
+            entities: HashMap::new(),
+        }
+    }
+
+    /// Get the entity in the replaying world for an entity that was created by
+    /// a replayed [`JournalEntry::New`] entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `recorded` - The entity in the recorded world.
+    pub fn entity(&self, recorded: impl Into<Entity>) -> Option<EntityView<'a>> {
+        self.entities
+            .get(&*recorded.into())
+            .map(|&e| EntityView::new_from(self.world, e))
+    }
+
+    /// Apply a journal entry to the world.
+    ///
+    /// # Returns
+    ///
+    /// An error if the entry refers to an entity that wasn't created by the
+    /// replay and has no name, or has a value that can't be deserialized.
+    pub fn apply(&mut self, entry: &JournalEntry) -> Result<(), FlecsError> {
+        let world = self.world.world_ptr_mut();
+        match entry {
+            JournalEntry::New { entity } => {
+                let new = unsafe { sys::ecs_new(world) };
+                self.entities.insert(**entity, new);
+            }
+            JournalEntry::Add { entity, id } => {
+                let entity = self.resolve_entity(*entity)?;
+                // names are assigned by set entries
+                if ecs_is_pair(id.id) && *ecs_first(id.id) == ECS_IDENTIFIER {
+                    return Ok(());
+                }
+                let id = self.resolve_id(id)?;
+                unsafe { sys::ecs_add_id(world, entity, *id) };
+            }
+            JournalEntry::Remove { entity, id } => {
+                let entity = self.resolve_entity(*entity)?;
+                if *id.id == name_pair() {
+                    unsafe { sys::ecs_set_name(world, entity, std::ptr::null()) };
+                } else if !ecs_is_pair(id.id) || *ecs_first(id.id) != ECS_IDENTIFIER {
+                    let id = self.resolve_id(id)?;
+                    unsafe { sys::ecs_remove_id(world, entity, *id) };
+                }
+            }
+            JournalEntry::Set { entity, id, value } => {
+                let entity = self.resolve_entity(*entity)?;
+                if *id.id == name_pair() {
+                    if let Some(name) = value {
+                        let name = compact_str::format_compact!("{}\0", name);
+                        unsafe { sys::ecs_set_name(world, entity, name.as_ptr() as *const _) };
+                    }
+                    return Ok(());
+                }
+
+                let id = self.resolve_id(id)?;
+                match (value, unsafe { value_type(world, *id) }) {
+                    (Some(value), Some(type_id)) => {
+                        let json = compact_str::format_compact!("{}\0", value);
+                        unsafe {
+                            let ptr = sys::ecs_ensure_id(world, entity, *id);
+                            let result = sys::ecs_ptr_from_json(
+                                world,
+                                type_id,
+                                ptr,
+                                json.as_ptr() as *const _,
+                                std::ptr::null(),
+                            );
+                            sys::ecs_modified_id(world, entity, *id);
+                            if result.is_null() {
+                                return Err(FlecsError::new(
+                                    FlecsErrorCode::InvalidConversion,
+                                    format!("can't deserialize journal value '{}'", value),
+                                )
+                                .with_entity(entity)
+                                .with_id(id));
+                            }
+                        }
+                    }
+                    _ => unsafe { sys::ecs_add_id(world, entity, *id) },
+                }
+            }
+            JournalEntry::Delete { entity } => {
+                let entity = self.resolve_entity(*entity)?;
+                unsafe { sys::ecs_delete(world, entity) };
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_entity(&self, entity: Entity) -> Result<u64, FlecsError> {
+        self.resolve_element(*entity, &None)
+    }
+
+    fn resolve_element(&self, entity: u64, path: &Option<String>) -> Result<u64, FlecsError> {
+        if let Some(&e) = self.entities.get(&entity) {
+            return Ok(e);
+        }
+        if let Some(path) = path {
+            let path = compact_str::format_compact!("{}\0", path);
+            // paths of builtin entities don't include the `flecs::core` scope,
+            // which is searched by a recursive lookup
+            let e = unsafe {
+                sys::ecs_lookup_path_w_sep(
+                    self.world.world_ptr(),
+                    0,
+                    path.as_ptr() as *const _,
+                    PATH_SEP.as_ptr(),
+                    std::ptr::null(),
+                    true,
+                )
+            };
+            if e != 0 {
+                return Ok(e);
+            }
+        }
+        Err(FlecsError::new(
+            FlecsErrorCode::InvalidParameter,
+            match path {
+                Some(path) => format!("journal entity '{}' doesn't exist in the world", path),
+                None => "journal entity wasn't created by the replay and has no name".to_string(),
+            },
+        )
+        .with_entity(entity))
+    }
+
+    fn resolve_id(&self, id: &JournalId) -> Result<Id, FlecsError> {
+        if ecs_is_pair(id.id) {
+            let first = self.resolve_element(*id.first_entity, &id.first)?;
+            let second = self.resolve_element(*id.second_entity, &id.second)?;
+            Ok(Id(ecs_pair(first, second)))
+        } else {
+            let flags = *id.id & RUST_ecs_id_FLAGS_MASK;
+            Ok(Id(
+                self.resolve_element(*id.first_entity, &id.first)? | flags
+            ))
+        }
+    }
+}
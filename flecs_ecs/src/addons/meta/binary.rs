@@ -0,0 +1,1522 @@
+//! A compact binary format for worlds and entities, driven by reflection data.
+//!
+//! Where the JSON addon converts every value to text, the binary format copies
+//! values that are plain data (numbers, bitmasks and structs or arrays of them
+//! without padding) as they are stored in the component columns, and only
+//! walks the members of types that contain strings, entities, enums, vectors
+//! or opaque types.
+//!
+//! The data starts with a schema that describes all reflected types that are
+//! used by the stored components. When the data is read back, the schema is
+//! validated against the types of the target world, so that data written by a
+//! program with different component layouts is rejected instead of loaded.
+//!
+//! The data consists of the following sections, in this order:
+//!
+//! * a header with a magic number and the version of the format.
+//! * the paths of entities that are referenced, but not stored in the data,
+//!   such as component types, tags and relationships.
+//! * the reflection data of the stored types.
+//! * the stored entities, with their parent and name. Parents are stored before
+//!   their children.
+//! * the ids of the stored entities, grouped by table, followed by the values
+//!   of the components in the table.
+//!
+//! All numbers are stored in little endian byte order. Entity ids are stored
+//! as they were in the world the data was written from, and are mapped to the
+//! entities of the target world when the data is read.
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt;
+use std::io::{Read, Write};
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use super::reflection::*;
+use crate::core::*;
+use crate::sys;
+
+const MAGIC: &[u8; 8] = b"FLECSBIN";
+const VERSION: u32 = 1;
+const NULL_STRING: u32 = u32::MAX;
+
+/// An error returned when writing or reading binary data.
+#[derive(Debug)]
+pub enum BinaryError {
+    /// Writing to or reading from the underlying stream failed.
+    Io(std::io::Error),
+    /// The data is not valid binary data, or is truncated.
+    InvalidData(String),
+    /// The data is valid, but does not match the types or entities of the
+    /// world it is read into.
+    Mismatch(String),
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryError::Io(e) => write!(f, "{e}"),
+            BinaryError::InvalidData(message) => write!(f, "invalid binary data: {message}"),
+            BinaryError::Mismatch(message) => {
+                write!(f, "binary data does not match the world: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BinaryError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for BinaryError {
+    fn from(e: std::io::Error) -> Self {
+        BinaryError::Io(e)
+    }
+}
+
+impl World {
+    /// Write all entities of the world with their components to `writer`.
+    ///
+    /// Only the values of components with reflection data are stored, for
+    /// example of components registered with `world.component::<T>().meta()`.
+    /// Components with data but without reflection data are skipped. Builtin
+    /// entities (components, modules, systems, observers, queries and their
+    /// children) are not stored.
+    ///
+    /// The data can be read back with [`World::from_binary()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>().meta();
+    /// world.entity_named("e").set(Position { x: 10.0, y: 20.0 });
+    ///
+    /// let mut data = Vec::new();
+    /// world.to_binary_world(&mut data).unwrap();
+    ///
+    /// let world2 = World::new();
+    /// world2.component::<Position>().meta();
+    /// world2.from_binary(data.as_slice()).unwrap();
+    ///
+    /// world2.lookup("e").get::<&Position>(|pos| {
+    ///     assert_eq!(pos.x, 10.0);
+    ///     assert_eq!(pos.y, 20.0);
+    /// });
+    /// ```
+    pub fn to_binary_world(&self, writer: impl Write) -> Result<(), BinaryError> {
+        let entities = unsafe { sys::ecs_get_entities(self.world_ptr()) };
+        let ids =
+            unsafe { std::slice::from_raw_parts(entities.ids, entities.alive_count as usize) };
+        self.to_binary_entities(ids.iter().map(|&id| Entity(id)), writer)
+    }
+
+    /// Write a subset of the entities of the world with their components to
+    /// `writer`.
+    ///
+    /// Entities that are not alive and builtin entities are skipped. Entities
+    /// that are referenced by the stored entities but are not stored
+    /// themselves, such as parents, relationship targets and entities in
+    /// component values, are stored as their path, and must exist in the world
+    /// that the data is read into.
+    ///
+    /// See [`World::to_binary_world()`] for which components are stored.
+    pub fn to_binary_entities<E: Into<Entity>>(
+        &self,
+        entities: impl IntoIterator<Item = E>,
+        mut writer: impl Write,
+    ) -> Result<(), BinaryError> {
+        let mut encoder = Encoder::new(self.ptr_mut());
+        unsafe { encoder.encode(entities.into_iter().map(|e| *e.into()))? };
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&unsafe { encoder.refs() })?;
+        writer.write_all(&encoder.type_count.to_le_bytes())?;
+        writer.write_all(&encoder.schema)?;
+        writer.write_all(&encoder.out)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Read entities written by [`World::to_binary_world()`] or
+    /// [`World::to_binary_entities()`] into the world, and return them.
+    ///
+    /// The whole data is read and validated before anything is changed, so the
+    /// world is left unchanged when an error is returned. The stored types
+    /// must have the same layout and reflection data as the types of the world.
+    /// Referenced entities that are not stored in the data are looked up by
+    /// their path. Named entities that already exist in the world are reused,
+    /// all other entities are created.
+    ///
+    /// # Panics
+    ///
+    /// The world must not be deferred.
+    pub fn from_binary(&self, mut reader: impl Read) -> Result<Vec<Entity>, BinaryError> {
+        ecs_assert!(
+            !self.is_deferred(),
+            FlecsErrorCode::InvalidOperation,
+            "cannot read binary data while the world is deferred"
+        );
+
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let mut decoder = Decoder::new(self.ptr_mut(), &data);
+        unsafe { decoder.decode() }
+    }
+}
+
+/// Returns true if a type has the reflection data that is needed to encode its
+/// values with [`encode_value()`].
+pub(crate) fn has_reflection(world: *const sys::ecs_world_t, type_id: u64) -> bool {
+    unsafe { type_kind(world, type_id).is_some() }
+}
+
+/// Encode a single value with the reflection data of its type, for example to
+/// store a value that has no copy hook. Entities in the value are stored with
+/// their id, so the value can only be decoded in the same world.
+///
+/// # Safety
+///
+/// `ptr` must point to a valid value of the type.
+pub(crate) unsafe fn encode_value(
+    world: *mut sys::ecs_world_t,
+    type_id: u64,
+    ptr: *const c_void,
+) -> Result<Vec<u8>, BinaryError> {
+    let mut encoder = Encoder::new(world);
+    encoder.value(type_id, ptr)?;
+    Ok(encoder.out)
+}
+
+/// Decode a value encoded with [`encode_value()`] into an existing value.
+///
+/// # Safety
+///
+/// `ptr` must point to a valid value of the type, which is overwritten.
+pub(crate) unsafe fn decode_value(
+    world: *mut sys::ecs_world_t,
+    type_id: u64,
+    data: &[u8],
+    ptr: *mut c_void,
+) -> Result<(), BinaryError> {
+    let mut decoder = Decoder::new(world, data);
+    decoder.keep_ids = true;
+    decoder.value(type_id, ptr)?;
+    if decoder.pos != data.len() {
+        return Err(BinaryError::InvalidData(
+            "unexpected data after the value".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+trait Put {
+    fn put_u8(&mut self, value: u8);
+    fn put_u32(&mut self, value: u32);
+    fn put_u64(&mut self, value: u64);
+    fn put_str(&mut self, value: Option<&[u8]>);
+}
+
+impl Put for Vec<u8> {
+    fn put_u8(&mut self, value: u8) {
+        self.push(value);
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        self.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_u64(&mut self, value: u64) {
+        self.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_str(&mut self, value: Option<&[u8]>) {
+        match value {
+            Some(bytes) => {
+                self.put_u32(bytes.len() as u32);
+                self.extend_from_slice(bytes);
+            }
+            None => self.put_u32(NULL_STRING),
+        }
+    }
+}
+
+/// Returns the size of primitives that are stored as their bytes.
+fn primitive_size(kind: sys::ecs_primitive_kind_t) -> Option<usize> {
+    match kind {
+        sys::ecs_primitive_kind_t_EcsChar
+        | sys::ecs_primitive_kind_t_EcsByte
+        | sys::ecs_primitive_kind_t_EcsU8
+        | sys::ecs_primitive_kind_t_EcsI8 => Some(1),
+        sys::ecs_primitive_kind_t_EcsU16 | sys::ecs_primitive_kind_t_EcsI16 => Some(2),
+        sys::ecs_primitive_kind_t_EcsU32
+        | sys::ecs_primitive_kind_t_EcsI32
+        | sys::ecs_primitive_kind_t_EcsF32 => Some(4),
+        sys::ecs_primitive_kind_t_EcsU64
+        | sys::ecs_primitive_kind_t_EcsI64
+        | sys::ecs_primitive_kind_t_EcsF64 => Some(8),
+        _ => None,
+    }
+}
+
+/// Returns true if values of a type can be copied as their bytes, which is the
+/// case for numbers and bitmasks, and for structs and arrays of them that have
+/// no padding.
+unsafe fn is_pod(
+    world: *const sys::ecs_world_t,
+    cache: &mut FxHashMap<u64, bool>,
+    type_id: u64,
+) -> bool {
+    if cfg!(target_endian = "big") {
+        return false;
+    }
+    if let Some(&pod) = cache.get(&type_id) {
+        return pod;
+    }
+    let pod = match type_kind(world, type_id) {
+        Some(Kind::Primitive(kind)) => primitive_size(kind).is_some(),
+        Some(Kind::Bitmask(_)) => true,
+        Some(Kind::Struct(members)) => {
+            let mut members: Vec<&sys::ecs_member_t> = members.iter().collect();
+            members.sort_unstable_by_key(|m| m.offset);
+            let mut offset = 0;
+            let mut pod = true;
+            for m in members {
+                if m.offset as usize != offset || !is_pod(world, cache, m.type_) {
+                    pod = false;
+                    break;
+                }
+                offset += type_size(world, m.type_) * (m.count as usize).max(1);
+            }
+            pod && offset == type_size(world, type_id)
+        }
+        Some(Kind::Array(array)) => is_pod(world, cache, array.type_),
+        _ => false,
+    };
+    cache.insert(type_id, pod);
+    pod
+}
+
+fn is_pair(id: u64) -> bool {
+    id & sys::ECS_ID_FLAGS_MASK as u64 == ECS_PAIR
+}
+
+fn pair_first(id: u64) -> u64 {
+    (id & sys::ECS_COMPONENT_MASK) >> 32
+}
+
+fn pair_second(id: u64) -> u64 {
+    id & sys::ECS_ENTITY_MASK as u64
+}
+
+struct Encoder {
+    world: *mut sys::ecs_world_t,
+    /// The index of the stored entities, by the low 32 bits of their id.
+    stored: FxHashMap<u32, u32>,
+    /// Entities that are referenced but not stored.
+    refs: Vec<u64>,
+    ref_set: FxHashSet<u32>,
+    types: FxHashSet<u64>,
+    type_count: u32,
+    schema: Vec<u8>,
+    out: Vec<u8>,
+    pod: FxHashMap<u64, bool>,
+    builtin: FxHashMap<*mut sys::ecs_table_t, bool>,
+}
+
+impl Encoder {
+    fn new(world: *mut sys::ecs_world_t) -> Self {
+        Self {
+            world,
+            stored: FxHashMap::default(),
+            refs: Vec::new(),
+            ref_set: FxHashSet::default(),
+            types: FxHashSet::default(),
+            type_count: 0,
+            schema: Vec::new(),
+            out: Vec::new(),
+            pod: FxHashMap::default(),
+            builtin: FxHashMap::default(),
+        }
+    }
+
+    /// Returns true if the entities in a table are builtin, which is the case
+    /// if the table or the table of one of their parents has builtin ids.
+    unsafe fn is_builtin(&mut self, table: *mut sys::ecs_table_t) -> bool {
+        if table.is_null() {
+            return false;
+        }
+        if let Some(&builtin) = self.builtin.get(&table) {
+            return builtin;
+        }
+        let builtin = sys::ecs_table_has_flags(table, sys::EcsTableHasBuiltins) || {
+            let mut parent = 0;
+            sys::ecs_search(
+                self.world,
+                table,
+                ecs_pair(ECS_CHILD_OF, ECS_WILDCARD),
+                &mut parent,
+            );
+            parent != 0 && {
+                let parent = sys::ecs_get_alive(self.world, pair_second(parent));
+                parent != 0 && self.is_builtin(sys::ecs_get_table(self.world, parent))
+            }
+        };
+        self.builtin.insert(table, builtin);
+        builtin
+    }
+
+    unsafe fn encode(&mut self, entities: impl Iterator<Item = u64>) -> Result<(), BinaryError> {
+        let world = self.world;
+
+        let mut stored = Vec::new();
+        let mut seen = FxHashSet::default();
+        for e in entities {
+            if e == 0 || !sys::ecs_is_alive(world, e) || !seen.insert(e) {
+                continue;
+            }
+            if !self.is_builtin(sys::ecs_get_table(world, e)) {
+                stored.push(e);
+            }
+        }
+
+        // parents are stored before their children, so that they can be
+        // created first when the data is read
+        let mut depths = FxHashMap::default();
+        let mut depth = |e: u64| {
+            let mut chain = Vec::new();
+            let mut current = e;
+            let mut depth = 0;
+            while current != 0 {
+                if let Some(&d) = depths.get(&current) {
+                    depth = d;
+                    break;
+                }
+                chain.push(current);
+                current = sys::ecs_get_parent(world, current);
+            }
+            for &e in chain.iter().rev() {
+                depth += 1;
+                depths.insert(e, depth);
+            }
+            depths[&e]
+        };
+        let mut keyed: Vec<(u32, u64)> = stored.iter().map(|&e| (depth(e), e)).collect();
+        keyed.sort_by_key(|&(depth, _)| depth);
+        let stored: Vec<u64> = keyed.into_iter().map(|(_, e)| e).collect();
+
+        for (index, &e) in stored.iter().enumerate() {
+            self.stored.insert(e as u32, index as u32);
+        }
+
+        self.out.put_u32(stored.len() as u32);
+        for &e in &stored {
+            let parent = self.entity_ref(sys::ecs_get_parent(world, e));
+            let name = sys::ecs_get_name(world, e);
+            self.out.put_u64(e);
+            self.out.put_u64(parent);
+            self.out
+                .put_str((!name.is_null()).then(|| CStr::from_ptr(name).to_bytes()));
+        }
+
+        let mut tables: Vec<(*mut sys::ecs_table_t, Vec<(u64, *mut sys::ecs_record_t)>)> =
+            Vec::new();
+        let mut table_index = FxHashMap::default();
+        for &e in &stored {
+            let record = sys::ecs_record_find(world, e);
+            let table = (*record).table;
+            if table.is_null() {
+                continue;
+            }
+            let index = *table_index.entry(table).or_insert_with(|| {
+                tables.push((table, Vec::new()));
+                tables.len() - 1
+            });
+            tables[index].1.push((e, record));
+        }
+
+        let count_pos = self.out.len();
+        self.out.put_u32(0);
+        let mut table_count = 0u32;
+        for (table, entities) in &tables {
+            if self.encode_table(*table, entities)? {
+                table_count += 1;
+            }
+        }
+        self.out[count_pos..count_pos + 4].copy_from_slice(&table_count.to_le_bytes());
+        Ok(())
+    }
+
+    /// Encode the ids and component values of entities in a table. Returns
+    /// false if the table has no ids that are stored.
+    unsafe fn encode_table(
+        &mut self,
+        table: *mut sys::ecs_table_t,
+        entities: &[(u64, *mut sys::ecs_record_t)],
+    ) -> Result<bool, BinaryError> {
+        let world = self.world;
+        let type_ = sys::ecs_table_get_type(table);
+        if type_.is_null() || (*type_).count == 0 {
+            return Ok(false);
+        }
+        let table_ids = std::slice::from_raw_parts((*type_).array, (*type_).count as usize);
+
+        // identifiers are stored with the entity, and the parent is stored
+        // with the entity
+        let mut ids = Vec::new();
+        for &id in table_ids {
+            if is_pair(id) && matches!(pair_first(id), ECS_IDENTIFIER | ECS_CHILD_OF) {
+                continue;
+            }
+            let type_info = sys::ecs_get_type_info(world, id);
+            let type_id = if type_info.is_null() || (*type_info).size == 0 {
+                0
+            } else {
+                let type_id = (*type_info).component;
+                if type_kind(world, type_id).is_none() {
+                    continue;
+                }
+                type_id
+            };
+            ids.push((id, type_id));
+        }
+        if ids.is_empty() {
+            return Ok(false);
+        }
+
+        self.out.put_u32(ids.len() as u32);
+        for &(id, type_id) in &ids {
+            let id = self.id_ref(id);
+            self.out.put_u64(id);
+            if type_id != 0 {
+                self.add_type(type_id)?;
+            }
+            let type_id = self.entity_ref(type_id);
+            self.out.put_u64(type_id);
+        }
+
+        self.out.put_u32(entities.len() as u32);
+        for &(e, _) in entities {
+            self.out.put_u32(self.stored[&(e as u32)]);
+        }
+
+        for &(id, type_id) in &ids {
+            if type_id == 0 {
+                continue;
+            }
+            let column = sys::ecs_table_get_column_index(world, table, id);
+            let size = type_size(world, type_id);
+            let pod = is_pod(world, &mut self.pod, type_id);
+            for &(_, record) in entities {
+                let ptr = sys::ecs_record_get_by_column(record, column, size);
+                if pod {
+                    self.bytes(ptr, size);
+                } else {
+                    self.value(type_id, ptr)?;
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Returns the paths of the referenced entities that are not stored.
+    unsafe fn refs(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.put_u32(self.refs.len() as u32);
+        for &e in &self.refs {
+            out.put_u64(e);
+            out.put_str(Some(entity_path(self.world, e).as_bytes()));
+        }
+        out
+    }
+
+    /// Register a referenced entity, and return the id to store for it.
+    /// References to entities that are not alive are stored as 0.
+    unsafe fn entity_ref(&mut self, e: u64) -> u64 {
+        if e == 0 || !sys::ecs_is_alive(self.world, e) {
+            return 0;
+        }
+        let low = e as u32;
+        if !self.stored.contains_key(&low) && self.ref_set.insert(low) {
+            self.refs.push(e);
+        }
+        e
+    }
+
+    /// Register the entities of an id, and return the id to store for it.
+    unsafe fn id_ref(&mut self, id: u64) -> u64 {
+        let parts = if is_pair(id) {
+            [pair_first(id), pair_second(id)]
+        } else {
+            [id & sys::ECS_ENTITY_MASK as u64, 0]
+        };
+        let mut alive = [0; 2];
+        for (part, alive) in parts.into_iter().zip(alive.iter_mut()) {
+            if part != 0 {
+                *alive = sys::ecs_get_alive(self.world, part);
+                if *alive == 0 {
+                    return 0;
+                }
+            }
+        }
+        for e in alive {
+            self.entity_ref(e);
+        }
+        id
+    }
+
+    /// Add the reflection data of a type and the types it uses to the schema.
+    unsafe fn add_type(&mut self, type_id: u64) -> Result<(), BinaryError> {
+        if !self.types.insert(type_id) {
+            return Ok(());
+        }
+        let world = self.world;
+        let kind = type_kind(world, type_id)
+            .ok_or_else(|| BinaryError::InvalidData(no_reflection(world, type_id)))?;
+        let type_kind =
+            get::<sys::EcsType>(world, type_id, flecs::meta::Type::ID).map_or(0, |ty| ty.kind);
+
+        let type_ref = self.entity_ref(type_id);
+        let mut out = Vec::new();
+        out.put_u64(type_ref);
+        out.put_u8(type_kind as u8);
+        out.put_u32(type_size(world, type_id) as u32);
+
+        let mut nested = Vec::new();
+        match kind {
+            Kind::Primitive(primitive) => out.put_u8(primitive as u8),
+            Kind::Enum(e) => {
+                let constants = enum_constants(e);
+                out.put_u32(constants.len() as u32);
+                for (value, name) in constants {
+                    out.put_str(Some(name.as_bytes()));
+                    out.put_u64(value as u64);
+                }
+            }
+            Kind::Bitmask(b) => {
+                let constants = bitmask_constants(b);
+                out.put_u32(constants.len() as u32);
+                for (value, name) in constants {
+                    out.put_str(Some(name.as_bytes()));
+                    out.put_u32(value);
+                }
+            }
+            Kind::Struct(members) => {
+                out.put_u32(members.len() as u32);
+                for m in members {
+                    out.put_str(Some(cstr(m.name).as_bytes()));
+                    out.put_u64(self.entity_ref(m.type_));
+                    out.put_u32(m.count as u32);
+                    out.put_u32(m.offset as u32);
+                    nested.push(m.type_);
+                }
+            }
+            Kind::Array(array) => {
+                out.put_u64(self.entity_ref(array.type_));
+                out.put_u32(array.count as u32);
+                nested.push(array.type_);
+            }
+            Kind::Vector(vector) => {
+                out.put_u64(self.entity_ref(vector.type_));
+                nested.push(vector.type_);
+            }
+            Kind::Opaque(opaque) => {
+                out.put_u64(self.entity_ref(opaque.as_type));
+                nested.push(opaque.as_type);
+            }
+        }
+        self.schema.extend_from_slice(&out);
+        self.type_count += 1;
+
+        for type_id in nested {
+            self.add_type(type_id)?;
+        }
+        Ok(())
+    }
+
+    /// Copy the bytes of a value, in little endian byte order.
+    unsafe fn bytes(&mut self, ptr: *const c_void, size: usize) {
+        let start = self.out.len();
+        self.out
+            .extend_from_slice(std::slice::from_raw_parts(ptr as *const u8, size));
+        if cfg!(target_endian = "big") {
+            self.out[start..].reverse();
+        }
+    }
+
+    unsafe fn value(&mut self, type_id: u64, ptr: *const c_void) -> Result<(), BinaryError> {
+        let world = self.world;
+        let kind = type_kind(world, type_id)
+            .ok_or_else(|| BinaryError::InvalidData(no_reflection(world, type_id)))?;
+        match kind {
+            Kind::Primitive(primitive) => self.primitive(primitive, ptr),
+            Kind::Bitmask(_) => self.out.put_u32(*(ptr as *const u32)),
+            Kind::Enum(_) => self
+                .out
+                .put_u64(read_enum(ptr, type_size(world, type_id)) as u64),
+            Kind::Struct(members) => {
+                if is_pod(world, &mut self.pod, type_id) {
+                    self.bytes(ptr, type_size(world, type_id));
+                    return Ok(());
+                }
+                for m in members {
+                    let size = type_size(world, m.type_);
+                    for i in 0..(m.count as usize).max(1) {
+                        self.value(m.type_, ptr.byte_add(m.offset as usize + i * size))?;
+                    }
+                }
+            }
+            Kind::Array(array) => {
+                let size = type_size(world, array.type_);
+                for i in 0..array.count as usize {
+                    self.value(array.type_, ptr.byte_add(i * size))?;
+                }
+            }
+            Kind::Vector(vector) => {
+                let vec = &*(ptr as *const sys::ecs_vec_t);
+                let size = type_size(world, vector.type_);
+                self.out.put_u32(vec.count as u32);
+                for i in 0..vec.count as usize {
+                    self.value(vector.type_, vec.array.byte_add(i * size))?;
+                }
+            }
+            Kind::Opaque(opaque) => self.opaque(type_id, opaque, ptr)?,
+        }
+        Ok(())
+    }
+
+    unsafe fn primitive(&mut self, kind: sys::ecs_primitive_kind_t, ptr: *const c_void) {
+        if let Some(size) = primitive_size(kind) {
+            self.bytes(ptr, size);
+            return;
+        }
+        match kind {
+            sys::ecs_primitive_kind_t_EcsBool => self.out.put_u8(*(ptr as *const bool) as u8),
+            sys::ecs_primitive_kind_t_EcsUPtr => self.out.put_u64(*(ptr as *const usize) as u64),
+            sys::ecs_primitive_kind_t_EcsIPtr => self.out.put_u64(*(ptr as *const isize) as u64),
+            sys::ecs_primitive_kind_t_EcsString => {
+                let string = *(ptr as *const *const c_char);
+                self.out
+                    .put_str((!string.is_null()).then(|| CStr::from_ptr(string).to_bytes()));
+            }
+            sys::ecs_primitive_kind_t_EcsEntity => {
+                let e = self.entity_ref(*(ptr as *const u64));
+                self.out.put_u64(e);
+            }
+            _ => {
+                let id = self.id_ref(*(ptr as *const u64));
+                self.out.put_u64(id);
+            }
+        }
+    }
+
+    /// Encode an opaque type as the type it describes itself as. Collections
+    /// are stored as the number of elements followed by the elements, structs
+    /// as the number of members followed by the index and value of each member.
+    unsafe fn opaque(
+        &mut self,
+        type_id: u64,
+        opaque: &sys::EcsOpaque,
+        ptr: *const c_void,
+    ) -> Result<(), BinaryError> {
+        let world = self.world;
+        let as_type = opaque.as_type;
+        let kind = type_kind(world, as_type)
+            .ok_or_else(|| BinaryError::InvalidData(no_reflection(world, as_type)))?;
+
+        let members = match kind {
+            Kind::Struct(members) => Some(members),
+            _ => None,
+        };
+        let elem = match kind {
+            Kind::Array(array) => Some(array.type_),
+            Kind::Vector(vector) => Some(vector.type_),
+            _ => None,
+        };
+
+        let outer = std::mem::take(&mut self.out);
+        let mut count = 0u32;
+        let mut member = None;
+        let mut error = None;
+        let result = call_opaque(world, opaque, ptr, &mut |event| {
+            let result = match event {
+                OpaqueEvent::Member(name) => match members
+                    .and_then(|members| members.iter().position(|m| cstr(m.name) == name))
+                {
+                    Some(index) => {
+                        member = Some(index as u32);
+                        Ok(())
+                    }
+                    None => Err(format!("unknown member '{name}'")),
+                },
+                OpaqueEvent::Value(value_type, value) => {
+                    let expected = match (members, member) {
+                        (Some(members), Some(index)) => members[index as usize].type_,
+                        (Some(_), None) => 0,
+                        _ => elem.unwrap_or(as_type),
+                    };
+                    if value_type != expected || (elem.is_none() && members.is_none() && count > 0)
+                    {
+                        Err(format!(
+                            "unexpected value of type '{}'",
+                            id_str(world, value_type)
+                        ))
+                    } else {
+                        if let Some(index) = member {
+                            self.out.put_u32(index);
+                        }
+                        count += 1;
+                        self.value(value_type, value).map_err(|e| e.to_string())
+                    }
+                }
+            };
+            match result {
+                Ok(()) => true,
+                Err(e) => {
+                    error = Some(e);
+                    false
+                }
+            }
+        });
+        let encoded = std::mem::replace(&mut self.out, outer);
+
+        if let Some(error) = error.or(result.err()) {
+            return Err(BinaryError::InvalidData(format!(
+                "cannot serialize opaque type '{}': {error}",
+                id_str(world, type_id)
+            )));
+        }
+        if elem.is_some() || members.is_some() {
+            self.out.put_u32(count);
+        } else if count == 0 {
+            return Err(BinaryError::InvalidData(format!(
+                "opaque type '{}' did not serialize a value",
+                id_str(world, type_id)
+            )));
+        }
+        self.out.extend_from_slice(&encoded);
+        Ok(())
+    }
+}
+
+/// An entity stored in the data.
+struct StoredEntity {
+    id: u64,
+    parent: u64,
+    name: Option<CString>,
+}
+
+/// A table stored in the data.
+struct StoredTable {
+    /// The ids of the table as they are in the data, with their type.
+    ids: Vec<(u64, u64)>,
+    /// The indices of the entities in the table in the stored entities.
+    entities: Vec<usize>,
+    /// The position of the component values in the data.
+    values: usize,
+}
+
+struct Decoder<'d> {
+    world: *mut sys::ecs_world_t,
+    data: &'d [u8],
+    pos: usize,
+    /// The entities of the world, by the low 32 bits of their id in the data.
+    entities: FxHashMap<u32, u64>,
+    /// The stored entities that don't exist yet, and are created when the data
+    /// is applied to the world.
+    created: FxHashSet<u32>,
+    pod: FxHashMap<u64, bool>,
+    /// Entity ids in the data are ids of the world, for values that are
+    /// decoded in the world they were encoded in.
+    keep_ids: bool,
+}
+
+impl<'d> Decoder<'d> {
+    fn new(world: *mut sys::ecs_world_t, data: &'d [u8]) -> Self {
+        Self {
+            world,
+            data,
+            pos: 0,
+            entities: FxHashMap::default(),
+            created: FxHashSet::default(),
+            pod: FxHashMap::default(),
+            keep_ids: false,
+        }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'d [u8], BinaryError> {
+        if self.data.len() - self.pos < len {
+            return Err(BinaryError::InvalidData(
+                "unexpected end of data".to_owned(),
+            ));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, BinaryError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, BinaryError> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, BinaryError> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn str(&mut self) -> Result<Option<&'d str>, BinaryError> {
+        let len = self.u32()?;
+        if len == NULL_STRING {
+            return Ok(None);
+        }
+        let bytes = self.bytes(len as usize)?;
+        std::str::from_utf8(bytes)
+            .map(Some)
+            .map_err(|e| BinaryError::InvalidData(e.to_string()))
+    }
+
+    /// Returns the entity of the world for an entity id in the data. Returns 0
+    /// for entities that are not created yet.
+    fn entity(&self, e: u64) -> Result<u64, BinaryError> {
+        if self.keep_ids {
+            return Ok(e);
+        }
+        if e == 0 || self.is_created(e) {
+            return Ok(0);
+        }
+        self.entities
+            .get(&(e as u32))
+            .copied()
+            .ok_or_else(|| BinaryError::InvalidData(format!("reference to unknown entity {e}")))
+    }
+
+    /// Returns true if an entity id in the data is a stored entity that is not
+    /// created yet.
+    fn is_created(&self, e: u64) -> bool {
+        e != 0 && !self.entities.contains_key(&(e as u32)) && self.created.contains(&(e as u32))
+    }
+
+    /// Returns the id of the world for an id in the data.
+    fn id(&self, id: u64) -> Result<u64, BinaryError> {
+        if id == 0 {
+            return Ok(0);
+        }
+        if is_pair(id) {
+            let first = self.entity(pair_first(id))? & sys::ECS_ENTITY_MASK as u64;
+            let second = self.entity(pair_second(id))? & sys::ECS_ENTITY_MASK as u64;
+            Ok(ECS_PAIR | (first << 32) | second)
+        } else {
+            Ok(self.entity(id & sys::ECS_ENTITY_MASK as u64)?
+                | (id & sys::ECS_ID_FLAGS_MASK as u64))
+        }
+    }
+
+    fn read_entity(&mut self) -> Result<u64, BinaryError> {
+        let e = self.u64()?;
+        self.entity(e)
+    }
+
+    fn read_id(&mut self) -> Result<u64, BinaryError> {
+        let id = self.u64()?;
+        self.id(id)
+    }
+
+    /// Read and validate all data, and only then apply it to the world, so
+    /// that the world is left unchanged when the data is invalid.
+    unsafe fn decode(&mut self) -> Result<Vec<Entity>, BinaryError> {
+        let (stored, tables) = self.parse()?;
+        self.apply(&stored, &tables)
+    }
+
+    /// Read the data and validate it against the world, without changing the
+    /// world. Component values are read into temporary values, which checks
+    /// them the same way as when they are applied.
+    unsafe fn parse(&mut self) -> Result<(Vec<StoredEntity>, Vec<StoredTable>), BinaryError> {
+        let world = self.world;
+        if self.bytes(MAGIC.len())? != MAGIC {
+            return Err(BinaryError::InvalidData(
+                "data does not start with the binary header".to_owned(),
+            ));
+        }
+        let version = self.u32()?;
+        if version != VERSION {
+            return Err(BinaryError::InvalidData(format!(
+                "unsupported version {version}"
+            )));
+        }
+
+        for _ in 0..self.u32()? {
+            let e = self.u64()?;
+            let path = self.str()?.unwrap_or_default();
+            let resolved = lookup_entity(world, path).map_err(BinaryError::Mismatch)?;
+            self.entities.insert(e as u32, resolved);
+        }
+
+        for _ in 0..self.u32()? {
+            self.validate_type()?;
+        }
+
+        // named entities that already exist are reused, all other entities
+        // are created when the data is applied
+        let mut stored = Vec::new();
+        for _ in 0..self.u32()? {
+            let e = self.u64()?;
+            let parent = self.u64()?;
+            let parent_entity = self.entity(parent)?;
+            let name = self.str()?;
+            let name = name
+                .map(CString::new)
+                .transpose()
+                .map_err(|e| BinaryError::InvalidData(e.to_string()))?;
+
+            let entity = match &name {
+                Some(name) if !self.is_created(parent) => {
+                    sys::ecs_lookup_child(world, parent_entity, name.as_ptr())
+                }
+                _ => 0,
+            };
+            if entity != 0 {
+                self.entities.insert(e as u32, entity);
+            } else {
+                self.created.insert(e as u32);
+            }
+            stored.push(StoredEntity {
+                id: e,
+                parent,
+                name,
+            });
+        }
+
+        let mut tables = Vec::new();
+        for _ in 0..self.u32()? {
+            tables.push(self.parse_table(stored.len())?);
+        }
+
+        if self.pos != self.data.len() {
+            return Err(BinaryError::InvalidData(
+                "unexpected data after the last table".to_owned(),
+            ));
+        }
+        Ok((stored, tables))
+    }
+
+    /// Create the stored entities and set their components.
+    unsafe fn apply(
+        &mut self,
+        stored: &[StoredEntity],
+        tables: &[StoredTable],
+    ) -> Result<Vec<Entity>, BinaryError> {
+        let world = self.world;
+
+        // parents are stored before their children, so they exist when their
+        // children are created
+        let mut entities = Vec::new();
+        for s in stored {
+            let entity = match self.entities.get(&(s.id as u32)) {
+                Some(&entity) => entity,
+                None => {
+                    let parent = self.entity(s.parent)?;
+                    // an entity stored earlier in the data can have the same name
+                    let existing = match &s.name {
+                        Some(name) => sys::ecs_lookup_child(world, parent, name.as_ptr()),
+                        None => 0,
+                    };
+                    if existing != 0 {
+                        self.entities.insert(s.id as u32, existing);
+                        entities.push(existing);
+                        continue;
+                    }
+                    let entity = sys::ecs_new(world);
+                    if parent != 0 {
+                        sys::ecs_add_id(world, entity, ecs_pair(ECS_CHILD_OF, parent));
+                    }
+                    if let Some(name) = &s.name {
+                        sys::ecs_set_name(world, entity, name.as_ptr());
+                    }
+                    self.entities.insert(s.id as u32, entity);
+                    entity
+                }
+            };
+            entities.push(entity);
+        }
+
+        for table in tables {
+            self.apply_table(table, &entities)?;
+        }
+        Ok(entities.into_iter().map(Entity).collect())
+    }
+
+    /// Check that a type in the schema matches the type in the world.
+    unsafe fn validate_type(&mut self) -> Result<(), BinaryError> {
+        let world = self.world;
+        let type_id = self.read_entity()?;
+        let kind_code = self.u8()?;
+        let size = self.u32()? as usize;
+
+        let mismatch = || {
+            BinaryError::Mismatch(format!(
+                "type '{}' differs from the type in the data",
+                id_str(world, type_id)
+            ))
+        };
+        let kind = type_kind(world, type_id)
+            .ok_or_else(|| BinaryError::Mismatch(no_reflection(world, type_id)))?;
+        let actual_code = get::<sys::EcsType>(world, type_id, flecs::meta::Type::ID)
+            .map_or(0, |ty| ty.kind as u8);
+        if kind_code != actual_code || size != type_size(world, type_id) {
+            return Err(mismatch());
+        }
+
+        let matches = match kind {
+            Kind::Primitive(primitive) => self.u8()? == primitive as u8,
+            Kind::Enum(e) => {
+                let constants = enum_constants(e);
+                let mut matches = self.u32()? as usize == constants.len();
+                for (value, name) in &constants {
+                    if !matches {
+                        break;
+                    }
+                    matches = self.str()? == Some(*name) && self.u64()? == *value as u64;
+                }
+                matches
+            }
+            Kind::Bitmask(b) => {
+                let constants = bitmask_constants(b);
+                let mut matches = self.u32()? as usize == constants.len();
+                for (value, name) in &constants {
+                    if !matches {
+                        break;
+                    }
+                    matches = self.str()? == Some(*name) && self.u32()? == *value;
+                }
+                matches
+            }
+            Kind::Struct(members) => {
+                let mut matches = self.u32()? as usize == members.len();
+                for m in members {
+                    if !matches {
+                        break;
+                    }
+                    matches = self.str()? == Some(cstr(m.name))
+                        && self.read_entity()? == m.type_
+                        && self.u32()? == m.count as u32
+                        && self.u32()? == m.offset as u32;
+                }
+                matches
+            }
+            Kind::Array(array) => {
+                self.read_entity()? == array.type_ && self.u32()? == array.count as u32
+            }
+            Kind::Vector(vector) => self.read_entity()? == vector.type_,
+            Kind::Opaque(opaque) => self.read_entity()? == opaque.as_type,
+        };
+        if matches {
+            Ok(())
+        } else {
+            Err(mismatch())
+        }
+    }
+
+    /// Read a table and validate its ids and component values.
+    unsafe fn parse_table(&mut self, stored_count: usize) -> Result<StoredTable, BinaryError> {
+        let world = self.world;
+
+        let mut ids = Vec::new();
+        for _ in 0..self.u32()? {
+            let id = self.u64()?;
+            let type_id = self.read_entity()?;
+            if self.id_type(id)? != type_id {
+                return Err(BinaryError::Mismatch(format!(
+                    "id '{}' has a different type than in the data",
+                    id_str(world, self.id(id)?)
+                )));
+            }
+            ids.push((id, type_id));
+        }
+
+        let mut entities = Vec::new();
+        for _ in 0..self.u32()? {
+            let index = self.u32()? as usize;
+            if index >= stored_count {
+                return Err(BinaryError::InvalidData(format!(
+                    "entity index {index} is out of range"
+                )));
+            }
+            entities.push(index);
+        }
+
+        let values = self.pos;
+        for &(_, type_id) in &ids {
+            if type_id == 0 {
+                continue;
+            }
+            let size = type_size(world, type_id);
+            let pod = is_pod(world, &mut self.pod, type_id);
+            for _ in &entities {
+                if pod {
+                    self.bytes(size)?;
+                } else {
+                    let ptr = sys::ecs_value_new(world, type_id);
+                    let result = self.value(type_id, ptr);
+                    sys::ecs_value_free(world, type_id, ptr);
+                    result?;
+                }
+            }
+        }
+
+        Ok(StoredTable {
+            ids,
+            entities,
+            values,
+        })
+    }
+
+    /// Returns the type of an id in the data in the world. Stored entities
+    /// that are not created yet are not components, so the type of a pair
+    /// with such an entity is the type of its other element.
+    unsafe fn id_type(&self, id: u64) -> Result<u64, BinaryError> {
+        let world = self.world;
+        let component_type = |id: u64| {
+            let type_info = sys::ecs_get_type_info(world, id);
+            if type_info.is_null() || (*type_info).size == 0 {
+                0
+            } else {
+                (*type_info).component
+            }
+        };
+
+        let resolved = self.id(id)?;
+        let (first, second) = if is_pair(id) {
+            (pair_first(id), pair_second(id))
+        } else {
+            (id & sys::ECS_ENTITY_MASK as u64, 0)
+        };
+        if !self.is_created(first) && !self.is_created(second) {
+            if resolved == 0 || !sys::ecs_id_is_valid(world, resolved) {
+                return Err(BinaryError::InvalidData(format!("invalid id {id}")));
+            }
+            return Ok(component_type(resolved));
+        }
+
+        if !is_pair(id) {
+            return Ok(0);
+        }
+        if !self.is_created(first) {
+            let first = self.entity(first)?;
+            if sys::ecs_has_id(world, first, ECS_PAIR_IS_TAG) {
+                return Ok(0);
+            }
+            return Ok(component_type(first));
+        }
+        if !self.is_created(second) {
+            return Ok(component_type(self.entity(second)?));
+        }
+        Ok(0)
+    }
+
+    /// Add the ids of a table to its entities, and set the component values.
+    unsafe fn apply_table(
+        &mut self,
+        table: &StoredTable,
+        stored: &[u64],
+    ) -> Result<(), BinaryError> {
+        let world = self.world;
+
+        let ids = table
+            .ids
+            .iter()
+            .map(|&(id, type_id)| Ok((self.id(id)?, type_id)))
+            .collect::<Result<Vec<_>, BinaryError>>()?;
+        let entities: Vec<u64> = table.entities.iter().map(|&index| stored[index]).collect();
+
+        // move the entities to their table at once, instead of adding each id
+        let mut moves: FxHashMap<*mut sys::ecs_table_t, (*mut sys::ecs_table_t, Vec<u64>)> =
+            FxHashMap::default();
+        for &e in &entities {
+            let table = sys::ecs_get_table(world, e);
+            let (dst, added) = moves.entry(table).or_insert_with(|| {
+                let mut dst = table;
+                let mut added = Vec::new();
+                for &(id, _) in &ids {
+                    let next = sys::ecs_table_add_id(world, dst, id);
+                    if next != dst {
+                        added.push(id);
+                        dst = next;
+                    }
+                }
+                (dst, added)
+            });
+            if *dst != table {
+                let added = sys::ecs_type_t {
+                    array: added.as_mut_ptr(),
+                    count: added.len() as i32,
+                };
+                sys::ecs_commit(
+                    world,
+                    e,
+                    std::ptr::null_mut(),
+                    *dst,
+                    &added,
+                    std::ptr::null(),
+                );
+            }
+        }
+
+        self.pos = table.values;
+        for &(id, type_id) in &ids {
+            if type_id == 0 {
+                continue;
+            }
+            let size = type_size(world, type_id);
+            let pod = is_pod(world, &mut self.pod, type_id);
+            for &e in &entities {
+                let ptr = sys::ecs_get_mut_id(world, e, id);
+                if pod {
+                    self.copy(ptr, size)?;
+                } else {
+                    self.value(type_id, ptr)?;
+                }
+                sys::ecs_modified_id(world, e, id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy the bytes of a value, stored in little endian byte order.
+    unsafe fn copy(&mut self, ptr: *mut c_void, size: usize) -> Result<(), BinaryError> {
+        let bytes = self.bytes(size)?;
+        let dst = std::slice::from_raw_parts_mut(ptr as *mut u8, size);
+        dst.copy_from_slice(bytes);
+        if cfg!(target_endian = "big") {
+            dst.reverse();
+        }
+        Ok(())
+    }
+
+    unsafe fn value(&mut self, type_id: u64, ptr: *mut c_void) -> Result<(), BinaryError> {
+        let world = self.world;
+        let kind = type_kind(world, type_id)
+            .ok_or_else(|| BinaryError::Mismatch(no_reflection(world, type_id)))?;
+        match kind {
+            Kind::Primitive(primitive) => self.primitive(primitive, ptr)?,
+            Kind::Bitmask(_) => *(ptr as *mut u32) = self.u32()?,
+            Kind::Enum(e) => {
+                let value = self.u64()? as i64;
+                if sys::ecs_map_get(&e.constants, value as u64).is_null() {
+                    return Err(BinaryError::InvalidData(format!(
+                        "invalid value {value} for enum '{}'",
+                        id_str(world, type_id)
+                    )));
+                }
+                write_enum(ptr, type_size(world, type_id), value);
+            }
+            Kind::Struct(members) => {
+                if is_pod(world, &mut self.pod, type_id) {
+                    return self.copy(ptr, type_size(world, type_id));
+                }
+                for m in members {
+                    let size = type_size(world, m.type_);
+                    for i in 0..(m.count as usize).max(1) {
+                        self.value(m.type_, ptr.byte_add(m.offset as usize + i * size))?;
+                    }
+                }
+            }
+            Kind::Array(array) => {
+                let size = type_size(world, array.type_);
+                for i in 0..array.count as usize {
+                    self.value(array.type_, ptr.byte_add(i * size))?;
+                }
+            }
+            Kind::Vector(vector) => {
+                let vec = ptr as *mut sys::ecs_vec_t;
+                let count = self.u32()? as usize;
+                for i in 0..count {
+                    let elem = vec_element(world, vec, vector.type_, i);
+                    self.value(vector.type_, elem)?;
+                }
+                vec_truncate(world, vec, vector.type_, count);
+            }
+            Kind::Opaque(opaque) => self.opaque(type_id, opaque, ptr)?,
+        }
+        Ok(())
+    }
+
+    unsafe fn primitive(
+        &mut self,
+        kind: sys::ecs_primitive_kind_t,
+        ptr: *mut c_void,
+    ) -> Result<(), BinaryError> {
+        if let Some(size) = primitive_size(kind) {
+            return self.copy(ptr, size);
+        }
+        match kind {
+            sys::ecs_primitive_kind_t_EcsBool => *(ptr as *mut bool) = self.bool()?,
+            sys::ecs_primitive_kind_t_EcsUPtr => {
+                *(ptr as *mut usize) = usize::try_from(self.u64()?)
+                    .map_err(|e| BinaryError::InvalidData(e.to_string()))?;
+            }
+            sys::ecs_primitive_kind_t_EcsIPtr => {
+                *(ptr as *mut isize) = isize::try_from(self.u64()? as i64)
+                    .map_err(|e| BinaryError::InvalidData(e.to_string()))?;
+            }
+            sys::ecs_primitive_kind_t_EcsString => {
+                let string = self.str()?;
+                write_string(ptr, string).map_err(BinaryError::InvalidData)?;
+            }
+            sys::ecs_primitive_kind_t_EcsEntity => {
+                *(ptr as *mut u64) = self.read_entity()?;
+            }
+            _ => *(ptr as *mut u64) = self.read_id()?,
+        }
+        Ok(())
+    }
+
+    fn bool(&mut self) -> Result<bool, BinaryError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(BinaryError::InvalidData(format!(
+                "invalid bool value {value}"
+            ))),
+        }
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], BinaryError> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    /// Read a primitive value that is assigned to an opaque type.
+    fn scalar(&mut self, kind: sys::ecs_primitive_kind_t) -> Result<Scalar, BinaryError> {
+        Ok(match kind {
+            sys::ecs_primitive_kind_t_EcsBool => Scalar::Bool(self.bool()?),
+            sys::ecs_primitive_kind_t_EcsChar | sys::ecs_primitive_kind_t_EcsI8 => {
+                Scalar::Int(self.u8()? as i8 as i64)
+            }
+            sys::ecs_primitive_kind_t_EcsByte | sys::ecs_primitive_kind_t_EcsU8 => {
+                Scalar::UInt(self.u8()? as u64)
+            }
+            sys::ecs_primitive_kind_t_EcsI16 => {
+                Scalar::Int(i16::from_le_bytes(self.array()?) as i64)
+            }
+            sys::ecs_primitive_kind_t_EcsU16 => {
+                Scalar::UInt(u16::from_le_bytes(self.array()?) as u64)
+            }
+            sys::ecs_primitive_kind_t_EcsI32 => {
+                Scalar::Int(i32::from_le_bytes(self.array()?) as i64)
+            }
+            sys::ecs_primitive_kind_t_EcsU32 => {
+                Scalar::UInt(u32::from_le_bytes(self.array()?) as u64)
+            }
+            sys::ecs_primitive_kind_t_EcsI64 | sys::ecs_primitive_kind_t_EcsIPtr => {
+                Scalar::Int(self.u64()? as i64)
+            }
+            sys::ecs_primitive_kind_t_EcsU64 | sys::ecs_primitive_kind_t_EcsUPtr => {
+                Scalar::UInt(self.u64()?)
+            }
+            sys::ecs_primitive_kind_t_EcsF32 => {
+                Scalar::Float(f32::from_le_bytes(self.array()?) as f64)
+            }
+            sys::ecs_primitive_kind_t_EcsF64 => Scalar::Float(f64::from_le_bytes(self.array()?)),
+            sys::ecs_primitive_kind_t_EcsString => {
+                Scalar::Str(self.str()?.unwrap_or_default().to_owned())
+            }
+            sys::ecs_primitive_kind_t_EcsEntity => Scalar::UInt(self.read_entity()?),
+            _ => Scalar::UInt(self.read_id()?),
+        })
+    }
+
+    unsafe fn opaque(
+        &mut self,
+        type_id: u64,
+        opaque: &sys::EcsOpaque,
+        ptr: *mut c_void,
+    ) -> Result<(), BinaryError> {
+        let world = self.world;
+        let as_type = opaque.as_type;
+        let missing = |callback: &str| {
+            BinaryError::Mismatch(format!(
+                "opaque type '{}' has no {callback} callback",
+                id_str(world, type_id)
+            ))
+        };
+        let kind = type_kind(world, as_type)
+            .ok_or_else(|| BinaryError::Mismatch(no_reflection(world, as_type)))?;
+
+        let elem = match kind {
+            Kind::Array(array) => Some(array.type_),
+            Kind::Vector(vector) => Some(vector.type_),
+            _ => None,
+        };
+        if let Some(elem) = elem {
+            let count = self.u32()? as usize;
+            let ensure_element = opaque
+                .ensure_element
+                .ok_or_else(|| missing("ensure_element"))?;
+            for i in 0..count {
+                let elem_ptr = ensure_element(ptr, i);
+                if elem_ptr.is_null() {
+                    return Err(BinaryError::Mismatch(format!(
+                        "cannot create element {i} of opaque type '{}'",
+                        id_str(world, type_id)
+                    )));
+                }
+                self.value(elem, elem_ptr)?;
+            }
+            if let Some(resize) = opaque.resize {
+                resize(ptr, count);
+            }
+            return Ok(());
+        }
+
+        let (primitive, scalar) = match kind {
+            Kind::Struct(members) => {
+                let ensure_member = opaque
+                    .ensure_member
+                    .ok_or_else(|| missing("ensure_member"))?;
+                for _ in 0..self.u32()? {
+                    let index = self.u32()? as usize;
+                    let m = members.get(index).ok_or_else(|| {
+                        BinaryError::InvalidData(format!("member index {index} is out of range"))
+                    })?;
+                    let member_ptr = ensure_member(ptr, m.name);
+                    if member_ptr.is_null() {
+                        return Err(BinaryError::Mismatch(format!(
+                            "cannot assign member '{}' of opaque type '{}'",
+                            cstr(m.name),
+                            id_str(world, type_id)
+                        )));
+                    }
+                    self.value(m.type_, member_ptr)?;
+                }
+                return Ok(());
+            }
+            Kind::Primitive(primitive) => (primitive, self.scalar(primitive)?),
+            Kind::Enum(_) => (0, Scalar::Int(self.u64()? as i64)),
+            Kind::Bitmask(_) => (0, Scalar::UInt(self.u32()? as u64)),
+            _ => {
+                return Err(BinaryError::Mismatch(format!(
+                    "opaque type '{}' is described as an opaque type",
+                    id_str(world, type_id)
+                )))
+            }
+        };
+        assign_opaque(world, type_id, ptr, opaque, primitive, scalar).map_err(BinaryError::Mismatch)
+    }
+}
@@ -51,12 +51,22 @@ pub trait ExternalComponent<T> {
     fn deref_id<'a>(&self, world: impl WorldProvider<'a>) -> FetchedId<T>;
 }
 
+pub trait StdComponent<T> {
+    fn deref_id<'a>(&self, world: impl WorldProvider<'a>) -> FetchedId<T>;
+}
+
 impl<T: ComponentId> FlecsComponent<T> for &&ComponentIdFetcher<T> {
     fn deref_id<'a>(&self, world: impl WorldProvider<'a>) -> FetchedId<T> {
         FetchedId::new(T::id(world))
     }
 }
 
+impl<T: StdType> StdComponent<T> for &&ComponentIdFetcher<T> {
+    fn deref_id<'a>(&self, world: impl WorldProvider<'a>) -> FetchedId<T> {
+        FetchedId::new(T::std_id(world.world()))
+    }
+}
+
 impl<T: 'static> ExternalComponent<T> for &ComponentIdFetcher<T> {
     fn deref_id<'a>(&self, world: impl WorldProvider<'a>) -> FetchedId<T> {
         let world = world.world();
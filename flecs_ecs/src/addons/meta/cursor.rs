@@ -48,6 +48,21 @@ impl<'a> Cursor<'a> {
 
     /// Move to element by index
     pub fn elem(&mut self, elem: i32) -> i32 {
+        // `ecs_meta_elem` counts the elements of an opaque collection with the
+        // pointer of the parent scope, which is only correct for collections
+        // at offset zero, so opaque collections are bounds checked here.
+        let scope = &mut self.cursor.scope[self.cursor.depth as usize];
+        if scope.is_collection && !scope.opaque.is_null() {
+            if let Some(count) = unsafe { (*scope.opaque).count } {
+                if elem < 0 || elem as usize >= unsafe { count(scope.ptr) } {
+                    return -1;
+                }
+                scope.elem_cur = elem;
+                scope.op_cur = 0;
+                return 0;
+            }
+        }
+
         unsafe { sys::ecs_meta_elem(&mut self.cursor, elem) }
     }
 
@@ -168,9 +183,15 @@ impl<'a> Cursor<'a> {
     }
 
     /// Get string value
-    pub fn get_string(&self) -> *const i8 {
-        // TODO: Rustify this to return &str
-        unsafe { sys::ecs_meta_get_string(&self.cursor) }
+    pub fn get_string(&self) -> &str {
+        let string = unsafe { sys::ecs_meta_get_string(&self.cursor) };
+        if string.is_null() {
+            ""
+        } else {
+            unsafe { std::ffi::CStr::from_ptr(string) }
+                .to_str()
+                .unwrap_or_default()
+        }
     }
 
     /// Get entity value
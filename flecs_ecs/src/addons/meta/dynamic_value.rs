@@ -0,0 +1,435 @@
+//! Owned values of component types that are only known at runtime.
+//!
+//! [`DynamicValue`] holds a constructed value of any component, identified by
+//! its component id instead of its Rust type. This is useful for tooling such
+//! as editors, which inspect and modify components through their reflection
+//! data.
+use std::ffi::c_void;
+
+use super::reflection::{id_str, no_reflection, take_c_string, type_kind};
+use super::{Cursor, ReflectValue};
+use crate::core::*;
+use crate::sys;
+
+/// An owned value of a component, of which the type is known at runtime.
+///
+/// The value is constructed with the hooks of its type, and destructed when
+/// the `DynamicValue` is dropped. Members are read and written by path, such
+/// as `"transform.position.x"`, where numeric segments index into arrays and
+/// vectors, as in `"inventory.0"`.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// #[meta]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Position>().meta();
+///
+/// let mut value = DynamicValue::new(&world, world.component_id::<Position>());
+/// value.set_path("x", 10.0).unwrap();
+///
+/// let e = world.entity().set_dynamic(&value);
+/// e.get::<&Position>(|pos| assert_eq!(pos.x, 10.0));
+/// ```
+pub struct DynamicValue<'a> {
+    world: WorldRef<'a>,
+    id: u64,
+    type_id: Entity,
+    ptr: *mut c_void,
+}
+
+impl<'a> DynamicValue<'a> {
+    /// Create a default constructed value of a component or pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` has no data, or if it is a Rust component that does
+    /// not implement `Default`.
+    pub fn new(world: impl WorldProvider<'a>, id: impl IntoId) -> Self {
+        let world = world.world().real_world();
+        let id = *id.into();
+        let ti = Self::type_info(world, id);
+
+        let hooks = unsafe { &(*ti).hooks };
+        ecs_assert!(
+            hooks.ctor.is_some() || hooks.dtor.is_none(),
+            FlecsErrorCode::InvalidOperation,
+            "component '{}' does not implement Default",
+            unsafe { id_str(world.world_ptr(), id) }
+        );
+
+        let ptr = unsafe { sys::ecs_value_new_w_type_info(world.world_ptr_mut(), ti) };
+        Self {
+            world,
+            id,
+            type_id: unsafe { (*ti).component }.into(),
+            ptr,
+        }
+    }
+
+    /// Create a value that is a copy of the value at `src`.
+    ///
+    /// # Safety
+    ///
+    /// `src` must point to a valid value of the type of `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` has no data, or if it is a Rust component that does
+    /// not implement `Clone`.
+    pub unsafe fn from_ptr(
+        world: impl WorldProvider<'a>,
+        id: impl IntoId,
+        src: *const c_void,
+    ) -> Self {
+        let world = world.world().real_world();
+        let id = *id.into();
+        let ti = Self::type_info(world, id);
+        let hooks = unsafe { &(*ti).hooks };
+
+        let ptr = unsafe { sys::ecs_value_new_w_type_info(world.world_ptr_mut(), ti) };
+        // Values of types without a constructor are zero initialized, and
+        // have to be copy constructed to not drop the zeroed value.
+        unsafe {
+            if hooks.ctor.is_some() {
+                sys::ecs_value_copy_w_type_info(world.world_ptr(), ti, ptr, src);
+            } else if let Some(copy_ctor) = hooks.copy_ctor {
+                copy_ctor(ptr, src, 1, ti);
+            } else {
+                std::ptr::copy_nonoverlapping(
+                    src as *const u8,
+                    ptr as *mut u8,
+                    (*ti).size as usize,
+                );
+            }
+        }
+
+        Self {
+            world,
+            id,
+            type_id: unsafe { (*ti).component }.into(),
+            ptr,
+        }
+    }
+
+    fn type_info(world: WorldRef, id: u64) -> *const sys::ecs_type_info_t {
+        let ti = unsafe { sys::ecs_get_type_info(world.world_ptr(), id) };
+        ecs_assert!(
+            !ti.is_null(),
+            FlecsErrorCode::InvalidParameter,
+            "'{}' is not a component with data",
+            unsafe { id_str(world.world_ptr(), id) }
+        );
+        ti
+    }
+
+    /// Returns the component or pair of the value.
+    pub fn id(&self) -> IdView<'a> {
+        IdView::new_from(self.world, self.id)
+    }
+
+    /// Returns the type of the value.
+    pub fn type_id(&self) -> Entity {
+        self.type_id
+    }
+
+    /// Returns a pointer to the value.
+    pub fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+
+    /// Returns a mutable pointer to the value.
+    pub fn as_mut_ptr(&mut self) -> *mut c_void {
+        self.ptr
+    }
+
+    /// Returns a read-only view of the value.
+    pub fn reflect(&self) -> ReflectValue<'_> {
+        unsafe { ReflectValue::from_ptr(self.world, self.type_id, self.ptr) }
+    }
+
+    /// Returns a cursor to read and write the value.
+    pub fn cursor(&mut self) -> Cursor<'_> {
+        Cursor::new(self.world, self.type_id, self.ptr)
+    }
+
+    /// Moves a cursor to the member or element at `path`.
+    fn cursor_at(&self, path: &str) -> Result<Cursor<'_>, FlecsError> {
+        let world = self.world.world_ptr();
+        if unsafe { type_kind(world, *self.type_id) }.is_none() {
+            return Err(FlecsError::new(FlecsErrorCode::InvalidOperation, unsafe {
+                no_reflection(world, *self.type_id)
+            }));
+        }
+
+        let mut cursor = Cursor::new(self.world, self.type_id, self.ptr);
+        if path.is_empty() {
+            return Ok(cursor);
+        }
+
+        for segment in path.split('.') {
+            let result = if segment.is_empty() {
+                -1
+            } else if let Ok(index) = segment.parse::<i32>() {
+                cursor.push();
+                cursor.elem(index)
+            } else {
+                cursor.push();
+                cursor.member(segment)
+            };
+
+            if result != 0 {
+                return Err(FlecsError::new(
+                    FlecsErrorCode::InvalidParameter,
+                    format!("cannot resolve '{segment}' in path '{path}'"),
+                )
+                .with_id(self.type_id));
+            }
+        }
+        Ok(cursor)
+    }
+
+    /// Returns a view of the member or element at `path`. An empty path
+    /// returns the whole value.
+    ///
+    /// # Errors
+    ///
+    /// * [`FlecsErrorCode::InvalidOperation`] if the type has no reflection data.
+    /// * [`FlecsErrorCode::InvalidParameter`] if the path does not exist in the
+    ///   type, or if an index is out of range.
+    pub fn get_path(&self, path: &str) -> Result<ReflectValue<'_>, FlecsError> {
+        let mut cursor = self.cursor_at(path)?;
+        let type_id = cursor.get_type().id();
+        let ptr = cursor.get_ptr();
+        Ok(unsafe { ReflectValue::from_ptr(self.world, type_id, ptr) })
+    }
+
+    /// Assign the member or element at `path`. Values are converted to the
+    /// type of the member, such as an integer to a float, or a string to an
+    /// enum constant.
+    ///
+    /// # Errors
+    ///
+    /// * [`FlecsErrorCode::InvalidOperation`] if the type has no reflection data.
+    /// * [`FlecsErrorCode::InvalidParameter`] if the path does not exist in the
+    ///   type, or if an index is out of range.
+    /// * [`FlecsErrorCode::InvalidConversion`] if the value can't be converted
+    ///   to the type of the member.
+    pub fn set_path(&mut self, path: &str, value: impl CursorValue) -> Result<(), FlecsError> {
+        let mut cursor = self.cursor_at(path)?;
+        if value.assign(&mut cursor) != 0 {
+            return Err(FlecsError::new(
+                FlecsErrorCode::InvalidConversion,
+                format!("cannot assign value to '{path}'"),
+            )
+            .with_id(self.type_id));
+        }
+        Ok(())
+    }
+
+    /// Serialize the value to JSON.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_ptr_to_json`
+    #[cfg(feature = "flecs_json")]
+    pub fn to_json(&self) -> String {
+        unsafe {
+            take_c_string(sys::ecs_ptr_to_json(
+                self.world.world_ptr(),
+                *self.type_id,
+                self.ptr,
+            ))
+        }
+    }
+
+    /// Deserialize JSON into the value. Members that are not in the JSON
+    /// keep their current value.
+    ///
+    /// # Errors
+    ///
+    /// * [`FlecsErrorCode::InvalidParameter`] if the JSON can't be parsed into
+    ///   the type of the value.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_ptr_from_json`
+    #[cfg(feature = "flecs_json")]
+    pub fn from_json(
+        &mut self,
+        json: &str,
+        desc: Option<&crate::addons::json::FromJsonDesc>,
+    ) -> Result<(), FlecsError> {
+        let c_json = compact_str::format_compact!("{}\0", json);
+        let end = unsafe {
+            sys::ecs_ptr_from_json(
+                self.world.world_ptr(),
+                *self.type_id,
+                self.ptr,
+                c_json.as_ptr() as *const _,
+                desc.map_or(std::ptr::null(), |desc| desc as *const _),
+            )
+        };
+
+        if end.is_null() {
+            return Err(FlecsError::new(
+                FlecsErrorCode::InvalidParameter,
+                format!("failed to parse JSON '{json}'"),
+            )
+            .with_id(self.type_id));
+        }
+        Ok(())
+    }
+
+    /// Serialize the value to an expression.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_ptr_to_expr`
+    #[cfg(feature = "flecs_script")]
+    pub fn to_expr(&self) -> String {
+        crate::addons::script::Script::to_expr_id(self.world, self.type_id, self.ptr)
+    }
+
+    /// Evaluate an expression into the value. Members that are not assigned
+    /// by the expression keep their current value.
+    ///
+    /// # See also
+    ///
+    /// * [`World::eval_expr()`]
+    /// * C API: `ecs_script_expr_run`
+    #[cfg(feature = "flecs_script")]
+    pub fn from_expr(
+        &mut self,
+        expr: &str,
+        vars: Option<&crate::addons::script::ScriptVars>,
+    ) -> Result<(), crate::addons::script::ScriptError> {
+        unsafe {
+            crate::addons::script::run_expr(
+                self.world.world_ptr_mut(),
+                *self.type_id,
+                self.ptr,
+                expr,
+                vars,
+            )
+        }
+    }
+}
+
+impl Clone for DynamicValue<'_> {
+    /// # Panics
+    ///
+    /// Panics if the value is a Rust component that does not implement `Clone`.
+    fn clone(&self) -> Self {
+        unsafe { Self::from_ptr(self.world, self.id, self.ptr) }
+    }
+}
+
+impl Drop for DynamicValue<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::ecs_value_free(self.world.world_ptr_mut(), *self.type_id, self.ptr) };
+    }
+}
+
+/// Values that can be assigned with a [`Cursor`].
+pub trait CursorValue {
+    /// Assign the value at the current position of the cursor. Returns zero
+    /// on success.
+    fn assign(self, cursor: &mut Cursor) -> i32;
+}
+
+macro_rules! impl_cursor_value {
+    ($method:ident($cast:ty): $($t:ty),*) => {
+        $(
+            impl CursorValue for $t {
+                fn assign(self, cursor: &mut Cursor) -> i32 {
+                    cursor.$method(self as $cast)
+                }
+            }
+        )*
+    };
+}
+
+impl_cursor_value!(set_int(i64): i8, i16, i32, i64, isize);
+impl_cursor_value!(set_uint(u64): u8, u16, u32, u64, usize);
+impl_cursor_value!(set_float(f64): f32, f64);
+impl_cursor_value!(set_bool(bool): bool);
+impl_cursor_value!(set_char(char): char);
+
+impl CursorValue for &str {
+    fn assign(self, cursor: &mut Cursor) -> i32 {
+        cursor.set_string(self)
+    }
+}
+
+impl CursorValue for &String {
+    fn assign(self, cursor: &mut Cursor) -> i32 {
+        cursor.set_string(self)
+    }
+}
+
+impl CursorValue for String {
+    fn assign(self, cursor: &mut Cursor) -> i32 {
+        cursor.set_string(&self)
+    }
+}
+
+impl CursorValue for Entity {
+    fn assign(self, cursor: &mut Cursor) -> i32 {
+        cursor.set_entity(self)
+    }
+}
+
+impl CursorValue for EntityView<'_> {
+    fn assign(self, cursor: &mut Cursor) -> i32 {
+        cursor.set_entity(self)
+    }
+}
+
+impl<'a> EntityView<'a> {
+    /// Set a component or pair from a [`DynamicValue`]. The value is copied
+    /// to the entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a Rust component that does not implement `Clone`.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_set_id`
+    pub fn set_dynamic(self, value: &DynamicValue) -> Self {
+        let world = self.world.world_ptr_mut();
+        let size = unsafe { (*sys::ecs_get_type_info(world, value.id)).size } as usize;
+        unsafe { sys::ecs_set_id(world, *self.id, value.id, size, value.ptr) };
+        self
+    }
+
+    /// Returns a copy of a component or pair of the entity as a
+    /// [`DynamicValue`], or `None` if the entity does not have it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component is a Rust component that does not implement
+    /// `Clone`.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_get_id`
+    pub fn get_dynamic(self, id: impl IntoId) -> Option<DynamicValue<'a>> {
+        let id = *id.into();
+        let ptr = unsafe { sys::ecs_get_id(self.world.world_ptr(), *self.id, id) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { DynamicValue::from_ptr(self.world, id, ptr) })
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Reflection for Rust enums with data-carrying variants, which the `#[meta]`
+//! attribute of the `Component` derive describes as tagged unions.
+//!
+//! The enum is an opaque type with a struct layout that has a member for every
+//! variant, of which only the active variant is serialized, as in
+//! `{"Circle": {"r": 1}}`. A variant with fields has an opaque type of its own
+//! over the enum, such as `Shape.Circle`, so that its fields are read and
+//! written in place. Unit variants are reflected as booleans.
+//!
+//! Deserializing a variant that isn't active replaces the value with that
+//! variant, with default values for its fields.
+use std::cell::Cell;
+use std::ffi::c_void;
+
+use super::*;
+
+impl<'a, T: 'static> Component<'a, T> {
+    /// Register the type of a variant with fields, which is an opaque type over
+    /// the enum named `name` in the scope of the enum. `fields` is a struct
+    /// that describes the fields of the variant, the layout of which is only
+    /// used for reflection.
+    ///
+    /// The returned opaque type builder should serialize the fields of the
+    /// variant and hand out pointers to them with `ensure_member`.
+    pub fn variant(&self, name: &str, fields: impl Into<Entity>) -> Opaque<'a, T> {
+        let world = self.world();
+        let id = variant_id::<T>(world, name);
+        let mut ts = Opaque::<T>::new_id(world, id);
+        ts.as_type(fields);
+        ts
+    }
+}
+
+/// Returns the type of a variant with fields, registering it if it doesn't
+/// exist yet. Variant types get the lifecycle hooks of the enum, so that
+/// values of a variant type are valid values of the enum.
+fn variant_id<T: 'static>(world: WorldRef, name: &str) -> u64 {
+    let world_ptr = world.world_ptr_mut();
+    let parent = world.component_id_map::<T>();
+    let name = compact_str::format_compact!("{}\0", name);
+    let id = unsafe { sys::ecs_lookup_child(world_ptr, parent, name.as_ptr() as *const i8) };
+    if id != 0 {
+        return id;
+    }
+
+    // only the hooks that construct, copy and move values apply to variants
+    let hooks = unsafe { sys::ecs_get_hooks_id(world_ptr, parent).as_ref() }
+        .map(|hooks| sys::ecs_type_hooks_t {
+            ctor: hooks.ctor,
+            dtor: hooks.dtor,
+            copy: hooks.copy,
+            move_: hooks.move_,
+            copy_ctor: hooks.copy_ctor,
+            move_ctor: hooks.move_ctor,
+            ctor_move_dtor: hooks.ctor_move_dtor,
+            move_dtor: hooks.move_dtor,
+            ..Default::default()
+        })
+        .unwrap_or_default();
+
+    let entity_desc = sys::ecs_entity_desc_t {
+        name: name.as_ptr() as *const i8,
+        parent,
+        ..Default::default()
+    };
+    let entity = unsafe { sys::ecs_entity_init(world_ptr, &entity_desc) };
+    let type_info = sys::ecs_type_info_t {
+        world: world_ptr,
+        size: std::mem::size_of::<T>() as i32,
+        alignment: std::mem::align_of::<T>() as i32,
+        hooks,
+        component: 0,
+        name: std::ptr::null(),
+    };
+    unsafe { sys::ecs_component_init(world_ptr, &create_component_desc(entity, type_info)) }
+}
+
+/// Serialize the active variant of an enum, which has fields.
+pub fn serialize_variant<T: 'static>(s: &Serializer, name: &str, data: &T) -> i32 {
+    let world = unsafe { WorldRef::from_ptr(s.world as *mut sys::ecs_world_t) };
+    s.member(name);
+    s.value_id(
+        variant_id::<T>(world, name),
+        data as *const T as *const c_void,
+    )
+}
+
+/// Serialize the active variant of an enum, which is a unit variant.
+pub fn serialize_unit_variant(s: &Serializer, name: &str) -> i32 {
+    s.member(name);
+    s.value(&true)
+}
+
+thread_local! {
+    static UNIT_VARIANT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The member that is handed out for a unit variant by `ensure_member`, which
+/// accepts any boolean.
+pub fn unit_variant_ptr() -> *mut c_void {
+    UNIT_VARIANT.with(|value| value.as_ptr() as *mut c_void)
+}
@@ -183,8 +183,8 @@ macro_rules! meta_register_vector_func {
                 &mut data[elem]
             }
 
-            fn resize_vec(data: &mut Vec<$struct_type>, elem: usize) {
-                data.resize_with(elem + 1, || $struct_type { $($name : $value),* });
+            fn resize_vec(data: &mut Vec<$struct_type>, count: usize) {
+                data.resize_with(count, || $struct_type { $($name : $value),* });
             }
 
             // Ensure element exists, return
@@ -224,8 +224,8 @@ macro_rules! meta_register_vector_func {
                     &mut data[elem]
                 }
 
-                fn resize_vec(data: &mut Vec<$struct_type>, elem: usize) {
-                    data.resize_with(elem + 1, || $struct_type::$constructor($($args),*));
+                fn resize_vec(data: &mut Vec<$struct_type>, count: usize) {
+                    data.resize_with(count, || $struct_type::$constructor($($args),*));
                 }
 
                 // Ensure element exists, return
@@ -1,24 +1,39 @@
 #![doc(hidden)]
+mod binary;
 mod builtin;
 mod component_id_fetcher;
 mod cursor;
 mod declarations;
+mod dynamic_value;
+mod enum_variant;
 mod impl_bindings;
 mod impl_primitives;
 pub mod macros;
 mod meta_functions;
 mod meta_traits;
 mod opaque;
+mod reflect_value;
+mod reflection;
+#[cfg(feature = "serde")]
+mod serde_reflect;
+mod std_types;
 
 use std::ffi::{c_void, CStr};
 
+pub use binary::*;
 pub use builtin::*;
 pub use component_id_fetcher::*;
 pub use cursor::*;
 pub use declarations::*;
+pub use dynamic_value::*;
+pub use enum_variant::*;
 pub use macros::*;
 pub use meta_traits::MetaMember;
 pub use opaque::*;
+pub use reflect_value::*;
+#[cfg(feature = "serde")]
+pub use serde_reflect::*;
+pub use std_types::StdType;
 
 use crate::core::*;
 
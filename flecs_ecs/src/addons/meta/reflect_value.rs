@@ -0,0 +1,690 @@
+//! A safe, read-only view of values through the reflection data of their type.
+//!
+//! [`ReflectValue`] walks a value as a tree of typed nodes, which can be used
+//! to build inspectors, or serializers for formats that the meta addon does
+//! not support. The nodes of a value are either matched with
+//! [`ReflectValue::kind()`], or walked with a [`ReflectVisitor`].
+use std::ffi::{c_char, c_void};
+use std::ops::RangeInclusive;
+
+use super::reflection::*;
+use crate::core::*;
+use crate::sys;
+
+/// A read-only view of a value of a reflected type.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// #[meta]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Position>().meta();
+///
+/// let value = Position { x: 10.0, y: 20.0 };
+/// let Some(ReflectKind::Struct(position)) = ReflectValue::of(&world, &value).kind() else {
+///     panic!("expected a struct");
+/// };
+///
+/// for member in position.members() {
+///     let Some(ReflectKind::Primitive(value)) = member.value().kind() else {
+///         panic!("expected a primitive");
+///     };
+///     println!("{}: {}", member.name(), value.as_f64().unwrap());
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct ReflectValue<'a> {
+    world: WorldRef<'a>,
+    type_id: Entity,
+    ptr: *const c_void,
+}
+
+impl<'a> ReflectValue<'a> {
+    /// Create a view of the value at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the reflected type `type_id`, which
+    /// must not be moved, dropped or mutated while the view is used.
+    pub unsafe fn from_ptr(
+        world: impl WorldProvider<'a>,
+        type_id: impl Into<Entity>,
+        ptr: *const c_void,
+    ) -> Self {
+        Self {
+            world: world.world(),
+            type_id: type_id.into(),
+            ptr,
+        }
+    }
+
+    /// Create a view of a value of a reflected component.
+    pub fn of<T: ComponentId>(world: impl WorldProvider<'a>, value: &'a T) -> Self {
+        let world = world.world();
+        unsafe { Self::from_ptr(world, T::get_id(world), value as *const T as *const c_void) }
+    }
+
+    /// Returns the type of the value.
+    pub fn type_id(&self) -> Entity {
+        self.type_id
+    }
+
+    /// Returns the entity of the type of the value.
+    pub fn type_entity(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.type_id)
+    }
+
+    /// Returns a pointer to the value.
+    pub fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+
+    /// Returns the typed node of the value, or `None` if the type has no
+    /// reflection data.
+    pub fn kind(&self) -> Option<ReflectKind<'a>> {
+        let world = self.world;
+        let ptr = self.ptr;
+        unsafe {
+            Some(match type_kind(world.world_ptr(), *self.type_id)? {
+                Kind::Primitive(kind) => ReflectKind::Primitive(PrimitiveValue::read(kind, ptr)),
+                Kind::Bitmask(bitmask) => ReflectKind::Bitmask(BitmaskValue {
+                    value: *(ptr as *const u32),
+                    bitmask,
+                }),
+                Kind::Enum(constants) => ReflectKind::Enum(EnumValue {
+                    value: read_enum(ptr, type_size(world.world_ptr(), *self.type_id)),
+                    constants,
+                }),
+                Kind::Struct(members) => ReflectKind::Struct(StructValue {
+                    world,
+                    ptr,
+                    members,
+                }),
+                Kind::Array(array) => ReflectKind::Array(ListValue {
+                    world,
+                    elem: array.type_,
+                    ptr,
+                    count: array.count as usize,
+                }),
+                Kind::Vector(vector) => {
+                    let vec = &*(ptr as *const sys::ecs_vec_t);
+                    ReflectKind::Vector(ListValue {
+                        world,
+                        elem: vector.type_,
+                        ptr: vec.array,
+                        count: vec.count as usize,
+                    })
+                }
+                Kind::Opaque(opaque) => ReflectKind::Opaque(OpaqueValue { world, opaque, ptr }),
+            })
+        }
+    }
+
+    /// Walk the nodes of the value with a visitor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Names(Vec<String>);
+    ///
+    /// impl ReflectVisitor for Names {
+    ///     fn enter_member(&mut self, member: &ReflectMember) {
+    ///         self.0.push(member.name().to_owned());
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>().meta();
+    ///
+    /// let mut names = Names::default();
+    /// ReflectValue::of(&world, &Position { x: 1.0, y: 2.0 }).visit(&mut names);
+    /// assert_eq!(names.0, ["x", "y"]);
+    /// ```
+    pub fn visit<V: ReflectVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self.kind() {
+            None => visitor.visit_unknown(self),
+            Some(ReflectKind::Primitive(value)) => visitor.visit_primitive(value),
+            Some(ReflectKind::Enum(value)) => visitor.visit_enum(&value),
+            Some(ReflectKind::Bitmask(value)) => visitor.visit_bitmask(&value),
+            Some(ReflectKind::Struct(value)) => {
+                visitor.enter_struct(&value);
+                for member in value.members() {
+                    visitor.enter_member(&member);
+                    if member.count() > 1 {
+                        for (index, element) in member.elements().enumerate() {
+                            visitor.enter_element(index);
+                            element.visit(visitor);
+                            visitor.exit_element(index);
+                        }
+                    } else {
+                        member.value().visit(visitor);
+                    }
+                    visitor.exit_member(&member);
+                }
+                visitor.exit_struct(&value);
+            }
+            Some(ReflectKind::Array(value) | ReflectKind::Vector(value)) => {
+                visitor.enter_list(&value);
+                for (index, element) in value.iter().enumerate() {
+                    visitor.enter_element(index);
+                    element.visit(visitor);
+                    visitor.exit_element(index);
+                }
+                visitor.exit_list(&value);
+            }
+            Some(ReflectKind::Opaque(value)) => {
+                // the values of opaque collections are visited as elements
+                let is_list = unsafe {
+                    matches!(
+                        type_kind(self.world.world_ptr(), value.opaque.as_type),
+                        Some(Kind::Array(_) | Kind::Vector(_))
+                    )
+                };
+                let mut index = 0;
+                visitor.enter_opaque(&value);
+                value.for_each(|member, element| {
+                    if let Some(name) = member {
+                        visitor.opaque_member(name);
+                    }
+                    if is_list {
+                        visitor.enter_element(index);
+                        element.visit(visitor);
+                        visitor.exit_element(index);
+                        index += 1;
+                    } else {
+                        element.visit(visitor);
+                    }
+                });
+                visitor.exit_opaque(&value);
+            }
+        }
+    }
+}
+
+/// The typed node of a [`ReflectValue`].
+pub enum ReflectKind<'a> {
+    Primitive(PrimitiveValue<'a>),
+    Enum(EnumValue<'a>),
+    Bitmask(BitmaskValue<'a>),
+    Struct(StructValue<'a>),
+    Array(ListValue<'a>),
+    Vector(ListValue<'a>),
+    Opaque(OpaqueValue<'a>),
+}
+
+/// The value of a primitive type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrimitiveValue<'a> {
+    Bool(bool),
+    Char(i8),
+    Byte(u8),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    UPtr(usize),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    IPtr(isize),
+    F32(f32),
+    F64(f64),
+    /// A string, or `None` for a null string.
+    String(Option<&'a str>),
+    Entity(Entity),
+    Id(Id),
+}
+
+impl<'a> PrimitiveValue<'a> {
+    unsafe fn read(kind: sys::ecs_primitive_kind_t, ptr: *const c_void) -> Self {
+        match kind {
+            sys::ecs_primitive_kind_t_EcsBool => Self::Bool(*(ptr as *const bool)),
+            sys::ecs_primitive_kind_t_EcsChar => Self::Char(*(ptr as *const i8)),
+            sys::ecs_primitive_kind_t_EcsByte => Self::Byte(*(ptr as *const u8)),
+            sys::ecs_primitive_kind_t_EcsU8 => Self::U8(*(ptr as *const u8)),
+            sys::ecs_primitive_kind_t_EcsU16 => Self::U16(*(ptr as *const u16)),
+            sys::ecs_primitive_kind_t_EcsU32 => Self::U32(*(ptr as *const u32)),
+            sys::ecs_primitive_kind_t_EcsU64 => Self::U64(*(ptr as *const u64)),
+            sys::ecs_primitive_kind_t_EcsUPtr => Self::UPtr(*(ptr as *const usize)),
+            sys::ecs_primitive_kind_t_EcsI8 => Self::I8(*(ptr as *const i8)),
+            sys::ecs_primitive_kind_t_EcsI16 => Self::I16(*(ptr as *const i16)),
+            sys::ecs_primitive_kind_t_EcsI32 => Self::I32(*(ptr as *const i32)),
+            sys::ecs_primitive_kind_t_EcsI64 => Self::I64(*(ptr as *const i64)),
+            sys::ecs_primitive_kind_t_EcsIPtr => Self::IPtr(*(ptr as *const isize)),
+            sys::ecs_primitive_kind_t_EcsF32 => Self::F32(*(ptr as *const f32)),
+            sys::ecs_primitive_kind_t_EcsF64 => Self::F64(*(ptr as *const f64)),
+            sys::ecs_primitive_kind_t_EcsString => {
+                let string = *(ptr as *const *const c_char);
+                Self::String((!string.is_null()).then(|| cstr(string)))
+            }
+            sys::ecs_primitive_kind_t_EcsEntity => Self::Entity(Entity(*(ptr as *const u64))),
+            _ => Self::Id(Id(*(ptr as *const u64))),
+        }
+    }
+
+    /// Returns the value as a signed integer, if it is an integer that fits.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Self::Char(v) | Self::I8(v) => Some(v as i64),
+            Self::I16(v) => Some(v as i64),
+            Self::I32(v) => Some(v as i64),
+            Self::I64(v) => Some(v),
+            Self::IPtr(v) => Some(v as i64),
+            _ => self.as_u64().and_then(|v| i64::try_from(v).ok()),
+        }
+    }
+
+    /// Returns the value as an unsigned integer, if it is an integer that fits.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Self::Byte(v) | Self::U8(v) => Some(v as u64),
+            Self::U16(v) => Some(v as u64),
+            Self::U32(v) => Some(v as u64),
+            Self::U64(v) => Some(v),
+            Self::UPtr(v) => Some(v as u64),
+            Self::Char(_) | Self::I8(_) | Self::I16(_) | Self::I32(_) | Self::I64(_) => {
+                self.as_i64().and_then(|v| u64::try_from(v).ok())
+            }
+            Self::IPtr(v) => u64::try_from(v).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a float, if it is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Self::F32(v) => Some(v as f64),
+            Self::F64(v) => Some(v),
+            _ => self
+                .as_i64()
+                .map(|v| v as f64)
+                .or_else(|| self.as_u64().map(|v| v as f64)),
+        }
+    }
+
+    /// Returns the value as a string, if it is a string that is not null.
+    pub fn as_str(&self) -> Option<&'a str> {
+        match *self {
+            Self::String(v) => v,
+            _ => None,
+        }
+    }
+}
+
+/// The value of an enum type.
+pub struct EnumValue<'a> {
+    value: i64,
+    constants: &'a sys::EcsEnum,
+}
+
+impl<'a> EnumValue<'a> {
+    /// Returns the underlying value.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Returns the name of the constant of the value, or `None` if the value
+    /// is not a constant of the enum.
+    pub fn name(&self) -> Option<&'a str> {
+        unsafe {
+            enum_constants(self.constants)
+                .into_iter()
+                .find(|&(value, _)| value == self.value)
+                .map(|(_, name)| name)
+        }
+    }
+
+    /// Returns the names and values of the constants of the enum, sorted by
+    /// value.
+    pub fn constants(&self) -> Vec<(&'a str, i64)> {
+        unsafe {
+            enum_constants(self.constants)
+                .into_iter()
+                .map(|(value, name)| (name, value))
+                .collect()
+        }
+    }
+}
+
+/// The value of a bitmask type.
+pub struct BitmaskValue<'a> {
+    value: u32,
+    bitmask: &'a sys::EcsBitmask,
+}
+
+impl<'a> BitmaskValue<'a> {
+    /// Returns the underlying value.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns the names of the constants that are set in the value.
+    pub fn flags(&self) -> Vec<&'a str> {
+        unsafe {
+            bitmask_constants(self.bitmask)
+                .into_iter()
+                .filter(|&(bits, _)| bits != 0 && self.value & bits == bits)
+                .map(|(_, name)| name)
+                .collect()
+        }
+    }
+
+    /// Returns the names and values of the constants of the bitmask, sorted
+    /// by value.
+    pub fn constants(&self) -> Vec<(&'a str, u32)> {
+        unsafe {
+            bitmask_constants(self.bitmask)
+                .into_iter()
+                .map(|(bits, name)| (name, bits))
+                .collect()
+        }
+    }
+}
+
+/// The value of a struct type.
+pub struct StructValue<'a> {
+    world: WorldRef<'a>,
+    ptr: *const c_void,
+    members: &'a [sys::ecs_member_t],
+}
+
+impl<'a> StructValue<'a> {
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns true if the struct has no members.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns the members of the struct, in the order they were declared.
+    pub fn members(&self) -> impl ExactSizeIterator<Item = ReflectMember<'a>> + '_ {
+        self.members.iter().map(|member| ReflectMember {
+            world: self.world,
+            ptr: self.ptr,
+            member,
+        })
+    }
+
+    /// Returns the member with the name `name`.
+    pub fn member(&self, name: &str) -> Option<ReflectMember<'a>> {
+        self.members().find(|member| member.name() == name)
+    }
+}
+
+/// A member of a [`StructValue`].
+pub struct ReflectMember<'a> {
+    world: WorldRef<'a>,
+    ptr: *const c_void,
+    member: &'a sys::ecs_member_t,
+}
+
+impl<'a> ReflectMember<'a> {
+    /// Returns the name of the member.
+    pub fn name(&self) -> &'a str {
+        unsafe { cstr(self.member.name) }
+    }
+
+    /// Returns the type of the member.
+    pub fn type_entity(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.member.type_)
+    }
+
+    /// Returns the number of elements of the member, which is larger than 1
+    /// for members that are inline arrays.
+    pub fn count(&self) -> usize {
+        (self.member.count as usize).max(1)
+    }
+
+    /// Returns the value of the member. For inline arrays this is the first
+    /// element, see [`ReflectMember::elements()`].
+    pub fn value(&self) -> ReflectValue<'a> {
+        unsafe {
+            ReflectValue::from_ptr(
+                self.world,
+                self.member.type_,
+                self.ptr.byte_add(self.member.offset as usize),
+            )
+        }
+    }
+
+    /// Returns the elements of the member.
+    pub fn elements(&self) -> impl ExactSizeIterator<Item = ReflectValue<'a>> + '_ {
+        let first = self.value();
+        let size = unsafe { type_size(self.world.world_ptr(), self.member.type_) };
+        (0..self.count()).map(move |i| unsafe {
+            ReflectValue::from_ptr(self.world, first.type_id, first.ptr.byte_add(i * size))
+        })
+    }
+
+    /// Returns the unit of the member, if it has one.
+    pub fn unit(&self) -> Option<EntityView<'a>> {
+        (self.member.unit != 0).then(|| EntityView::new_from(self.world, self.member.unit))
+    }
+
+    /// Returns the range of valid values of the member, if it has one.
+    pub fn range(&self) -> Option<RangeInclusive<f64>> {
+        range(&self.member.range)
+    }
+
+    /// Returns the range outside of which the value of the member should
+    /// produce a warning, if it has one.
+    pub fn warning_range(&self) -> Option<RangeInclusive<f64>> {
+        range(&self.member.warning_range)
+    }
+
+    /// Returns the range outside of which the value of the member should
+    /// produce an error, if it has one.
+    pub fn error_range(&self) -> Option<RangeInclusive<f64>> {
+        range(&self.member.error_range)
+    }
+}
+
+/// Ranges that are not set have the same minimum and maximum.
+#[allow(clippy::float_cmp)]
+fn range(range: &sys::ecs_member_value_range_t) -> Option<RangeInclusive<f64>> {
+    (range.min != range.max).then_some(range.min..=range.max)
+}
+
+/// The value of an array or vector type.
+pub struct ListValue<'a> {
+    world: WorldRef<'a>,
+    elem: u64,
+    ptr: *const c_void,
+    count: usize,
+}
+
+impl<'a> ListValue<'a> {
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the type of the elements.
+    pub fn element_type(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.elem)
+    }
+
+    /// Returns the element at `index`.
+    pub fn get(&self, index: usize) -> Option<ReflectValue<'a>> {
+        (index < self.count).then(|| unsafe {
+            let size = type_size(self.world.world_ptr(), self.elem);
+            ReflectValue::from_ptr(self.world, self.elem, self.ptr.byte_add(index * size))
+        })
+    }
+
+    /// Returns the elements.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = ReflectValue<'a>> + '_ {
+        (0..self.count).map(|i| self.get(i).unwrap())
+    }
+}
+
+/// The value of an opaque type, which describes itself as another type.
+pub struct OpaqueValue<'a> {
+    world: WorldRef<'a>,
+    opaque: &'a sys::EcsOpaque,
+    ptr: *const c_void,
+}
+
+impl<'a> OpaqueValue<'a> {
+    /// Returns the type the opaque type describes itself as.
+    pub fn as_type(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.opaque.as_type)
+    }
+
+    /// Call `func` for each value that the opaque type serializes itself to,
+    /// which is one value for types that describe themselves as a primitive,
+    /// and one value per element or member for collections and structs.
+    ///
+    /// For structs, the name of the member is passed with its value. The
+    /// values are only valid during the call to `func`.
+    pub fn for_each(&self, mut func: impl FnMut(Option<&str>, ReflectValue<'_>)) {
+        let world = self.world;
+        let mut member = None;
+        // the callback fails if the opaque type can't be serialized, in which
+        // case there are no values
+        let _ = unsafe {
+            call_opaque(world.world_ptr(), self.opaque, self.ptr, &mut |event| {
+                match event {
+                    OpaqueEvent::Member(name) => member = Some(name.to_owned()),
+                    OpaqueEvent::Value(type_id, ptr) => {
+                        func(
+                            member.take().as_deref(),
+                            ReflectValue::from_ptr(world, type_id, ptr),
+                        );
+                    }
+                }
+                true
+            })
+        };
+    }
+}
+
+/// Visits the nodes of a value, see [`ReflectValue::visit()`].
+///
+/// All methods have an empty default implementation, so a visitor only needs
+/// to implement the nodes it is interested in. Nested nodes are visited
+/// between the matching `enter_` and `exit_` calls.
+#[allow(unused_variables)]
+pub trait ReflectVisitor {
+    /// Visit a primitive value.
+    fn visit_primitive(&mut self, value: PrimitiveValue) {}
+
+    /// Visit an enum value.
+    fn visit_enum(&mut self, value: &EnumValue) {}
+
+    /// Visit a bitmask value.
+    fn visit_bitmask(&mut self, value: &BitmaskValue) {}
+
+    /// Visit a value of a type without reflection data.
+    fn visit_unknown(&mut self, value: &ReflectValue) {}
+
+    /// Enter a struct, before its members are visited.
+    fn enter_struct(&mut self, value: &StructValue) {}
+
+    /// Exit a struct, after its members are visited.
+    fn exit_struct(&mut self, value: &StructValue) {}
+
+    /// Enter a member of a struct, before its value is visited. The elements
+    /// of members that are inline arrays are visited as elements.
+    fn enter_member(&mut self, member: &ReflectMember) {}
+
+    /// Exit a member of a struct, after its value is visited.
+    fn exit_member(&mut self, member: &ReflectMember) {}
+
+    /// Enter an array or vector, before its elements are visited.
+    fn enter_list(&mut self, value: &ListValue) {}
+
+    /// Exit an array or vector, after its elements are visited.
+    fn exit_list(&mut self, value: &ListValue) {}
+
+    /// Enter the element at `index` of an array, vector, inline array or
+    /// opaque collection.
+    fn enter_element(&mut self, index: usize) {}
+
+    /// Exit the element at `index` of an array, vector, inline array or
+    /// opaque collection.
+    fn exit_element(&mut self, index: usize) {}
+
+    /// Enter an opaque value, before the values it serializes to are visited.
+    fn enter_opaque(&mut self, value: &OpaqueValue) {}
+
+    /// Visit the name of a member that an opaque value serializes, before
+    /// the value of the member is visited.
+    fn opaque_member(&mut self, name: &str) {}
+
+    /// Exit an opaque value, after the values it serializes to are visited.
+    fn exit_opaque(&mut self, value: &OpaqueValue) {}
+}
+
+impl<'a> EntityView<'a> {
+    /// Call `func` for each component of the entity that has reflection data,
+    /// with a view of its value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component_named::<Position>("Position").meta();
+    ///
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let mut names = Vec::new();
+    /// e.each_reflected(|id, value| {
+    ///     names.push(id.to_str().to_owned());
+    ///     assert!(matches!(value.kind(), Some(ReflectKind::Struct(_))));
+    /// });
+    /// assert_eq!(names, ["Position"]);
+    /// ```
+    pub fn each_reflected(self, mut func: impl FnMut(IdView<'a>, ReflectValue<'_>)) {
+        let world = self.world_ptr();
+        for &id in self.archetype().as_slice() {
+            unsafe {
+                let type_id = sys::ecs_get_typeid(world, *id);
+                if type_id == 0 || type_kind(world, type_id).is_none() {
+                    continue;
+                }
+                let ptr = sys::ecs_get_id(world, *self.id, *id);
+                func(
+                    IdView::new_from(self.world, id),
+                    ReflectValue::from_ptr(self.world, type_id, ptr),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//! Helpers to walk values using the reflection data of their type, shared by
+//! the serializers of the meta addon.
+use std::ffi::{c_char, c_void, CStr, CString};
+
+use crate::core::*;
+use crate::sys;
+
+/// The reflection data of a type.
+pub(crate) enum Kind<'t> {
+    Primitive(sys::ecs_primitive_kind_t),
+    Bitmask(&'t sys::EcsBitmask),
+    Enum(&'t sys::EcsEnum),
+    Struct(&'t [sys::ecs_member_t]),
+    Array(&'t sys::EcsArray),
+    Vector(&'t sys::EcsVector),
+    Opaque(&'t sys::EcsOpaque),
+}
+
+pub(crate) unsafe fn get<'t, T>(
+    world: *const sys::ecs_world_t,
+    entity: u64,
+    id: u64,
+) -> Option<&'t T> {
+    (sys::ecs_get_id(world, entity, id) as *const T).as_ref()
+}
+
+pub(crate) unsafe fn type_kind<'t>(
+    world: *const sys::ecs_world_t,
+    type_id: u64,
+) -> Option<Kind<'t>> {
+    let ty = get::<sys::EcsType>(world, type_id, flecs::meta::Type::ID)?;
+    Some(match ty.kind {
+        sys::ecs_type_kind_t_EcsPrimitiveType => {
+            let primitive = get::<sys::EcsPrimitive>(world, type_id, flecs::meta::Primitive::ID)?;
+            Kind::Primitive(primitive.kind)
+        }
+        sys::ecs_type_kind_t_EcsBitmaskType => {
+            Kind::Bitmask(get(world, type_id, flecs::meta::Bitmask::ID)?)
+        }
+        sys::ecs_type_kind_t_EcsEnumType => {
+            Kind::Enum(get(world, type_id, flecs::meta::EcsEnum::ID)?)
+        }
+        sys::ecs_type_kind_t_EcsStructType => {
+            let members =
+                &get::<sys::EcsStruct>(world, type_id, flecs::meta::EcsStruct::ID)?.members;
+            if members.count == 0 {
+                Kind::Struct(&[])
+            } else {
+                Kind::Struct(std::slice::from_raw_parts(
+                    members.array as *const sys::ecs_member_t,
+                    members.count as usize,
+                ))
+            }
+        }
+        sys::ecs_type_kind_t_EcsArrayType => {
+            Kind::Array(get(world, type_id, flecs::meta::Array::ID)?)
+        }
+        sys::ecs_type_kind_t_EcsVectorType => {
+            Kind::Vector(get(world, type_id, flecs::meta::Vector::ID)?)
+        }
+        sys::ecs_type_kind_t_EcsOpaqueType => {
+            Kind::Opaque(get(world, type_id, flecs::meta::EcsOpaque::ID)?)
+        }
+        _ => return None,
+    })
+}
+
+pub(crate) unsafe fn type_size(world: *const sys::ecs_world_t, type_id: u64) -> usize {
+    let type_info = sys::ecs_get_type_info(world, type_id);
+    if type_info.is_null() {
+        0
+    } else {
+        (*type_info).size as usize
+    }
+}
+
+pub(crate) unsafe fn no_reflection(world: *const sys::ecs_world_t, type_id: u64) -> String {
+    format!("type '{}' has no reflection data", id_str(world, type_id))
+}
+
+pub(crate) unsafe fn cstr<'s>(ptr: *const c_char) -> &'s str {
+    if ptr.is_null() {
+        ""
+    } else {
+        CStr::from_ptr(ptr).to_str().unwrap_or_default()
+    }
+}
+
+pub(crate) unsafe fn take_c_string(ptr: *mut c_char) -> String {
+    let string = cstr(ptr).to_owned();
+    sys::ecs_os_api.free_.expect("os api is missing")(ptr as *mut c_void);
+    string
+}
+
+pub(crate) unsafe fn id_str(world: *const sys::ecs_world_t, id: u64) -> String {
+    take_c_string(sys::ecs_id_str(world, id))
+}
+
+pub(crate) unsafe fn entity_path(world: *const sys::ecs_world_t, entity: u64) -> String {
+    if entity == 0 {
+        return "#0".to_owned();
+    }
+    take_c_string(sys::ecs_get_path_w_sep(
+        world,
+        0,
+        entity,
+        c".".as_ptr(),
+        c"".as_ptr(),
+    ))
+}
+
+pub(crate) unsafe fn lookup_entity(
+    world: *const sys::ecs_world_t,
+    path: &str,
+) -> Result<u64, String> {
+    if path == "#0" {
+        return Ok(0);
+    }
+    let cpath = CString::new(path).map_err(|e| e.to_string())?;
+    let entity =
+        sys::ecs_lookup_path_w_sep(world, 0, cpath.as_ptr(), c".".as_ptr(), c"".as_ptr(), false);
+    if entity == 0 {
+        Err(format!("unresolved entity '{path}'"))
+    } else {
+        Ok(entity)
+    }
+}
+
+/// Returns the constants of an enum, sorted by value.
+pub(crate) unsafe fn enum_constants(e: &sys::EcsEnum) -> Vec<(i64, &str)> {
+    let mut constants = Vec::new();
+    let mut it = sys::ecs_map_iter(&e.constants);
+    while sys::ecs_map_next(&mut it) {
+        let constant = &*(*it.res.add(1) as *const sys::ecs_enum_constant_t);
+        constants.push((constant.value as i64, cstr(constant.name)));
+    }
+    constants.sort_unstable_by_key(|c| c.0);
+    constants
+}
+
+/// Returns the constants of a bitmask, sorted by value.
+pub(crate) unsafe fn bitmask_constants(b: &sys::EcsBitmask) -> Vec<(u32, &str)> {
+    let mut constants = Vec::new();
+    let mut it = sys::ecs_map_iter(&b.constants);
+    while sys::ecs_map_next(&mut it) {
+        let constant = &*(*it.res.add(1) as *const sys::ecs_bitmask_constant_t);
+        constants.push((constant.value, cstr(constant.name)));
+    }
+    constants.sort_unstable_by_key(|c| c.0);
+    constants
+}
+
+pub(crate) unsafe fn read_enum(ptr: *const c_void, size: usize) -> i64 {
+    match size {
+        1 => *(ptr as *const i8) as i64,
+        2 => *(ptr as *const i16) as i64,
+        8 => *(ptr as *const i64),
+        _ => *(ptr as *const i32) as i64,
+    }
+}
+
+pub(crate) unsafe fn write_enum(ptr: *mut c_void, size: usize, value: i64) {
+    match size {
+        1 => *(ptr as *mut i8) = value as i8,
+        2 => *(ptr as *mut i16) = value as i16,
+        8 => *(ptr as *mut i64) = value,
+        _ => *(ptr as *mut i32) = value as i32,
+    }
+}
+
+pub(crate) enum OpaqueEvent<'e> {
+    Member(&'e str),
+    Value(u64, *const c_void),
+}
+
+pub(crate) type OpaqueEmit<'f> = dyn FnMut(OpaqueEvent) -> bool + 'f;
+
+unsafe extern "C" fn opaque_value(
+    ser: *const sys::ecs_serializer_t,
+    type_id: u64,
+    value: *const c_void,
+) -> i32 {
+    let emit = &mut *((*ser).ctx as *mut &mut OpaqueEmit);
+    if emit(OpaqueEvent::Value(type_id, value)) {
+        0
+    } else {
+        -1
+    }
+}
+
+unsafe extern "C" fn opaque_member(ser: *const sys::ecs_serializer_t, name: *const c_char) -> i32 {
+    let emit = &mut *((*ser).ctx as *mut &mut OpaqueEmit);
+    if emit(OpaqueEvent::Member(cstr(name))) {
+        0
+    } else {
+        -1
+    }
+}
+
+/// Invoke the serialize callback of an opaque type, forwarding the values and
+/// members it emits to `emit`.
+pub(crate) unsafe fn call_opaque(
+    world: *const sys::ecs_world_t,
+    opaque: &sys::EcsOpaque,
+    ptr: *const c_void,
+    mut emit: &mut OpaqueEmit,
+) -> Result<(), String> {
+    let Some(serialize) = opaque.serialize else {
+        return Err("opaque type has no serialize callback".to_owned());
+    };
+    let ser = sys::ecs_serializer_t {
+        value: Some(opaque_value),
+        member: Some(opaque_member),
+        world,
+        ctx: &mut emit as *mut &mut OpaqueEmit as *mut c_void,
+    };
+    serialize(&ser, ptr);
+    Ok(())
+}
+
+/// A primitive value read from an input format.
+#[derive(Debug)]
+pub(crate) enum Scalar {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(String),
+}
+
+impl Scalar {
+    pub(crate) fn int<T: TryFrom<i64> + TryFrom<u64>>(&self) -> Option<T> {
+        match *self {
+            Scalar::Int(v) => T::try_from(v).ok(),
+            Scalar::UInt(v) => T::try_from(v).ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn float(&self) -> Option<f64> {
+        match *self {
+            Scalar::Int(v) => Some(v as f64),
+            Scalar::UInt(v) => Some(v as f64),
+            Scalar::Float(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn char(&self) -> Option<i8> {
+        match self {
+            Scalar::Str(s) if s.len() == 1 => Some(s.as_bytes()[0] as i8),
+            _ => self.int(),
+        }
+    }
+
+    pub(crate) unsafe fn entity(&self, world: *const sys::ecs_world_t) -> Result<u64, String> {
+        match self {
+            Scalar::Str(path) => lookup_entity(world, path),
+            Scalar::UInt(entity) => Ok(*entity),
+            _ => Err(format!("expected an entity, found {self:?}")),
+        }
+    }
+}
+
+/// Assign a primitive value to an opaque type with its assign callbacks.
+pub(crate) unsafe fn assign_opaque(
+    world: *const sys::ecs_world_t,
+    type_id: u64,
+    dst: *mut c_void,
+    opaque: &sys::EcsOpaque,
+    primitive: sys::ecs_primitive_kind_t,
+    scalar: Scalar,
+) -> Result<(), String> {
+    let world_mut = world as *mut sys::ecs_world_t;
+    let assigned = match primitive {
+        sys::ecs_primitive_kind_t_EcsEntity => {
+            let entity = scalar.entity(world)?;
+            opaque
+                .assign_entity
+                .map(|assign| assign(dst, world_mut, entity))
+        }
+        sys::ecs_primitive_kind_t_EcsId => match scalar.int() {
+            Some(id) => opaque.assign_id.map(|assign| assign(dst, world_mut, id)),
+            None => None,
+        },
+        sys::ecs_primitive_kind_t_EcsChar => match (scalar.char(), opaque.assign_char) {
+            (Some(c), Some(assign)) => {
+                assign(dst, c);
+                Some(())
+            }
+            _ => None,
+        },
+        _ => match &scalar {
+            Scalar::Bool(v) => opaque.assign_bool.map(|assign| assign(dst, *v)),
+            Scalar::Int(v) => opaque
+                .assign_int
+                .map(|assign| assign(dst, *v))
+                .or_else(|| opaque.assign_float.map(|assign| assign(dst, *v as f64))),
+            Scalar::UInt(v) => opaque
+                .assign_uint
+                .map(|assign| assign(dst, *v))
+                .or_else(|| {
+                    scalar
+                        .int()
+                        .and_then(|v| opaque.assign_int.map(|assign| assign(dst, v)))
+                })
+                .or_else(|| opaque.assign_float.map(|assign| assign(dst, *v as f64))),
+            Scalar::Float(v) => opaque.assign_float.map(|assign| assign(dst, *v)),
+            Scalar::Str(v) => {
+                let string = CString::new(v.as_str()).map_err(|e| e.to_string())?;
+                opaque
+                    .assign_string
+                    .map(|assign| assign(dst, string.as_ptr()))
+            }
+        },
+    };
+    assigned.ok_or_else(|| {
+        format!(
+            "cannot assign {scalar:?} to opaque type '{}'",
+            id_str(world, type_id)
+        )
+    })
+}
+
+/// Replace the C string at `dst`, freeing the previous string.
+pub(crate) unsafe fn write_string(dst: *mut c_void, value: Option<&str>) -> Result<(), String> {
+    let string = value
+        .map(CString::new)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    let dst = dst as *mut *mut c_char;
+    if !(*dst).is_null() {
+        sys::ecs_os_api.free_.expect("os api is missing")(*dst as *mut c_void);
+    }
+    *dst = match string {
+        Some(string) => sys::ecs_os_api.strdup_.expect("os api is missing")(string.as_ptr()),
+        None => std::ptr::null_mut(),
+    };
+    Ok(())
+}
+
+/// Returns the element at `index` of a vector, appending a constructed
+/// element if `index` is the number of elements in the vector.
+pub(crate) unsafe fn vec_element(
+    world: *const sys::ecs_world_t,
+    vec: *mut sys::ecs_vec_t,
+    elem: u64,
+    index: usize,
+) -> *mut c_void {
+    let size = type_size(world, elem);
+    if index < (*vec).count as usize {
+        return (*vec).array.byte_add(index * size);
+    }
+    let elem_ptr = sys::ecs_vec_append(std::ptr::null_mut(), vec, size as i32);
+    std::ptr::write_bytes(elem_ptr as *mut u8, 0, size);
+    let type_info = sys::ecs_get_type_info(world, elem);
+    if let Some(ctor) = type_info.as_ref().and_then(|ti| ti.hooks.ctor) {
+        ctor(elem_ptr, 1, type_info);
+    }
+    elem_ptr
+}
+
+/// Destruct and remove the elements of a vector after the first `count`.
+pub(crate) unsafe fn vec_truncate(
+    world: *const sys::ecs_world_t,
+    vec: *mut sys::ecs_vec_t,
+    elem: u64,
+    count: usize,
+) {
+    let old_count = (*vec).count as usize;
+    if count >= old_count {
+        return;
+    }
+    let size = type_size(world, elem);
+    let type_info = sys::ecs_get_type_info(world, elem);
+    if let Some(dtor) = type_info.as_ref().and_then(|ti| ti.hooks.dtor) {
+        dtor(
+            (*vec).array.byte_add(count * size),
+            (old_count - count) as i32,
+            type_info,
+        );
+    }
+    sys::ecs_vec_set_count(std::ptr::null_mut(), vec, size as i32, count as i32);
+}
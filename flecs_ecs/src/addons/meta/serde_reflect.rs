@@ -0,0 +1,1155 @@
+//! Serialize and deserialize reflected values with [serde](https://serde.rs).
+//!
+//! Values are walked using the reflection data of their type, so any type that
+//! is described with `#[meta]` or with the meta builder functions can be used
+//! with any serde format, without a hand written `Serialize` or `Deserialize`
+//! implementation.
+//!
+//! Values are mapped to the serde data model as follows:
+//!
+//! * primitives are mapped to the matching serde primitive.
+//! * entities are serialized as their path (`"parent.child"`, `"#0"` for no
+//!   entity) by human readable formats, and as their id otherwise.
+//! * enums are serialized as unit variants.
+//! * bitmasks are serialized as the names of the set flags (`"A|B"`) by human
+//!   readable formats, and as `u32` otherwise.
+//! * structs are serialized as structs, arrays as tuples and vectors as
+//!   sequences.
+//! * opaque types are serialized as the type they describe themselves as.
+use std::collections::HashSet;
+use std::ffi::{c_char, c_void};
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+use serde::de::{self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple};
+use serde::Serializer;
+
+use super::reflection::*;
+use crate::core::*;
+use crate::sys;
+
+/// Serializes a value of a reflected type with any [`serde::Serializer`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// #[meta]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Position>().meta();
+///
+/// let value = Position { x: 10.0, y: 20.0 };
+/// let json = serde_json::to_string(&ReflectSerializer::of(&world, &value)).unwrap();
+/// assert_eq!(json, r#"{"x":10.0,"y":20.0}"#);
+/// ```
+pub struct ReflectSerializer<'a> {
+    world: WorldRef<'a>,
+    type_id: Entity,
+    ptr: *const c_void,
+}
+
+impl<'a> ReflectSerializer<'a> {
+    /// Create a serializer for the value at `ptr`, which must point to a valid
+    /// value of the reflected type `type_id`.
+    pub fn new(
+        world: impl WorldProvider<'a>,
+        type_id: impl Into<Entity>,
+        ptr: *const c_void,
+    ) -> Self {
+        Self {
+            world: world.world(),
+            type_id: type_id.into(),
+            ptr,
+        }
+    }
+
+    /// Create a serializer for a value of a reflected component.
+    pub fn of<T: ComponentId>(world: impl WorldProvider<'a>, value: &'a T) -> Self {
+        let world = world.world();
+        Self::new(world, T::get_id(world), value as *const T as *const c_void)
+    }
+}
+
+impl Serialize for ReflectSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Value {
+            world: self.world.world_ptr(),
+            type_id: *self.type_id,
+            ptr: self.ptr,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a value of a reflected type in place from any
+/// [`serde::Deserializer`].
+///
+/// Members that are missing from the input keep their current value.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+/// use serde::de::DeserializeSeed;
+///
+/// #[derive(Component, Default)]
+/// #[meta]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Position>().meta();
+///
+/// let mut value = Position::default();
+/// let mut json = serde_json::Deserializer::from_str(r#"{"x":10.0,"y":20.0}"#);
+/// ReflectDeserializer::of(&world, &mut value)
+///     .deserialize(&mut json)
+///     .unwrap();
+/// assert_eq!(value.x, 10.0);
+/// assert_eq!(value.y, 20.0);
+/// ```
+pub struct ReflectDeserializer<'a> {
+    world: WorldRef<'a>,
+    type_id: Entity,
+    ptr: *mut c_void,
+}
+
+impl<'a> ReflectDeserializer<'a> {
+    /// Create a deserializer that writes to the value at `ptr`, which must
+    /// point to a valid value of the reflected type `type_id`.
+    pub fn new(
+        world: impl WorldProvider<'a>,
+        type_id: impl Into<Entity>,
+        ptr: *mut c_void,
+    ) -> Self {
+        Self {
+            world: world.world(),
+            type_id: type_id.into(),
+            ptr,
+        }
+    }
+
+    /// Create a deserializer that writes to a value of a reflected component.
+    pub fn of<T: ComponentId>(world: impl WorldProvider<'a>, value: &'a mut T) -> Self {
+        let world = world.world();
+        Self::new(world, T::get_id(world), value as *mut T as *mut c_void)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ReflectDeserializer<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        Place {
+            world: self.world.world_ptr(),
+            type_id: *self.type_id,
+            ptr: self.ptr,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+impl<'a> EntityView<'a> {
+    /// Serialize the components of the entity as a map from the component id
+    /// (e.g. `"Position"` or `"(Likes,Apples)"`) to its value.
+    ///
+    /// Tags are serialized as unit values. Components without reflection data
+    /// are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Npc;
+    ///
+    /// let world = World::new();
+    /// world.component_named::<Position>("Position").meta();
+    /// world.component_named::<Npc>("Npc");
+    ///
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 }).add::<Npc>();
+    ///
+    /// let mut json = Vec::new();
+    /// e.serialize_with(&mut serde_json::Serializer::new(&mut json))
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(json).unwrap(),
+    ///     r#"{"Position":{"x":1.0,"y":2.0},"Npc":null}"#
+    /// );
+    /// ```
+    pub fn serialize_with<S: Serializer>(self, serializer: S) -> Result<S::Ok, S::Error> {
+        let world = self.world_ptr();
+        let mut components = Vec::new();
+        for &id in self.archetype().as_slice() {
+            unsafe {
+                let type_id = sys::ecs_get_typeid(world, *id);
+                if type_id == 0 {
+                    components.push((*id, None));
+                } else if type_kind(world, type_id).is_some() {
+                    let ptr = sys::ecs_get_id(world, *self.id, *id);
+                    components.push((
+                        *id,
+                        Some(Value {
+                            world,
+                            type_id,
+                            ptr,
+                        }),
+                    ));
+                }
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(components.len()))?;
+        for (id, value) in components {
+            let key = unsafe { id_str(world, id) };
+            match value {
+                Some(value) => map.serialize_entry(&key, &value)?,
+                None => map.serialize_entry(&key, &())?,
+            }
+        }
+        map.end()
+    }
+}
+
+/// Serde requires `'static` names for structs, fields and enum variants. The
+/// names of reflected types are interned, so each name is only leaked once.
+fn intern(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES.get_or_init(Default::default).lock().unwrap();
+    if let Some(name) = names.get(name) {
+        return name;
+    }
+    let name: &'static str = Box::leak(name.into());
+    names.insert(name);
+    name
+}
+
+fn intern_names(list: Vec<&'static str>) -> &'static [&'static str] {
+    static LISTS: OnceLock<Mutex<HashSet<&'static [&'static str]>>> = OnceLock::new();
+    let mut lists = LISTS.get_or_init(Default::default).lock().unwrap();
+    if let Some(list) = lists.get(list.as_slice()) {
+        return list;
+    }
+    let list: &'static [&'static str] = Box::leak(list.into_boxed_slice());
+    lists.insert(list);
+    list
+}
+
+/// Returns the constants of an enum with interned names, sorted by value.
+unsafe fn variants(e: &sys::EcsEnum) -> Vec<(i64, &'static str)> {
+    enum_constants(e)
+        .into_iter()
+        .map(|(value, name)| (value, intern(name)))
+        .collect()
+}
+
+/// Returns the constants of a bitmask with interned names, sorted by value.
+unsafe fn bits(b: &sys::EcsBitmask) -> Vec<(u32, &'static str)> {
+    bitmask_constants(b)
+        .into_iter()
+        .map(|(value, name)| (value, intern(name)))
+        .collect()
+}
+
+unsafe fn type_name(world: *const sys::ecs_world_t, type_id: u64) -> &'static str {
+    intern(cstr(sys::ecs_get_name(world, type_id)))
+}
+
+unsafe fn member_names(members: &[sys::ecs_member_t]) -> &'static [&'static str] {
+    intern_names(members.iter().map(|m| intern(cstr(m.name))).collect())
+}
+
+// Serialization
+
+#[derive(Clone, Copy)]
+struct Value {
+    world: *const sys::ecs_world_t,
+    type_id: u64,
+    ptr: *const c_void,
+}
+
+/// An inline array of `count` values, such as a struct member with a count.
+#[derive(Clone, Copy)]
+struct Elements {
+    world: *const sys::ecs_world_t,
+    type_id: u64,
+    ptr: *const c_void,
+    count: usize,
+}
+
+impl Elements {
+    unsafe fn member(
+        world: *const sys::ecs_world_t,
+        m: &sys::ecs_member_t,
+        ptr: *const c_void,
+    ) -> Self {
+        Self {
+            world,
+            type_id: m.type_,
+            ptr,
+            count: (m.count as usize).max(1),
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        unsafe { serialize_value(*self, serializer) }
+    }
+}
+
+impl Serialize for Elements {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let size = unsafe { type_size(self.world, self.type_id) };
+        let mut tuple = serializer.serialize_tuple(self.count)?;
+        for i in 0..self.count {
+            tuple.serialize_element(&Value {
+                world: self.world,
+                type_id: self.type_id,
+                ptr: unsafe { self.ptr.byte_add(i * size) },
+            })?;
+        }
+        tuple.end()
+    }
+}
+
+/// Serializes a struct member, which is an inline array when it has a count.
+fn serialize_member<S: SerializeStruct>(
+    st: &mut S,
+    name: &'static str,
+    elements: Elements,
+) -> Result<(), S::Error> {
+    if elements.count > 1 {
+        st.serialize_field(name, &elements)
+    } else {
+        st.serialize_field(
+            name,
+            &Value {
+                world: elements.world,
+                type_id: elements.type_id,
+                ptr: elements.ptr,
+            },
+        )
+    }
+}
+
+unsafe fn serialize_value<S: Serializer>(value: Value, serializer: S) -> Result<S::Ok, S::Error> {
+    let Value {
+        world,
+        type_id,
+        ptr,
+    } = value;
+    let Some(kind) = type_kind(world, type_id) else {
+        return Err(ser::Error::custom(no_reflection(world, type_id)));
+    };
+
+    match kind {
+        Kind::Primitive(primitive) => serialize_primitive(value, primitive, serializer),
+        Kind::Enum(e) => {
+            let constant = read_enum(ptr, type_size(world, type_id));
+            let constants = variants(e);
+            match constants.iter().position(|c| c.0 == constant) {
+                Some(index) => serializer.serialize_unit_variant(
+                    type_name(world, type_id),
+                    index as u32,
+                    constants[index].1,
+                ),
+                None => Err(ser::Error::custom(format!(
+                    "invalid constant {constant} for enum '{}'",
+                    id_str(world, type_id)
+                ))),
+            }
+        }
+        Kind::Bitmask(b) => {
+            let bits = *(ptr as *const u32);
+            if !serializer.is_human_readable() {
+                return serializer.serialize_u32(bits);
+            }
+            let mut flags = Vec::new();
+            let mut remaining = bits;
+            for (flag, name) in bitmask_constants(b) {
+                if flag != 0 && bits & flag == flag {
+                    flags.push(name.to_owned());
+                    remaining &= !flag;
+                }
+            }
+            if remaining != 0 || flags.is_empty() {
+                flags.push(remaining.to_string());
+            }
+            serializer.serialize_str(&flags.join("|"))
+        }
+        Kind::Struct(members) => {
+            let mut st = serializer.serialize_struct(type_name(world, type_id), members.len())?;
+            for m in members {
+                let elements = Elements::member(world, m, ptr.byte_add(m.offset as usize));
+                serialize_member(&mut st, intern(cstr(m.name)), elements)?;
+            }
+            st.end()
+        }
+        Kind::Array(array) => Elements {
+            world,
+            type_id: array.type_,
+            ptr,
+            count: array.count as usize,
+        }
+        .serialize(serializer),
+        Kind::Vector(vector) => {
+            let vec = &*(ptr as *const sys::ecs_vec_t);
+            let size = type_size(world, vector.type_);
+            let mut seq = serializer.serialize_seq(Some(vec.count as usize))?;
+            for i in 0..vec.count as usize {
+                seq.serialize_element(&Value {
+                    world,
+                    type_id: vector.type_,
+                    ptr: vec.array.byte_add(i * size),
+                })?;
+            }
+            seq.end()
+        }
+        Kind::Opaque(opaque) => serialize_opaque(value, opaque, serializer),
+    }
+}
+
+unsafe fn serialize_primitive<S: Serializer>(
+    value: Value,
+    primitive: sys::ecs_primitive_kind_t,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let ptr = value.ptr;
+    match primitive {
+        sys::ecs_primitive_kind_t_EcsBool => serializer.serialize_bool(*(ptr as *const bool)),
+        sys::ecs_primitive_kind_t_EcsChar => serializer.serialize_char(*(ptr as *const u8) as char),
+        sys::ecs_primitive_kind_t_EcsByte | sys::ecs_primitive_kind_t_EcsU8 => {
+            serializer.serialize_u8(*(ptr as *const u8))
+        }
+        sys::ecs_primitive_kind_t_EcsU16 => serializer.serialize_u16(*(ptr as *const u16)),
+        sys::ecs_primitive_kind_t_EcsU32 => serializer.serialize_u32(*(ptr as *const u32)),
+        sys::ecs_primitive_kind_t_EcsU64 => serializer.serialize_u64(*(ptr as *const u64)),
+        sys::ecs_primitive_kind_t_EcsUPtr => {
+            serializer.serialize_u64(*(ptr as *const usize) as u64)
+        }
+        sys::ecs_primitive_kind_t_EcsI8 => serializer.serialize_i8(*(ptr as *const i8)),
+        sys::ecs_primitive_kind_t_EcsI16 => serializer.serialize_i16(*(ptr as *const i16)),
+        sys::ecs_primitive_kind_t_EcsI32 => serializer.serialize_i32(*(ptr as *const i32)),
+        sys::ecs_primitive_kind_t_EcsI64 => serializer.serialize_i64(*(ptr as *const i64)),
+        sys::ecs_primitive_kind_t_EcsIPtr => {
+            serializer.serialize_i64(*(ptr as *const isize) as i64)
+        }
+        sys::ecs_primitive_kind_t_EcsF32 => serializer.serialize_f32(*(ptr as *const f32)),
+        sys::ecs_primitive_kind_t_EcsF64 => serializer.serialize_f64(*(ptr as *const f64)),
+        sys::ecs_primitive_kind_t_EcsString => {
+            serializer.serialize_str(cstr(*(ptr as *const *const c_char)))
+        }
+        sys::ecs_primitive_kind_t_EcsEntity => {
+            let entity = *(ptr as *const u64);
+            if serializer.is_human_readable() {
+                serializer.serialize_str(&entity_path(value.world, entity))
+            } else {
+                serializer.serialize_u64(entity)
+            }
+        }
+        sys::ecs_primitive_kind_t_EcsId => serializer.serialize_u64(*(ptr as *const u64)),
+        _ => Err(ser::Error::custom(format!(
+            "unknown primitive kind {primitive}"
+        ))),
+    }
+}
+
+unsafe fn serialize_opaque<S: Serializer>(
+    value: Value,
+    opaque: &sys::EcsOpaque,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let world = value.world;
+    match type_kind(world, opaque.as_type) {
+        Some(Kind::Vector(_) | Kind::Array(_)) => {
+            let len = opaque.count.map(|count| count(value.ptr));
+            let mut seq = serializer.serialize_seq(len)?;
+            let mut result = Ok(());
+            call_opaque(world, opaque, value.ptr, &mut |event| {
+                if let OpaqueEvent::Value(type_id, ptr) = event {
+                    result = seq.serialize_element(&Value {
+                        world,
+                        type_id,
+                        ptr,
+                    });
+                }
+                result.is_ok()
+            })
+            .map_err(ser::Error::custom)?;
+            result?;
+            seq.end()
+        }
+        Some(Kind::Struct(members)) => {
+            let name = type_name(world, opaque.as_type);
+            let mut st = serializer.serialize_struct(name, members.len())?;
+            let mut field = None;
+            let mut result = Ok(());
+            call_opaque(world, opaque, value.ptr, &mut |event| {
+                match event {
+                    OpaqueEvent::Member(name) => field = Some(intern(name)),
+                    OpaqueEvent::Value(type_id, ptr) => {
+                        result = match field.take() {
+                            Some(name) => st.serialize_field(
+                                name,
+                                &Value {
+                                    world,
+                                    type_id,
+                                    ptr,
+                                },
+                            ),
+                            None => Err(ser::Error::custom("opaque value without member")),
+                        }
+                    }
+                }
+                result.is_ok()
+            })
+            .map_err(ser::Error::custom)?;
+            result?;
+            st.end()
+        }
+        Some(_) => {
+            let mut serializer = Some(serializer);
+            let mut result = None;
+            call_opaque(world, opaque, value.ptr, &mut |event| {
+                if let (OpaqueEvent::Value(type_id, ptr), Some(serializer)) =
+                    (event, serializer.take())
+                {
+                    result = Some(
+                        Value {
+                            world,
+                            type_id,
+                            ptr,
+                        }
+                        .serialize(serializer),
+                    );
+                }
+                true
+            })
+            .map_err(ser::Error::custom)?;
+            result.unwrap_or_else(|| Err(ser::Error::custom("opaque type serialized no value")))
+        }
+        None => Err(ser::Error::custom(no_reflection(world, opaque.as_type))),
+    }
+}
+
+// Deserialization
+
+#[derive(Clone, Copy)]
+struct Place {
+    world: *const sys::ecs_world_t,
+    type_id: u64,
+    ptr: *mut c_void,
+}
+
+/// An inline array of `count` values, see [`Elements`].
+#[derive(Clone, Copy)]
+struct PlaceElements {
+    world: *const sys::ecs_world_t,
+    type_id: u64,
+    ptr: *mut c_void,
+    count: usize,
+}
+
+impl PlaceElements {
+    unsafe fn member(
+        world: *const sys::ecs_world_t,
+        m: &sys::ecs_member_t,
+        ptr: *mut c_void,
+    ) -> Self {
+        Self {
+            world,
+            type_id: m.type_,
+            ptr,
+            count: (m.count as usize).max(1),
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Place {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        unsafe { deserialize_value(self, deserializer) }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for PlaceElements {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        if self.count > 1 {
+            deserializer.deserialize_tuple(self.count, self)
+        } else {
+            Place {
+                world: self.world,
+                type_id: self.type_id,
+                ptr: self.ptr,
+            }
+            .deserialize(deserializer)
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for PlaceElements {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of {} elements", self.count)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let size = unsafe { type_size(self.world, self.type_id) };
+        for i in 0..self.count {
+            let place = Place {
+                world: self.world,
+                type_id: self.type_id,
+                ptr: unsafe { self.ptr.byte_add(i * size) },
+            };
+            seq.next_element_seed(place)?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(self.count + 1, &self));
+        }
+        Ok(())
+    }
+}
+
+struct ScalarVisitor;
+
+impl<'de> Visitor<'de> for ScalarVisitor {
+    type Value = Scalar;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a primitive value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Scalar, E> {
+        Ok(Scalar::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Scalar, E> {
+        Ok(Scalar::Int(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Scalar, E> {
+        Ok(Scalar::UInt(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Scalar, E> {
+        Ok(Scalar::Float(v))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Scalar, E> {
+        Ok(Scalar::Str(v.to_string()))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Scalar, E> {
+        Ok(Scalar::Str(v.to_owned()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Scalar, E> {
+        Ok(Scalar::Str(v))
+    }
+}
+
+fn deserialize_scalar<'de, D: Deserializer<'de>>(
+    primitive: sys::ecs_primitive_kind_t,
+    deserializer: D,
+) -> Result<Scalar, D::Error> {
+    match primitive {
+        sys::ecs_primitive_kind_t_EcsBool => deserializer.deserialize_bool(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsChar => deserializer.deserialize_char(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsByte | sys::ecs_primitive_kind_t_EcsU8 => {
+            deserializer.deserialize_u8(ScalarVisitor)
+        }
+        sys::ecs_primitive_kind_t_EcsU16 => deserializer.deserialize_u16(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsU32 => deserializer.deserialize_u32(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsI8 => deserializer.deserialize_i8(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsI16 => deserializer.deserialize_i16(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsI32 => deserializer.deserialize_i32(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsI64 | sys::ecs_primitive_kind_t_EcsIPtr => {
+            deserializer.deserialize_i64(ScalarVisitor)
+        }
+        sys::ecs_primitive_kind_t_EcsF32 => deserializer.deserialize_f32(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsF64 => deserializer.deserialize_f64(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsString => deserializer.deserialize_str(ScalarVisitor),
+        sys::ecs_primitive_kind_t_EcsEntity if deserializer.is_human_readable() => {
+            deserializer.deserialize_any(ScalarVisitor)
+        }
+        _ => deserializer.deserialize_u64(ScalarVisitor),
+    }
+}
+
+unsafe fn write_primitive(
+    place: Place,
+    primitive: sys::ecs_primitive_kind_t,
+    scalar: Scalar,
+) -> Result<(), String> {
+    let invalid = || {
+        format!(
+            "cannot assign {scalar:?} to '{}'",
+            id_str(place.world, place.type_id)
+        )
+    };
+
+    macro_rules! assign {
+        ($t:ty, $value:expr) => {{
+            *(place.ptr as *mut $t) = $value.ok_or_else(invalid)?
+        }};
+    }
+
+    match primitive {
+        sys::ecs_primitive_kind_t_EcsBool => match &scalar {
+            Scalar::Bool(v) => *(place.ptr as *mut bool) = *v,
+            _ => return Err(invalid()),
+        },
+        sys::ecs_primitive_kind_t_EcsChar => assign!(i8, scalar.char()),
+        sys::ecs_primitive_kind_t_EcsByte | sys::ecs_primitive_kind_t_EcsU8 => {
+            assign!(u8, scalar.int());
+        }
+        sys::ecs_primitive_kind_t_EcsU16 => assign!(u16, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsU32 => assign!(u32, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsU64 => assign!(u64, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsUPtr => assign!(usize, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsI8 => assign!(i8, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsI16 => assign!(i16, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsI32 => assign!(i32, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsI64 => assign!(i64, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsIPtr => assign!(isize, scalar.int()),
+        sys::ecs_primitive_kind_t_EcsF32 => assign!(f32, scalar.float().map(|v| v as f32)),
+        sys::ecs_primitive_kind_t_EcsF64 => assign!(f64, scalar.float()),
+        sys::ecs_primitive_kind_t_EcsString => {
+            let Scalar::Str(string) = &scalar else {
+                return Err(invalid());
+            };
+            write_string(place.ptr, Some(string))?;
+        }
+        sys::ecs_primitive_kind_t_EcsEntity => {
+            *(place.ptr as *mut u64) = scalar.entity(place.world)?;
+        }
+        _ => assign!(u64, scalar.int()),
+    }
+    Ok(())
+}
+
+unsafe fn deserialize_value<'de, D: Deserializer<'de>>(
+    place: Place,
+    deserializer: D,
+) -> Result<(), D::Error> {
+    let Place {
+        world,
+        type_id,
+        ptr,
+    } = place;
+    let Some(kind) = type_kind(world, type_id) else {
+        return Err(de::Error::custom(no_reflection(world, type_id)));
+    };
+
+    match kind {
+        Kind::Primitive(primitive) => {
+            let scalar = deserialize_scalar(primitive, deserializer)?;
+            write_primitive(place, primitive, scalar).map_err(de::Error::custom)
+        }
+        Kind::Enum(e) => {
+            let constant = deserialize_enum(world, type_id, e, deserializer)?;
+            write_enum(ptr, type_size(world, type_id), constant);
+            Ok(())
+        }
+        Kind::Bitmask(b) => {
+            *(ptr as *mut u32) = deserialize_bitmask(b, deserializer)?;
+            Ok(())
+        }
+        Kind::Struct(members) => deserializer.deserialize_struct(
+            type_name(world, type_id),
+            member_names(members),
+            StructVisitor {
+                world,
+                members,
+                ptr,
+                opaque: None,
+            },
+        ),
+        Kind::Array(array) => deserializer.deserialize_tuple(
+            array.count as usize,
+            PlaceElements {
+                world,
+                type_id: array.type_,
+                ptr,
+                count: array.count as usize,
+            },
+        ),
+        Kind::Vector(vector) => deserializer.deserialize_seq(VectorVisitor {
+            world,
+            elem: vector.type_,
+            ptr,
+            opaque: None,
+        }),
+        Kind::Opaque(opaque) => deserialize_opaque(place, opaque, deserializer),
+    }
+}
+
+struct EnumVisitor {
+    constants: Vec<(i64, &'static str)>,
+}
+
+struct VariantSeed<'c> {
+    constants: &'c [(i64, &'static str)],
+}
+
+impl<'de> Visitor<'de> for EnumVisitor {
+    type Value = i64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an enum constant")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<i64, A::Error> {
+        let (constant, variant) = data.variant_seed(VariantSeed {
+            constants: &self.constants,
+        })?;
+        de::VariantAccess::unit_variant(variant)?;
+        Ok(constant)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<i64, E> {
+        VariantSeed {
+            constants: &self.constants,
+        }
+        .visit_str(v)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for VariantSeed<'_> {
+    type Value = i64;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<i64, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantSeed<'_> {
+    type Value = i64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an enum constant name or index")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<i64, E> {
+        self.constants
+            .get(v as usize)
+            .map(|c| c.0)
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<i64, E> {
+        match self.constants.iter().find(|c| c.1 == v) {
+            Some(constant) => Ok(constant.0),
+            None => Err(de::Error::unknown_variant(
+                v,
+                intern_names(self.constants.iter().map(|c| c.1).collect()),
+            )),
+        }
+    }
+}
+
+unsafe fn deserialize_enum<'de, D: Deserializer<'de>>(
+    world: *const sys::ecs_world_t,
+    type_id: u64,
+    e: &sys::EcsEnum,
+    deserializer: D,
+) -> Result<i64, D::Error> {
+    let constants = variants(e);
+    let variants = intern_names(constants.iter().map(|c| c.1).collect());
+    deserializer.deserialize_enum(
+        type_name(world, type_id),
+        variants,
+        EnumVisitor { constants },
+    )
+}
+
+struct BitmaskVisitor {
+    constants: Vec<(u32, &'static str)>,
+}
+
+impl<'de> Visitor<'de> for BitmaskVisitor {
+    type Value = u32;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("bitmask flags")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<u32, E> {
+        u32::try_from(v).map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<u32, E> {
+        u32::try_from(v).map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<u32, E> {
+        let mut bits = 0;
+        for flag in v.split('|').map(str::trim).filter(|flag| !flag.is_empty()) {
+            bits |= match self.constants.iter().find(|c| c.1 == flag) {
+                Some(constant) => constant.0,
+                None => flag.parse().map_err(|_| {
+                    de::Error::unknown_variant(
+                        flag,
+                        intern_names(self.constants.iter().map(|c| c.1).collect()),
+                    )
+                })?,
+            };
+        }
+        Ok(bits)
+    }
+}
+
+unsafe fn deserialize_bitmask<'de, D: Deserializer<'de>>(
+    b: &sys::EcsBitmask,
+    deserializer: D,
+) -> Result<u32, D::Error> {
+    let visitor = BitmaskVisitor { constants: bits(b) };
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_u32(visitor)
+    }
+}
+
+/// Visits the members of a struct. For opaque types the members are looked up
+/// with the `ensure_member` callback, otherwise with the member offset.
+struct StructVisitor<'t> {
+    world: *const sys::ecs_world_t,
+    members: &'t [sys::ecs_member_t],
+    ptr: *mut c_void,
+    opaque: Option<&'t sys::EcsOpaque>,
+}
+
+impl StructVisitor<'_> {
+    unsafe fn member<E: de::Error>(&self, m: &sys::ecs_member_t) -> Result<PlaceElements, E> {
+        let ptr = match self.opaque {
+            None => self.ptr.byte_add(m.offset as usize),
+            Some(opaque) => {
+                let ensure_member = opaque.ensure_member.ok_or_else(|| {
+                    de::Error::custom("opaque type has no ensure_member callback")
+                })?;
+                let ptr = ensure_member(self.ptr, m.name);
+                if ptr.is_null() {
+                    return Err(de::Error::custom(format!(
+                        "opaque type has no member '{}'",
+                        cstr(m.name)
+                    )));
+                }
+                ptr
+            }
+        };
+        Ok(PlaceElements::member(self.world, m, ptr))
+    }
+}
+
+impl<'de> Visitor<'de> for StructVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a struct")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            let member = unsafe { self.members.iter().find(|m| cstr(m.name) == key) };
+            let Some(member) = member else {
+                return Err(de::Error::unknown_field(&key, unsafe {
+                    member_names(self.members)
+                }));
+            };
+            map.next_value_seed(unsafe { self.member(member)? })?;
+        }
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for (i, member) in self.members.iter().enumerate() {
+            seq.next_element_seed(unsafe { self.member(member)? })?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(())
+    }
+}
+
+/// Visits the elements of a vector. For opaque types the elements are looked
+/// up with the `ensure_element` callback, otherwise the value is an `ecs_vec_t`.
+struct VectorVisitor<'t> {
+    world: *const sys::ecs_world_t,
+    elem: u64,
+    ptr: *mut c_void,
+    opaque: Option<&'t sys::EcsOpaque>,
+}
+
+/// Seed for a single vector element, which is only created when the input
+/// contains it.
+struct VectorElementSeed<'v, 't> {
+    vector: &'v VectorVisitor<'t>,
+    index: usize,
+}
+
+impl<'de> DeserializeSeed<'de> for VectorElementSeed<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let VectorVisitor {
+            world, elem, ptr, ..
+        } = *self.vector;
+        let ptr = unsafe {
+            match self.vector.opaque {
+                Some(opaque) => {
+                    let ensure_element = opaque.ensure_element.ok_or_else(|| {
+                        de::Error::custom("opaque type has no ensure_element callback")
+                    })?;
+                    ensure_element(ptr, self.index)
+                }
+                None => vec_element(world, ptr as *mut sys::ecs_vec_t, elem, self.index),
+            }
+        };
+        if ptr.is_null() {
+            return Err(de::Error::custom(format!(
+                "cannot create element {}",
+                self.index
+            )));
+        }
+        Place {
+            world,
+            type_id: elem,
+            ptr,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+impl VectorVisitor<'_> {
+    /// Remove the elements that were not in the input.
+    unsafe fn truncate(&self, count: usize) {
+        match self.opaque {
+            Some(opaque) => {
+                if let Some(resize) = opaque.resize {
+                    resize(self.ptr, count);
+                }
+            }
+            None => vec_truncate(
+                self.world,
+                self.ptr as *mut sys::ecs_vec_t,
+                self.elem,
+                count,
+            ),
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for VectorVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+        while seq
+            .next_element_seed(VectorElementSeed {
+                vector: &self,
+                index,
+            })?
+            .is_some()
+        {
+            index += 1;
+        }
+        unsafe { self.truncate(index) };
+        Ok(())
+    }
+}
+
+unsafe fn deserialize_opaque<'de, D: Deserializer<'de>>(
+    place: Place,
+    opaque: &sys::EcsOpaque,
+    deserializer: D,
+) -> Result<(), D::Error> {
+    let Place { world, ptr, .. } = place;
+    let Some(kind) = type_kind(world, opaque.as_type) else {
+        return Err(de::Error::custom(no_reflection(world, opaque.as_type)));
+    };
+
+    match kind {
+        Kind::Primitive(primitive) => {
+            let scalar = deserialize_scalar(primitive, deserializer)?;
+            assign_opaque(world, place.type_id, ptr, opaque, primitive, scalar)
+                .map_err(de::Error::custom)
+        }
+        Kind::Enum(e) => {
+            let constant = deserialize_enum(world, opaque.as_type, e, deserializer)?;
+            assign_opaque(
+                world,
+                place.type_id,
+                ptr,
+                opaque,
+                sys::ecs_primitive_kind_t_EcsI64,
+                Scalar::Int(constant),
+            )
+            .map_err(de::Error::custom)
+        }
+        Kind::Bitmask(b) => {
+            let bits = deserialize_bitmask(b, deserializer)?;
+            assign_opaque(
+                world,
+                place.type_id,
+                ptr,
+                opaque,
+                sys::ecs_primitive_kind_t_EcsU32,
+                Scalar::UInt(bits as u64),
+            )
+            .map_err(de::Error::custom)
+        }
+        Kind::Struct(members) => deserializer.deserialize_struct(
+            type_name(world, opaque.as_type),
+            member_names(members),
+            StructVisitor {
+                world,
+                members,
+                ptr,
+                opaque: Some(opaque),
+            },
+        ),
+        Kind::Array(array) => deserializer.deserialize_seq(VectorVisitor {
+            world,
+            elem: array.type_,
+            ptr,
+            opaque: Some(opaque),
+        }),
+        Kind::Vector(vector) => deserializer.deserialize_seq(VectorVisitor {
+            world,
+            elem: vector.type_,
+            ptr,
+            opaque: Some(opaque),
+        }),
+        Kind::Opaque(_) => Err(de::Error::custom(
+            "opaque type cannot be described as another opaque type",
+        )),
+    }
+}
@@ -0,0 +1,575 @@
+//! Reflection for the collections, boxes and shared strings of the standard
+//! library. These are registered as opaque types the first time their id is
+//! fetched with the [`id!`] macro, which is what the `#[meta]` attribute of the
+//! `Component` derive does for every field.
+//!
+//! Maps and sets can't hand out their elements for in place deserialization,
+//! so their elements are deserialized into a staging buffer that replaces the
+//! contents of the collection when the collection is resized. Maps are
+//! reflected as a vector of `{key, value}` entries.
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::hash::{BuildHasher, Hash};
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use fxhash::FxHashMap;
+
+use super::reflection::{cstr, type_kind, Kind};
+use super::*;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A type of the standard library that is reflected as an opaque type when
+/// its id is fetched with the [`id!`] macro.
+///
+/// The ids of the type arguments are looked up by type, so reflected
+/// components must be registered before the collections that contain them.
+/// The `#[meta]` attribute of the `Component` derive does this for the field
+/// types of a component.
+///
+/// Boxes are deserialized through the reflection data of the boxed type, which
+/// is supported for structs and opaque types. Other boxed types can only be
+/// serialized.
+pub trait StdType: private::Sealed + 'static {
+    #[doc(hidden)]
+    fn std_id(world: WorldRef) -> u64;
+}
+
+/// The name of a type without the module path of the outer type, such as
+/// `HashMap<alloc::string::String, i32>`.
+fn std_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let generics = name.find('<').unwrap_or(name.len());
+    match name[..generics].rfind("::") {
+        Some(index) => &name[index + 2..],
+        None => name,
+    }
+}
+
+/// Returns the id of a standard library type, registering it with `init` if
+/// the world doesn't know the type yet.
+fn std_component_id<T: 'static>(
+    world: WorldRef,
+    register_ctor: fn(&mut sys::ecs_type_hooks_t),
+    init: fn(WorldRef, u64),
+) -> u64 {
+    if let Some(id) = world.components_map().get(&TypeId::of::<T>()) {
+        return *id;
+    }
+
+    let name = compact_str::format_compact!("flecs::rust::{}\0", std_type_name::<T>());
+    let id = external_register_component::<false, T>(world, name.as_ptr() as *const c_char);
+    world.components_map().insert(TypeId::of::<T>(), id);
+
+    let world_ptr = world.world_ptr_mut();
+    unsafe {
+        let mut hooks = sys::ecs_get_hooks_id(world_ptr, id)
+            .as_ref()
+            .copied()
+            .unwrap_or_default();
+        register_ctor(&mut hooks);
+        sys::ecs_set_hooks_id(world_ptr, id, &hooks);
+    }
+
+    init(world, id);
+    id
+}
+
+fn register_str_ctor<T: for<'s> From<&'s str>>(hooks: &mut sys::ecs_type_hooks_t) {
+    extern "C" fn ctor<T: for<'s> From<&'s str>>(
+        ptr: *mut c_void,
+        count: i32,
+        _type_info: *const sys::ecs_type_info_t,
+    ) {
+        let ptr = ptr as *mut T;
+        for i in 0..count as usize {
+            unsafe { std::ptr::write(ptr.add(i), T::from("")) };
+        }
+    }
+
+    hooks.ctor = Some(ctor::<T>);
+}
+
+fn world_of(s: &Serializer) -> WorldRef<'_> {
+    unsafe { WorldRef::from_ptr(s.world as *mut sys::ecs_world_t) }
+}
+
+impl<T: Default + 'static> private::Sealed for VecDeque<T> {}
+
+impl<T: Default + 'static> StdType for VecDeque<T> {
+    fn std_id(world: WorldRef) -> u64 {
+        std_component_id::<Self>(
+            world,
+            register_ctor_lifecycle_actions::<Self>,
+            vec_deque_init::<T>,
+        )
+    }
+}
+
+fn vec_deque_init<T: Default + 'static>(world: WorldRef, id: u64) {
+    let mut ts = Opaque::<VecDeque<T>, T>::new_id(world, id);
+    ts.as_type(world.vector_id(id!(world, T)));
+
+    ts.serialize(|s: &Serializer, data: &VecDeque<T>| {
+        let id = id!(world_of(s), T);
+        for el in data.iter() {
+            s.value_id(id, el as *const T as *const c_void);
+        }
+        0
+    });
+
+    fn ensure_element<T: Default>(data: &mut VecDeque<T>, elem: usize) -> &mut T {
+        if data.len() <= elem {
+            data.resize_with(elem + 1, T::default);
+        }
+        &mut data[elem]
+    }
+
+    fn resize<T: Default>(data: &mut VecDeque<T>, count: usize) {
+        data.resize_with(count, T::default);
+    }
+
+    ts.count(|data: &mut VecDeque<T>| data.len());
+    ts.ensure_element(ensure_element::<T>);
+    ts.resize(resize::<T>);
+}
+
+/// A collection whose elements are deserialized into a staging buffer.
+trait StagedCollection: Default + 'static {
+    type Elem: Default + 'static;
+
+    /// The id of the type that describes an element.
+    fn elem_id(world: WorldRef) -> u64;
+
+    fn count(&self) -> usize;
+
+    fn serialize_elems(&self, s: &Serializer);
+
+    fn replace(&mut self, elems: impl Iterator<Item = Self::Elem>);
+}
+
+thread_local! {
+    /// The staged elements of collections, by the address of the collection.
+    static STAGED: RefCell<FxHashMap<usize, Box<dyn Any>>> = RefCell::default();
+}
+
+fn staged_element<C: StagedCollection>(data: &mut C, elem: usize) -> &mut C::Elem {
+    let key = data as *mut C as usize;
+    let elem = STAGED.with(|staged| {
+        let mut staged = staged.borrow_mut();
+        let elems = staged
+            .entry(key)
+            .or_insert_with(|| Box::new(Vec::<Box<C::Elem>>::new()));
+        if !elems.is::<Vec<Box<C::Elem>>>() {
+            // left behind by a collection of another type that was not resized
+            *elems = Box::new(Vec::<Box<C::Elem>>::new());
+        }
+        let elems = elems.downcast_mut::<Vec<Box<C::Elem>>>().unwrap();
+        while elems.len() <= elem {
+            elems.push(Box::default());
+        }
+        // elements are boxed, so the pointer stays valid when the buffer grows
+        &mut *elems[elem] as *mut C::Elem
+    });
+    unsafe { &mut *elem }
+}
+
+fn commit_staged<C: StagedCollection>(data: &mut C, count: usize) {
+    let key = data as *mut C as usize;
+    let elems = STAGED
+        .with(|staged| staged.borrow_mut().remove(&key))
+        .and_then(|elems| elems.downcast::<Vec<Box<C::Elem>>>().ok());
+    match elems {
+        Some(elems) => data.replace(elems.into_iter().take(count).map(|elem| *elem)),
+        None if count == 0 => data.replace(std::iter::empty()),
+        None => {}
+    }
+}
+
+fn staged_init<C: StagedCollection>(world: WorldRef, id: u64) {
+    let mut ts = Opaque::<C, C::Elem>::new_id(world, id);
+    ts.as_type(world.vector_id(C::elem_id(world)));
+
+    ts.serialize(|s: &Serializer, data: &C| {
+        data.serialize_elems(s);
+        0
+    });
+
+    ts.count(|data: &mut C| data.count());
+    ts.ensure_element(staged_element::<C>);
+    ts.resize(commit_staged::<C>);
+}
+
+macro_rules! impl_std_set {
+    ($set:ident<T $(, $hasher:ident)?> where T: $($bound:path),*) => {
+        impl<T: Default + $($bound +)* 'static $(, $hasher: BuildHasher + Default + 'static)?>
+            private::Sealed for $set<T $(, $hasher)?>
+        {
+        }
+
+        impl<T: Default + $($bound +)* 'static $(, $hasher: BuildHasher + Default + 'static)?>
+            StdType for $set<T $(, $hasher)?>
+        {
+            fn std_id(world: WorldRef) -> u64 {
+                std_component_id::<Self>(
+                    world,
+                    register_ctor_lifecycle_actions::<Self>,
+                    staged_init::<Self>,
+                )
+            }
+        }
+
+        impl<T: Default + $($bound +)* 'static $(, $hasher: BuildHasher + Default + 'static)?>
+            StagedCollection for $set<T $(, $hasher)?>
+        {
+            type Elem = T;
+
+            fn elem_id(world: WorldRef) -> u64 {
+                id!(world, T).id()
+            }
+
+            fn count(&self) -> usize {
+                self.len()
+            }
+
+            fn serialize_elems(&self, s: &Serializer) {
+                let id = id!(world_of(s), T);
+                for el in self.iter() {
+                    s.value_id(id, el as *const T as *const c_void);
+                }
+            }
+
+            fn replace(&mut self, elems: impl Iterator<Item = T>) {
+                self.clear();
+                self.extend(elems);
+            }
+        }
+    };
+}
+
+impl_std_set!(HashSet<T, S> where T: Eq, Hash);
+impl_std_set!(BTreeSet<T> where T: Ord);
+
+/// A deserialized map entry, also used to describe the layout of an entry.
+#[repr(C)]
+#[derive(Default)]
+struct KeyValue<K, V> {
+    key: K,
+    value: V,
+}
+
+/// A serialized map entry.
+struct MapEntry<K, V> {
+    key: *const K,
+    value: *const V,
+}
+
+/// Returns the id of the opaque type of map entries, which serializes a
+/// [`MapEntry`] and deserializes into a [`KeyValue`].
+fn map_entry_id<K: Default + 'static, V: Default + 'static>(world: WorldRef) -> u64 {
+    std_component_id::<MapEntry<K, V>>(world, |_| {}, map_entry_init::<K, V>)
+}
+
+fn map_entry_init<K: Default + 'static, V: Default + 'static>(world: WorldRef, id: u64) {
+    let layout = std_component_id::<KeyValue<K, V>>(
+        world,
+        register_ctor_lifecycle_actions::<KeyValue<K, V>>,
+        |world, id| {
+            world
+                .component_ext(FetchedId::<KeyValue<K, V>>::new(id))
+                .member_id(
+                    id!(world, K),
+                    ("key", Count(1), core::mem::offset_of!(KeyValue<K, V>, key)),
+                )
+                .member_id(
+                    id!(world, V),
+                    (
+                        "value",
+                        Count(1),
+                        core::mem::offset_of!(KeyValue<K, V>, value),
+                    ),
+                );
+        },
+    );
+
+    let mut ts = Opaque::<MapEntry<K, V>>::new_id(world, id);
+    ts.as_type(layout);
+
+    ts.serialize(|s: &Serializer, data: &MapEntry<K, V>| {
+        let world = world_of(s);
+        s.member("key");
+        s.value_id(id!(world, K), data.key as *const c_void);
+        s.member("value");
+        s.value_id(id!(world, V), data.value as *const c_void);
+        0
+    });
+
+    unsafe extern "C" fn ensure_member<K, V>(
+        data: *mut c_void,
+        member: *const c_char,
+    ) -> *mut c_void {
+        let data = &mut *(data as *mut KeyValue<K, V>);
+        match CStr::from_ptr(member).to_bytes() {
+            b"key" => &mut data.key as *mut K as *mut c_void,
+            b"value" => &mut data.value as *mut V as *mut c_void,
+            _ => std::ptr::null_mut(),
+        }
+    }
+
+    ts.desc.type_.ensure_member = Some(ensure_member::<K, V>);
+}
+
+macro_rules! impl_std_map {
+    ($map:ident<K, V $(, $hasher:ident)?> where K: $($bound:path),*) => {
+        impl<
+            K: Default + $($bound +)* 'static,
+            V: Default + 'static
+            $(, $hasher: BuildHasher + Default + 'static)?
+        > private::Sealed for $map<K, V $(, $hasher)?>
+        {
+        }
+
+        impl<
+            K: Default + $($bound +)* 'static,
+            V: Default + 'static
+            $(, $hasher: BuildHasher + Default + 'static)?
+        > StdType for $map<K, V $(, $hasher)?>
+        {
+            fn std_id(world: WorldRef) -> u64 {
+                std_component_id::<Self>(
+                    world,
+                    register_ctor_lifecycle_actions::<Self>,
+                    staged_init::<Self>,
+                )
+            }
+        }
+
+        impl<
+            K: Default + $($bound +)* 'static,
+            V: Default + 'static
+            $(, $hasher: BuildHasher + Default + 'static)?
+        > StagedCollection for $map<K, V $(, $hasher)?>
+        {
+            type Elem = KeyValue<K, V>;
+
+            fn elem_id(world: WorldRef) -> u64 {
+                map_entry_id::<K, V>(world)
+            }
+
+            fn count(&self) -> usize {
+                self.len()
+            }
+
+            fn serialize_elems(&self, s: &Serializer) {
+                let id = map_entry_id::<K, V>(world_of(s));
+                for (key, value) in self.iter() {
+                    let entry = MapEntry::<K, V> { key, value };
+                    s.value_id(id, &entry as *const MapEntry<K, V> as *const c_void);
+                }
+            }
+
+            fn replace(&mut self, elems: impl Iterator<Item = KeyValue<K, V>>) {
+                self.clear();
+                self.extend(elems.map(|entry| (entry.key, entry.value)));
+            }
+        }
+    };
+}
+
+impl_std_map!(HashMap<K, V, S> where K: Eq, Hash);
+impl_std_map!(BTreeMap<K, V> where K: Ord);
+
+/// How the value behind a box is deserialized.
+enum Pointee {
+    /// The names and offsets of the members of a struct.
+    Struct(Vec<(CString, usize)>),
+    /// The callbacks of an opaque type.
+    Opaque(sys::EcsOpaque),
+}
+
+/// The pointees of boxes, by the type id of the boxed type. Callbacks of
+/// opaque types don't get the world, so this is shared by all worlds.
+static POINTEES: OnceLock<Mutex<FxHashMap<TypeId, &'static Pointee>>> = OnceLock::new();
+
+fn pointee<T: 'static>() -> &'static Pointee {
+    let pointees = POINTEES.get_or_init(Default::default).lock().unwrap();
+    pointees
+        .get(&TypeId::of::<T>())
+        .expect("box is registered without a pointee")
+}
+
+unsafe fn unbox<T>(data: *const c_void) -> *mut c_void {
+    &**(data as *const Box<T>) as *const T as *mut c_void
+}
+
+impl<T: Default + 'static> private::Sealed for Box<T> {}
+
+impl<T: Default + 'static> StdType for Box<T> {
+    fn std_id(world: WorldRef) -> u64 {
+        std_component_id::<Self>(
+            world,
+            register_ctor_lifecycle_actions::<Self>,
+            box_init::<T>,
+        )
+    }
+}
+
+macro_rules! forward_opaque {
+    ($($callback:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)*) => {
+        /// Forward the callbacks of the opaque type `T` to the value behind a box.
+        fn forward_opaque<T: 'static>(desc: &mut sys::EcsOpaque, opaque: &sys::EcsOpaque) {
+            $(
+                unsafe extern "C" fn $callback<T: 'static>(
+                    data: *mut c_void
+                    $(, $arg: $ty)*
+                ) $(-> $ret)? {
+                    let Pointee::Opaque(opaque) = pointee::<T>() else {
+                        unreachable!("box of an opaque type has no opaque pointee")
+                    };
+                    opaque.$callback.unwrap()(unbox::<T>(data) $(, $arg)*)
+                }
+
+                if opaque.$callback.is_some() {
+                    desc.$callback = Some($callback::<T>);
+                }
+            )*
+
+            unsafe extern "C" fn count<T: 'static>(data: *const c_void) -> usize {
+                let Pointee::Opaque(opaque) = pointee::<T>() else {
+                    unreachable!("box of an opaque type has no opaque pointee")
+                };
+                opaque.count.unwrap()(unbox::<T>(data))
+            }
+
+            if opaque.count.is_some() {
+                desc.count = Some(count::<T>);
+            }
+        }
+    };
+}
+
+forward_opaque! {
+    assign_bool(value: bool);
+    assign_char(value: c_char);
+    assign_int(value: i64);
+    assign_uint(value: u64);
+    assign_float(value: f64);
+    assign_string(value: *const c_char);
+    assign_entity(world: *mut sys::ecs_world_t, entity: u64);
+    assign_id(world: *mut sys::ecs_world_t, id: u64);
+    assign_null();
+    clear();
+    ensure_element(elem: usize) -> *mut c_void;
+    ensure_member(member: *const c_char) -> *mut c_void;
+    resize(count: usize);
+}
+
+unsafe extern "C" fn ensure_boxed_member<T: 'static>(
+    data: *mut c_void,
+    member: *const c_char,
+) -> *mut c_void {
+    let Pointee::Struct(members) = pointee::<T>() else {
+        unreachable!("box of a struct has no struct pointee")
+    };
+    let member = CStr::from_ptr(member);
+    members
+        .iter()
+        .find(|(name, _)| name.as_c_str() == member)
+        .map_or(std::ptr::null_mut(), |(_, offset)| {
+            unbox::<T>(data).byte_add(*offset)
+        })
+}
+
+#[allow(clippy::borrowed_box)]
+fn box_init<T: 'static>(world: WorldRef, id: u64) {
+    let inner = id!(world, T).id();
+    let mut ts = Opaque::<Box<T>>::new_id(world, id);
+    ts.as_type(inner);
+
+    ts.serialize(|s: &Serializer, data: &Box<T>| {
+        let world = world_of(s);
+        let id = id!(world, T).id();
+        let value = &**data as *const T as *const c_void;
+        // a box of a struct is described by the struct, so it serializes the
+        // members instead of a nested struct
+        match unsafe { type_kind(world.world_ptr(), id) } {
+            Some(Kind::Struct(members)) => {
+                for m in members {
+                    s.member(unsafe { cstr(m.name) });
+                    s.value_id(m.type_, unsafe { value.byte_add(m.offset as usize) });
+                }
+                0
+            }
+            _ => s.value_id(id, value),
+        }
+    });
+
+    let pointee = match unsafe { type_kind(world.world_ptr(), inner) } {
+        Some(Kind::Struct(members)) => {
+            ts.desc.type_.ensure_member = Some(ensure_boxed_member::<T>);
+            Pointee::Struct(
+                members
+                    .iter()
+                    .map(|m| unsafe { (CStr::from_ptr(m.name).to_owned(), m.offset as usize) })
+                    .collect(),
+            )
+        }
+        Some(Kind::Opaque(opaque)) => {
+            ts.as_type(opaque.as_type);
+            forward_opaque::<T>(&mut ts.desc.type_, opaque);
+            Pointee::Opaque(*opaque)
+        }
+        _ => return,
+    };
+
+    let mut pointees = POINTEES.get_or_init(Default::default).lock().unwrap();
+    pointees
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::leak(Box::new(pointee)));
+}
+
+fn shared_str_init<P>(world: WorldRef, id: u64)
+where
+    P: Deref<Target = str> + for<'s> From<&'s str> + 'static,
+{
+    let mut ts = Opaque::<P>::new_id(world, id);
+    ts.as_type(flecs::meta::String);
+
+    ts.serialize(|s: &Serializer, data: &P| {
+        let data = compact_str::format_compact!("{}\0", &**data);
+        s.value_id(
+            flecs::meta::String,
+            &data.as_ptr() as *const *const u8 as *const c_void,
+        )
+    });
+
+    ts.assign_string(|data: &mut P, value: *const i8| {
+        *data = P::from(&unsafe { CStr::from_ptr(value) }.to_string_lossy());
+    });
+}
+
+macro_rules! impl_std_shared_str {
+    ($($pointer:ident),*) => {
+        $(
+            impl private::Sealed for $pointer<str> {}
+
+            impl StdType for $pointer<str> {
+                fn std_id(world: WorldRef) -> u64 {
+                    std_component_id::<Self>(
+                        world,
+                        register_str_ctor::<Self>,
+                        shared_str_init::<Self>,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_std_shared_str!(Box, Rc, Arc);
@@ -0,0 +1,344 @@
+use crate::core::*;
+use crate::sys;
+
+use super::{Metric, MetricKind};
+
+/// [`MetricBuilder`] is a builder pattern for creating metrics.
+///
+/// These are typically constructed via [`World::metric()`]
+pub struct MetricBuilder<'a> {
+    desc: sys::ecs_metric_desc_t,
+    dotmember: Option<compact_str::CompactString>,
+    brief: Option<compact_str::CompactString>,
+    world: WorldRef<'a>,
+}
+
+impl<'a> MetricBuilder<'a> {
+    /// Create a new metric builder for a new entity.
+    ///
+    /// # See also
+    ///
+    /// * [`World::metric()`]
+    /// * C++ API: `metric_builder::metric_builder`
+    #[doc(alias = "metric_builder::metric_builder")]
+    pub(crate) fn new(world: impl WorldProvider<'a>) -> Self {
+        let entity = unsafe { sys::ecs_new(world.world_ptr_mut()) };
+        Self::new_from(world, entity)
+    }
+
+    /// Create a new metric builder for a new named entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the metric.
+    ///
+    /// # See also
+    ///
+    /// * [`World::metric_named()`]
+    /// * C++ API: `metric_builder::metric_builder`
+    #[doc(alias = "metric_builder::metric_builder")]
+    pub(crate) fn new_named(world: impl WorldProvider<'a>, name: &str) -> Self {
+        let name = compact_str::format_compact!("{}\0", name);
+        let entity_desc = sys::ecs_entity_desc_t {
+            name: name.as_ptr() as *const i8,
+            sep: SEPARATOR.as_ptr(),
+            root_sep: SEPARATOR.as_ptr(),
+            ..Default::default()
+        };
+        let entity = unsafe { sys::ecs_entity_init(world.world_ptr_mut(), &entity_desc) };
+        Self::new_from(world, entity)
+    }
+
+    /// Create a new metric builder for an existing entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to turn into a metric.
+    ///
+    /// # See also
+    ///
+    /// * [`World::metric_from()`]
+    /// * C++ API: `metric_builder::metric_builder`
+    #[doc(alias = "metric_builder::metric_builder")]
+    pub(crate) fn new_from(world: impl WorldProvider<'a>, entity: impl Into<Entity>) -> Self {
+        MetricBuilder {
+            desc: sys::ecs_metric_desc_t {
+                entity: *entity.into(),
+                ..Default::default()
+            },
+            dotmember: None,
+            brief: None,
+            world: world.world(),
+        }
+    }
+
+    /// Set the member entity that stores the metric value.
+    ///
+    /// Must not be combined with [`MetricKind::CounterId`].
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - The member entity, as created by the meta addon.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::member`
+    #[doc(alias = "metric_builder::member")]
+    pub fn member_id(&mut self, member: impl Into<Entity>) -> &mut Self {
+        self.desc.member = *member.into();
+        self
+    }
+
+    /// Set the member that stores the metric value by name.
+    ///
+    /// The member is looked up in the scope of the id set with [`Self::id()`]
+    /// or [`Self::id_untyped()`] if one was set, otherwise the name is looked up
+    /// as a path from the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of (or path to) the member entity.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::member`
+    #[doc(alias = "metric_builder::member")]
+    pub fn member_named(&mut self, name: &str) -> &mut Self {
+        let world = self.world;
+        let scope = if self.desc.id != 0 {
+            unsafe { sys::ecs_get_typeid(world.world_ptr(), self.desc.id) }
+        } else {
+            0
+        };
+
+        let member = if scope != 0 {
+            EntityView::new_from(world, scope)
+                .try_lookup(name)
+                .map(|e| e.id)
+        } else {
+            world.try_lookup(name).map(|e| e.id)
+        };
+
+        ecs_assert!(
+            member.is_some(),
+            FlecsErrorCode::InvalidParameter,
+            "member '{}' not found",
+            name
+        );
+
+        if let Some(member) = member {
+            self.member_id(member);
+        }
+        self
+    }
+
+    /// Set the member of component `T` that stores the metric value.
+    ///
+    /// `T` must have been registered with reflection data, for example by
+    /// deriving `Component` and calling [`Component::meta()`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component that has the member.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field in `T`.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::member`
+    #[doc(alias = "metric_builder::member")]
+    pub fn member<T: ComponentId>(&mut self, name: &str) -> &mut Self {
+        let world = self.world;
+        let member = EntityView::new_from(world, T::id(world))
+            .try_lookup(name)
+            .map(|e| e.id);
+
+        ecs_assert!(
+            member.is_some(),
+            FlecsErrorCode::InvalidParameter,
+            "member '{}' not found in type '{}'",
+            name,
+            std::any::type_name::<T>()
+        );
+
+        if let Some(member) = member {
+            self.member_id(member);
+        }
+        self
+    }
+
+    /// Set the member that stores the metric value with a dot expression,
+    /// for example `"position.x"`. This allows for nested members.
+    ///
+    /// Must be combined with [`Self::id()`] or [`Self::id_untyped()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The member dot expression.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::dotmember`
+    #[doc(alias = "metric_builder::dotmember")]
+    pub fn dotmember_named(&mut self, expr: &str) -> &mut Self {
+        self.dotmember = Some(compact_str::format_compact!("{}\0", expr));
+        self
+    }
+
+    /// Set the member of component `T` that stores the metric value with a
+    /// dot expression, for example `"position.x"`. This allows for nested members.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component that has the (nested) member.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The member dot expression.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::dotmember`
+    #[doc(alias = "metric_builder::dotmember")]
+    pub fn dotmember<T: ComponentId>(&mut self, expr: &str) -> &mut Self {
+        let world = self.world;
+        self.desc.id = T::id(world);
+        self.dotmember_named(expr)
+    }
+
+    /// Set the (component) id to track.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id or pair to track.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::id`
+    #[doc(alias = "metric_builder::id")]
+    pub fn id_untyped(&mut self, id: impl IntoId) -> &mut Self {
+        self.desc.id = *id.into();
+        self
+    }
+
+    /// Set the (component) id to track.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component or pair to track.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::id`
+    #[doc(alias = "metric_builder::id")]
+    pub fn id<T: ComponentOrPairId>(&mut self) -> &mut Self {
+        let world = self.world;
+        self.id_untyped(T::get_id(world))
+    }
+
+    /// Track individual targets.
+    ///
+    /// If the id is a `(R, *)` wildcard and relationship `R` has the `OneOf`
+    /// property, setting this to true will track individual targets. If the kind
+    /// is [`MetricKind::CounterId`] this will create a metric per target.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Whether to track targets.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::targets`
+    #[doc(alias = "metric_builder::targets")]
+    pub fn targets(&mut self, value: bool) -> &mut Self {
+        self.desc.targets = value;
+        self
+    }
+
+    /// Set the metric kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The metric kind.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::kind`
+    #[doc(alias = "metric_builder::kind")]
+    pub fn kind(&mut self, kind: MetricKind) -> &mut Self {
+        self.kind_id(kind)
+    }
+
+    /// Set the metric kind by entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The metric kind entity. Must be one of the builtin kinds.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::kind`
+    #[doc(alias = "metric_builder::kind")]
+    pub fn kind_id(&mut self, kind: impl Into<Entity>) -> &mut Self {
+        self.desc.kind = *kind.into();
+        self
+    }
+
+    /// Set a brief description for the metric.
+    ///
+    /// The description is only stored if the doc addon is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `brief` - The description.
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `metric_builder::brief`
+    #[doc(alias = "metric_builder::brief")]
+    pub fn brief(&mut self, brief: &str) -> &mut Self {
+        self.brief = Some(compact_str::format_compact!("{}\0", brief));
+        self
+    }
+
+    /// Create the metric.
+    ///
+    /// # Returns
+    ///
+    /// The metric entity.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_metric_init`
+    #[doc(alias = "ecs_metric_init")]
+    pub fn build(&mut self) -> Metric<'a> {
+        if let Some(dotmember) = &self.dotmember {
+            self.desc.dotmember = dotmember.as_ptr() as *const i8;
+        }
+        if let Some(brief) = &self.brief {
+            self.desc.brief = brief.as_ptr() as *const i8;
+        }
+
+        let world = self.world;
+        let entity = unsafe { sys::ecs_metric_init(world.world_ptr_mut(), &self.desc) };
+
+        ecs_assert!(
+            entity != 0,
+            FlecsErrorCode::InvalidParameter,
+            "failed to create metric"
+        );
+
+        Metric {
+            entity: EntityView::new_from(world, entity),
+        }
+    }
+}
+
+impl<'a> WorldProvider<'a> for MetricBuilder<'a> {
+    #[inline(always)]
+    fn world(&self) -> WorldRef<'a> {
+        self.world
+    }
+}
@@ -0,0 +1,337 @@
+//! Metrics are entities that store values measured from a range of different
+//! properties in the ECS storage, such as component member values or the
+//! number of entities with a given component.
+//!
+//! Metrics are created with a [`MetricBuilder`], which is typically obtained
+//! through [`World::metric()`]. Each metric creates one or more metric
+//! instances, which are child entities of the metric that store the measured
+//! [`Value`](flecs::metrics::Value) and the [`Source`](flecs::metrics::Source)
+//! entity the value was measured from. Metric values are updated each frame
+//! when the world is progressed.
+//!
+//! The metrics addon identifies its builtin components by ids that are shared
+//! between worlds. When using more than one world, import the [`Metrics`]
+//! module before creating other entities or components so that these ids are
+//! the same in each world.
+
+mod metric_builder;
+
+pub use metric_builder::*;
+
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::core::*;
+use crate::sys;
+
+#[cfg(feature = "flecs_module")]
+use super::module::Module;
+
+/// The kind of a metric, which determines how the metric value is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    /// The metric stores the value of something at a specific point in time,
+    /// for example "velocity".
+    Gauge,
+    /// The metric stores the value of a member that is already a monotonically
+    /// increasing value, for example "miles driven".
+    Counter,
+    /// The metric is incremented by the value of the member multiplied by the
+    /// frame delta time, for example to integrate a velocity into a distance.
+    CounterIncrement,
+    /// The metric counts the number of entities with a specific (component) id.
+    /// This kind cannot be combined with a member.
+    CounterId,
+}
+
+impl MetricKind {
+    /// Returns the entity that represents this metric kind.
+    ///
+    /// The metrics module must be imported for the returned entity to be valid.
+    pub fn id(self) -> Entity {
+        Entity::new(unsafe {
+            match self {
+                MetricKind::Gauge => sys::EcsGauge,
+                MetricKind::Counter => sys::EcsCounter,
+                MetricKind::CounterIncrement => sys::EcsCounterIncrement,
+                MetricKind::CounterId => sys::EcsCounterId,
+            }
+        })
+    }
+}
+
+impl From<MetricKind> for Entity {
+    #[inline]
+    fn from(kind: MetricKind) -> Self {
+        kind.id()
+    }
+}
+
+/// [`Metric`] is a wrapper around an entity that was created with [`MetricBuilder::build()`].
+#[derive(Clone, Copy)]
+pub struct Metric<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'a> Deref for Metric<'a> {
+    type Target = EntityView<'a>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.entity
+    }
+}
+
+impl<'a> DerefMut for Metric<'a> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.entity
+    }
+}
+
+impl<'a> Metric<'a> {
+    /// Create a new metric handle from an existing metric entity.
+    pub fn new_from(world: impl WorldProvider<'a>, entity: impl Into<Entity>) -> Self {
+        let entity = EntityView::new_from(world, entity);
+        ecs_assert!(
+            entity.has_id(unsafe { sys::EcsMetric }),
+            FlecsErrorCode::InvalidParameter,
+            "Entity is not a metric"
+        );
+        Metric { entity }
+    }
+
+    /// Iterate the instances of this metric.
+    ///
+    /// Instances are created (and updated) when the world is progressed, so a
+    /// metric will not have any instances until the first frame has run.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function invoked for each metric instance.
+    pub fn each_instance(&self, mut func: impl FnMut(MetricInstance<'a>)) {
+        let world = self.entity.world;
+        self.entity.each_child(|child| {
+            if child.has_id(unsafe { sys::EcsMetricInstance }) {
+                func(MetricInstance {
+                    entity: EntityView::new_from(world, child.id),
+                });
+            }
+        });
+    }
+
+    /// Returns the value stored on the metric entity itself.
+    ///
+    /// This is only set for [`MetricKind::CounterId`] metrics that do not track
+    /// individual targets, which store a single value instead of creating
+    /// metric instances.
+    pub fn value(&self) -> Option<f64> {
+        self.entity
+            .try_cloned::<&flecs::metrics::Value>()
+            .map(|v| v.value)
+    }
+
+    /// Returns the number of instances of this metric.
+    pub fn instance_count(&self) -> usize {
+        let mut count = 0;
+        self.each_instance(|_| count += 1);
+        count
+    }
+
+    /// Find the metric instance that was created for the specified source entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The entity the metric instance measures.
+    ///
+    /// # Returns
+    ///
+    /// The metric instance if one exists for the source, otherwise `None`.
+    pub fn try_instance_for(&self, source: impl Into<Entity>) -> Option<MetricInstance<'a>> {
+        let source = source.into();
+        let mut found = None;
+        self.each_instance(|instance| {
+            if found.is_none() && instance.source().id == source {
+                found = Some(instance);
+            }
+        });
+        found
+    }
+}
+
+/// [`MetricInstance`] is a wrapper around a metric instance entity, which stores
+/// the value of a metric for a single source entity.
+#[derive(Clone, Copy)]
+pub struct MetricInstance<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'a> Deref for MetricInstance<'a> {
+    type Target = EntityView<'a>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.entity
+    }
+}
+
+impl<'a> MetricInstance<'a> {
+    /// Returns the current value of the metric instance.
+    pub fn value(&self) -> f64 {
+        self.entity
+            .try_cloned::<&flecs::metrics::Value>()
+            .map(|v| v.value)
+            .unwrap_or_default()
+    }
+
+    /// Returns the entity from which the metric value is measured.
+    pub fn source(&self) -> EntityView<'a> {
+        let source = self
+            .entity
+            .try_cloned::<&flecs::metrics::Source>()
+            .map(|s| s.entity)
+            .unwrap_or_default();
+        EntityView::new_from(self.entity.world, source)
+    }
+
+    /// Returns the metric this instance belongs to.
+    pub fn metric(&self) -> Metric<'a> {
+        let world = self.entity.world;
+        let parent = unsafe {
+            sys::ecs_get_target(world.world_ptr(), *self.entity.id, flecs::ChildOf::ID, 0)
+        };
+        Metric {
+            entity: EntityView::new_from(world, parent),
+        }
+    }
+}
+
+/// Metrics mixin implementation
+impl World {
+    /// Create a new metric builder for a new entity.
+    ///
+    /// This imports the [`Metrics`] module if it has not been imported yet.
+    ///
+    /// # See also
+    ///
+    /// * [`addons::metrics`](crate::addons::metrics)
+    /// * C++ API: `world::metric`
+    #[doc(alias = "world::metric")]
+    pub fn metric(&self) -> MetricBuilder<'_> {
+        self.import::<Metrics>();
+        MetricBuilder::new(self)
+    }
+
+    /// Create a new metric builder for a new named entity.
+    ///
+    /// This imports the [`Metrics`] module if it has not been imported yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the metric.
+    ///
+    /// # See also
+    ///
+    /// * [`addons::metrics`](crate::addons::metrics)
+    /// * C++ API: `world::metric`
+    #[doc(alias = "world::metric")]
+    pub fn metric_named(&self, name: &str) -> MetricBuilder<'_> {
+        self.import::<Metrics>();
+        MetricBuilder::new_named(self, name)
+    }
+
+    /// Create a new metric builder for an existing entity.
+    ///
+    /// This imports the [`Metrics`] module if it has not been imported yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity to turn into a metric.
+    ///
+    /// # See also
+    ///
+    /// * [`addons::metrics`](crate::addons::metrics)
+    /// * C++ API: `world::metric`
+    #[doc(alias = "world::metric")]
+    pub fn metric_from(&self, entity: impl Into<Entity>) -> MetricBuilder<'_> {
+        self.import::<Metrics>();
+        MetricBuilder::new_from(self, entity)
+    }
+}
+
+/// Module that imports the metrics addon.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Metrics;
+
+#[cfg(feature = "flecs_module")]
+impl Module for Metrics {
+    fn module(world: &World) {
+        // Import the C module before the module is moved to its Rust path. See
+        // `World::import_c_module`.
+        world.import_c_module(Some(sys::FlecsMetricsImport), c"FlecsMetrics", unsafe {
+            sys::FLECS_IDFlecsMetricsID_
+        });
+        world.module::<Metrics>("flecs::rust::metrics");
+        world.component::<flecs::metrics::Value>();
+        world.component::<flecs::metrics::Source>();
+    }
+}
+
+///////////////////////////
+// trait implementations
+///////////////////////////
+
+impl flecs_ecs::core::DataComponent for Metrics {}
+
+impl flecs_ecs::core::ComponentType<flecs_ecs::core::Struct> for Metrics {}
+
+impl flecs_ecs::core::component_registration::registration_traits::ComponentInfo for Metrics {
+    const IS_GENERIC: bool = false;
+    const IS_ENUM: bool = false;
+    const IS_TAG: bool = false;
+    type TagType =
+        flecs_ecs::core::component_registration::registration_traits::FlecsFirstIsNotATag;
+    const IMPLS_CLONE: bool = true;
+    const IMPLS_DEFAULT: bool = true;
+    const IS_REF: bool = false;
+    const IS_MUT: bool = false;
+}
+impl flecs_ecs::core::component_registration::registration_traits::ComponentId for Metrics
+where
+    Self: 'static,
+{
+    type UnderlyingType = Metrics;
+    type UnderlyingEnumType = flecs_ecs::core::component_registration::NoneEnum;
+    #[inline(always)]
+    fn index() -> u32 {
+        static INDEX: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(u32::MAX);
+        Self::get_or_init_index(&INDEX)
+    }
+    fn __register_lifecycle_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        flecs_ecs::core::lifecycle_traits::register_lifecycle_actions::<Metrics>(type_hooks);
+    }
+    fn __register_default_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_DEFAULT: bool = Metrics::IMPLS_DEFAULT;
+        if IMPLS_DEFAULT {
+            flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,Metrics>as flecs_ecs::core::component_registration::registration_traits::FlecsDefaultType> ::Type, >(type_hooks);
+        }
+    }
+    fn __register_clone_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_CLONE: bool = Metrics::IMPLS_CLONE;
+        if IMPLS_CLONE {
+            flecs_ecs::core::lifecycle_traits::register_copy_lifecycle_action:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_CLONE,Metrics>as flecs_ecs::core::component_registration::registration_traits::FlecsCloneType> ::Type, >(type_hooks);
+        } else {
+            flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<Metrics>(
+                type_hooks,
+            );
+        }
+    }
+
+    fn __register_or_get_id<'a, const MANUAL_REGISTRATION_CHECK: bool>(
+        world: impl WorldProvider<'a>,
+    ) -> sys::ecs_entity_t {
+        Self::__register_or_get_id_named::<MANUAL_REGISTRATION_CHECK>(world, "flecs::metrics")
+    }
+}
@@ -16,18 +16,39 @@ pub mod pipeline;
 #[cfg(feature = "flecs_stats")]
 pub mod stats;
 
+#[cfg(feature = "flecs_metrics")]
+pub mod metrics;
+
+#[cfg(feature = "flecs_alerts")]
+pub mod alerts;
+
 #[cfg(feature = "flecs_timer")]
 pub mod timer;
 
 #[cfg(feature = "flecs_meta")]
 pub mod meta;
 
+#[cfg(feature = "flecs_units")]
+pub mod units;
+
 #[cfg(feature = "flecs_script")]
 pub mod script;
 
+#[cfg(feature = "flecs_journal")]
+pub mod journal;
+
 #[cfg(feature = "flecs_json")]
 pub mod json;
 
+#[cfg(feature = "flecs_snapshot")]
+pub mod snapshot;
+
+#[cfg(feature = "flecs_http")]
+pub mod http;
+
+#[cfg(feature = "flecs_rest")]
+pub mod rest;
+
 // this is not feature gated to flecs_meta so calling `.meta()` on a component will always work despite meta being disabled.
 pub trait Meta<Component> {
     fn meta(component: flecs_ecs::core::Component<Component>);
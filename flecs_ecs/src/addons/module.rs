@@ -4,8 +4,8 @@
 //! * To import a module, see [`World::import()`].
 //! * To override the name of a module, see [`World::module()`].
 use crate::core::{
-    ecs_pair, flecs, register_componment_data_explicit, ComponentId, EntityView, FlecsConstantId,
-    IdOperations, World, WorldProvider, SEPARATOR,
+    ecs_assert, ecs_pair, flecs, register_componment_data_explicit, ComponentId, EntityView,
+    FlecsConstantId, FlecsErrorCode, IdOperations, World, WorldProvider, SEPARATOR,
 };
 use crate::sys;
 
@@ -74,6 +74,10 @@ impl World {
     /// * [`World::module()`]
     /// * C++ API: `world::import`
     pub fn import<T: Module>(&self) -> EntityView {
+        // Reset scope, so that a module imported from inside another module is
+        // not registered as a child of that module
+        let prev_scope = self.set_scope_id(0);
+
         let module = if T::is_registered_with_world(self) {
             self.component::<T>().entity
         } else {
@@ -95,6 +99,7 @@ impl World {
 
         // If we have already registered this type don't re-create the module
         if module.has::<flecs::Module>() {
+            self.set_scope_id(prev_scope);
             return module;
         }
 
@@ -103,9 +108,6 @@ impl World {
         // module resources.
         module.add_trait::<flecs::Sparse>();
 
-        // Reset scope
-        let prev_scope = self.set_scope_id(0);
-
         // Set scope to our module
         self.set_scope_id(module);
 
@@ -192,3 +194,40 @@ impl World {
         EntityView::new_from(self, *id)
     }
 }
+
+impl World {
+    /// Import a C addon module, unless it was already imported in this world.
+    ///
+    /// Rust modules that wrap a C module call this before [`World::module()`],
+    /// because moving the module deletes the entities of its old path. C modules
+    /// keep their entity in a process-wide global that other worlds reuse as
+    /// the module id, so the module must not be created from a recycled id, and
+    /// the id must not be taken by another entity in this world.
+    ///
+    /// # Arguments
+    ///
+    /// * `import` - The import function of the C module. Its ABI follows the
+    ///   declarations of the C library, which unwind with `flecs_abort_panic`.
+    /// * `c_name` - The C name of the module, e.g. `FlecsMetrics`.
+    /// * `module_id` - The value of the global that stores the module entity.
+    pub(crate) fn import_c_module(
+        &self,
+        import: sys::ecs_module_action_t,
+        c_name: &std::ffi::CStr,
+        module_id: sys::ecs_entity_t,
+    ) {
+        let world = self.raw_world.as_ptr();
+        unsafe {
+            ecs_assert!(
+                module_id == 0
+                    || !sys::ecs_exists(world, module_id)
+                    || (sys::ecs_is_alive(world, module_id)
+                        && sys::ecs_has_id(world, module_id, flecs::Module::ID)),
+                FlecsErrorCode::InvalidOperation,
+                "the id of the C module is in use in this world, import the module at \
+                 the same point in every world"
+            );
+            sys::ecs_import_c(world, import, c_name.as_ptr());
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! The REST addon provides the HTTP endpoints used by the Flecs explorer.
+//!
+//! Besides serving the endpoints over a socket with [`App::enable_rest()`](crate::addons::app::App::enable_rest),
+//! requests can be dispatched in-process with [`World::rest_request()`]. This
+//! runs the same `/entity`, `/query`, `/world`, ... endpoints without opening a
+//! socket, which is useful for tools and tests.
+
+use std::ffi::CStr;
+
+use crate::addons::http::has_line_break;
+use crate::core::*;
+use crate::sys;
+
+/// The reply to a request that was dispatched with [`World::rest_request()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestReply {
+    /// The HTTP status code, for example `200`.
+    pub code: i32,
+    /// The HTTP status message, for example `"OK"`.
+    pub status: String,
+    /// The content type of the body, for example `"application/json"`.
+    pub content_type: String,
+    /// Additional headers, separated by `\r\n`.
+    pub headers: String,
+    /// The body of the reply.
+    pub body: String,
+}
+
+impl RestReply {
+    /// Create a reply from a C reply, freeing the buffers of the C reply.
+    pub(crate) fn from_raw(reply: &mut sys::ecs_http_reply_t) -> Self {
+        unsafe fn c_str(ptr: *const std::ffi::c_char) -> String {
+            if ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        }
+
+        unsafe fn strbuf(buf: &mut sys::ecs_strbuf_t) -> String {
+            let ptr = sys::ecs_strbuf_get(buf);
+            if ptr.is_null() {
+                return String::new();
+            }
+            let value = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+            sys::ecs_os_api.free_.expect("os api is missing")(ptr as *mut std::ffi::c_void);
+            value
+        }
+
+        unsafe {
+            RestReply {
+                code: reply.code,
+                status: c_str(reply.status),
+                content_type: c_str(reply.content_type),
+                headers: strbuf(&mut reply.headers),
+                body: strbuf(&mut reply.body),
+            }
+        }
+    }
+
+    /// Returns whether the status code indicates success (2xx).
+    pub fn is_ok(&self) -> bool {
+        (200..300).contains(&self.code)
+    }
+}
+
+unsafe extern "C" fn rest_server_fini(_world: *mut sys::ecs_world_t, ctx: *mut std::ffi::c_void) {
+    sys::ecs_rest_server_fini(ctx as *mut sys::ecs_http_server_t);
+}
+
+impl World {
+    /// Dispatch a request to the REST endpoints of this world, without opening
+    /// a socket.
+    ///
+    /// The first call creates a REST server for the world, which is destroyed
+    /// together with the world.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method, for example `"GET"` or `"PUT"`.
+    /// * `path` - The request path including query parameters, for example
+    ///   `"/entity/Sun/Earth"` or `"/query?expr=Position"`. Query parameters
+    ///   must be URL encoded.
+    /// * `body` - The request body, if any.
+    ///
+    /// A `400 Bad Request` reply is returned without dispatching the request
+    /// if the method or path contains a `\r` or `\n`, as these would end the
+    /// request line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// world.entity_named("Sun");
+    ///
+    /// let reply = world.rest_request("GET", "/entity/Sun", None);
+    /// assert_eq!(reply.code, 200);
+    /// assert!(reply.body.contains("Sun"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_http_server_http_request`
+    #[doc(alias = "ecs_http_server_http_request")]
+    pub fn rest_request(&self, method: &str, path: &str, body: Option<&str>) -> RestReply {
+        if has_line_break(method) || has_line_break(path) {
+            return RestReply {
+                code: 400,
+                status: "Bad Request".to_string(),
+                content_type: String::new(),
+                headers: String::new(),
+                body: String::new(),
+            };
+        }
+
+        let server = self.rest_server();
+        let body = body.unwrap_or("");
+        let request = compact_str::format_compact!(
+            "{} {} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        );
+
+        let mut reply = sys::ecs_http_reply_t::default();
+        unsafe {
+            sys::ecs_http_server_http_request(
+                server,
+                request.as_ptr() as *const _,
+                request.len() as sys::ecs_size_t,
+                &mut reply,
+            );
+        }
+        RestReply::from_raw(&mut reply)
+    }
+
+    /// Get the REST server used by [`World::rest_request()`], creating it if
+    /// it does not exist yet.
+    fn rest_server(&self) -> *mut sys::ecs_http_server_t {
+        let ctx = self.world_ctx_mut();
+        if ctx.rest_server.is_null() {
+            let server = unsafe { sys::ecs_rest_server_init(self.ptr_mut(), &Default::default()) };
+            ecs_assert!(
+                !server.is_null(),
+                FlecsErrorCode::InternalError,
+                "failed to create REST server"
+            );
+            unsafe {
+                sys::ecs_atfini(self.ptr_mut(), Some(rest_server_fini), server as *mut _);
+            }
+            ctx.rest_server = server;
+        }
+        ctx.rest_server
+    }
+}
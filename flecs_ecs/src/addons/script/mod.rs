@@ -1,12 +1,17 @@
 mod script_builder;
 mod script_entity_view;
+mod script_error;
+mod script_vars;
 mod unmanaged_script;
 
 pub use script_builder::*;
 pub use script_entity_view::*;
+pub use script_error::*;
+pub use script_vars::*;
 pub use unmanaged_script::*;
 
 use flecs_ecs::core::*;
+use flecs_ecs::sys;
 
 /// Script mixin implementation
 impl World {
@@ -35,6 +40,18 @@ impl World {
         ScriptBuilder::new_from(self, entity)
     }
 
+    /// Create a new root variable scope, which can be used to pass values to scripts.
+    ///
+    /// See [`ScriptVars`] for more information.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_init`
+    #[doc(alias = "ecs_script_vars_init")]
+    pub fn script_vars(&self) -> ScriptVars<'_> {
+        ScriptVars::new(self)
+    }
+
     /// Parse script. This parses a script and instantiates the entities in the world.
     /// This operation is the equivalent to doing: [`parse`][flecs_ecs::addons::script::Script::parse], [`eval`][flecs_ecs::addons::script::Script::eval], [`destroy`][flecs_ecs::addons::script::Script::destroy].
     ///
@@ -100,6 +117,126 @@ impl World {
         Script::to_expr(self, value)
     }
 
+    /// Evaluate an expression into a value. This is the inverse of [`to_expr`](World::to_expr).
+    ///
+    /// The value starts out as `T::default()`, so members that are not assigned by
+    /// the expression keep their default value.
+    ///
+    /// # Arguments
+    ///
+    /// * expr - The expression, for example `{x: 10 + 5, y: $speed}`.
+    ///
+    /// * vars - The variables that can be used by the expression (optional).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::script::*;
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// #[meta]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>().meta();
+    ///
+    /// let mut vars = world.script_vars();
+    /// vars.declare("speed", 2.0f32);
+    ///
+    /// let pos: Position = world.eval_expr("{x: 10 + 5, y: $speed}", Some(&vars)).unwrap();
+    /// assert_eq!(pos.x, 15.0);
+    /// assert_eq!(pos.y, 2.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_expr_run`
+    #[doc(alias = "ecs_script_expr_run")]
+    pub fn eval_expr<T: ComponentId + DataComponent + Default>(
+        &self,
+        expr: &str,
+        vars: Option<&ScriptVars>,
+    ) -> Result<T, ScriptError> {
+        let mut result = T::default();
+        unsafe {
+            run_expr(
+                self.ptr_mut(),
+                T::id(self),
+                &mut result as *mut T as *mut std::ffi::c_void,
+                expr,
+                vars,
+            )?;
+        }
+        Ok(result)
+    }
+
+    /// Evaluate interpolated expressions in a string. Supported expression formats are
+    /// `$variable_name` and `{expression}`. The `$`, `{` and `}` characters can be
+    /// escaped with a backslash.
+    ///
+    /// # Arguments
+    ///
+    /// * str - The string to evaluate.
+    ///
+    /// * vars - The variables that can be used by the expressions (optional).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut vars = world.script_vars();
+    /// vars.declare("count", 3i32);
+    ///
+    /// let str = world.interpolate("{$count * 2} apples", Some(&vars)).unwrap();
+    /// assert_eq!(str, "6 apples");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_string_interpolate`
+    #[doc(alias = "ecs_script_string_interpolate")]
+    pub fn interpolate(&self, str: &str, vars: Option<&ScriptVars>) -> Result<String, ScriptError> {
+        let c_str = compact_str::format_compact!("{}\0", str);
+
+        // variable lookups don't accept a missing scope, so use an empty one
+        let empty;
+        let vars = match vars {
+            Some(vars) => vars,
+            None => {
+                empty = ScriptVars::new(self);
+                &empty
+            }
+        };
+
+        let result = unsafe {
+            sys::ecs_script_string_interpolate(
+                self.ptr_mut(),
+                c_str.as_ptr() as *const _,
+                vars.ptr(),
+            )
+        };
+
+        if result.is_null() {
+            return Err(ScriptError::Eval {
+                expr: str.to_string(),
+            });
+        }
+
+        let c_result = unsafe { std::ffi::CStr::from_ptr(result) };
+        let string = c_result.to_string_lossy().into_owned();
+        unsafe {
+            sys::ecs_os_api.free_.expect("os api is missing")(result as *mut std::ffi::c_void);
+        }
+        Ok(string)
+    }
+
     /// Wraps the provided entity id in a [`ScriptEntityView`].
     ///
     /// # Panics
@@ -118,3 +255,42 @@ impl World {
         ScriptEntityView::new_from(self, T::id(self))
     }
 }
+
+/// Evaluate an expression into the value at `ptr`, which must point to a valid
+/// value of type `type_id`.
+pub(crate) unsafe fn run_expr(
+    world: *mut sys::ecs_world_t,
+    type_id: u64,
+    ptr: *mut std::ffi::c_void,
+    expr: &str,
+    vars: Option<&ScriptVars>,
+) -> Result<(), ScriptError> {
+    let c_expr = compact_str::format_compact!("{}\0", expr);
+    let mut value = sys::ecs_value_t {
+        type_: type_id,
+        ptr,
+    };
+    let desc = sys::ecs_script_expr_run_desc_t {
+        vars: vars.map_or(std::ptr::null_mut(), |vars| vars.ptr()),
+        ..Default::default()
+    };
+
+    let end =
+        unsafe { sys::ecs_script_expr_run(world, c_expr.as_ptr() as *const _, &mut value, &desc) };
+
+    if end.is_null() {
+        return Err(ScriptError::Eval {
+            expr: expr.to_string(),
+        });
+    }
+
+    let offset = end as usize - c_expr.as_ptr() as usize;
+    if !expr[offset..].trim().is_empty() {
+        return Err(ScriptError::TrailingInput {
+            expr: expr.to_string(),
+            offset,
+        });
+    }
+
+    Ok(())
+}
@@ -4,6 +4,8 @@ use std::ops::DerefMut;
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
+use super::ScriptVars;
+
 /// [`ScriptEntityView`] is a wrapper around an entity that is associated with a script.
 #[derive(Clone, Copy)]
 pub struct ScriptEntityView<'a> {
@@ -73,6 +75,43 @@ impl<'a> ScriptEntityView<'a> {
         }
     }
 
+    /// Update script with new code, which can access the provided variables with `$name`.
+    ///
+    /// # Arguments
+    ///
+    /// * instance - An template instance (optional).
+    ///
+    /// * code - The script code.
+    ///
+    /// * vars - The variables to make available to the script.
+    ///
+    /// # Returns
+    ///
+    /// True if success, false if failed.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_update`
+    #[doc(alias = "ecs_script_update")]
+    pub fn update_w_vars(
+        &self,
+        world: impl WorldProvider<'a>,
+        instance: Option<impl Into<Entity>>,
+        code: &str,
+        vars: &ScriptVars,
+    ) -> bool {
+        let code = compact_str::format_compact!("{}\0", code);
+        unsafe {
+            sys::ecs_rust_script_update(
+                world.world_ptr_mut(),
+                *self.id,
+                instance.map(|e| *e.into()).unwrap_or(0),
+                code.as_ptr() as *const i8,
+                vars.ptr(),
+            ) == 0
+        }
+    }
+
     /// Convert script AST to string.
     /// This operation converts the script abstract syntax tree to a string, which can be used to debug a script.
     ///
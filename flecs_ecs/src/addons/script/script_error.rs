@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+
+/// Error returned when evaluating a script expression fails.
+///
+/// The details of a failure (such as the location of a syntax error) are
+/// written to the flecs log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    /// The expression could not be parsed or evaluated.
+    Eval {
+        /// The expression that failed.
+        expr: String,
+    },
+    /// The expression was evaluated, but was followed by input that is not
+    /// part of the expression.
+    TrailingInput {
+        /// The expression that failed.
+        expr: String,
+        /// The byte offset of the first character that was not consumed.
+        offset: usize,
+    },
+}
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptError::Eval { expr } => write!(f, "failed to evaluate expression '{}'", expr),
+            ScriptError::TrailingInput { expr, offset } => write!(
+                f,
+                "unexpected input at offset {} in expression '{}'",
+                offset, expr
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
@@ -0,0 +1,234 @@
+use std::ffi::CString;
+
+use flecs_ecs::core::*;
+use flecs_ecs::sys;
+
+/// Variables that can be passed to a script, which allows for parameterizing
+/// scripts from code. Variables are accessed in a script with `$name`.
+///
+/// Variables are organized in scopes. Scopes can be nested with [`push`](ScriptVars::push)
+/// and [`pop`](ScriptVars::pop), which allows variables in different scopes to have the same name.
+/// Variables from parent scopes are shadowed by variables in child scopes with the same name.
+///
+/// Variable storage is allocated from the stack allocator of the world, so variables should be
+/// dropped in the reverse order in which they were created.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::script::*;
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// #[meta]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let world = World::new();
+/// world.component_named::<Position>("Position").meta();
+///
+/// let mut vars = world.script_vars();
+/// vars.declare("x", 10.0f32).declare("y", 20.0f32);
+///
+/// let script = Script::parse(&world, "main", "e { Position: {$x, $y} }").unwrap();
+/// assert!(script.eval_w_vars(&vars));
+///
+/// world.lookup("e").get::<&Position>(|pos| {
+///     assert_eq!(pos.x, 10.0);
+///     assert_eq!(pos.y, 20.0);
+/// });
+/// ```
+///
+/// # See also
+///
+/// * C API: `ecs_script_vars_t`
+#[doc(alias = "ecs_script_vars_t")]
+pub struct ScriptVars<'a> {
+    vars: *mut sys::ecs_script_vars_t,
+    world: WorldRef<'a>,
+    // variables don't own their name, so names are kept alive per scope
+    names: Vec<Vec<CString>>,
+}
+
+impl<'a> Drop for ScriptVars<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            while !(*self.vars).parent.is_null() {
+                self.vars = sys::ecs_script_vars_pop(self.vars);
+            }
+            sys::ecs_script_vars_fini(self.vars);
+        }
+    }
+}
+
+impl<'a> ScriptVars<'a> {
+    /// Create a new root variable scope.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_init`
+    #[doc(alias = "ecs_script_vars_init")]
+    pub fn new(world: impl WorldProvider<'a>) -> Self {
+        let vars = unsafe { sys::ecs_script_vars_init(world.world_ptr_mut()) };
+        ecs_assert!(
+            !vars.is_null(),
+            FlecsErrorCode::InternalError,
+            "failed to create variable scope"
+        );
+        ScriptVars {
+            vars,
+            world: world.world(),
+            names: vec![Vec::new()],
+        }
+    }
+
+    /// Push a new variable scope. Variables declared after this call are
+    /// freed by the matching [`pop`](ScriptVars::pop).
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_push`
+    #[doc(alias = "ecs_script_vars_push")]
+    pub fn push(&mut self) -> &mut Self {
+        self.vars = unsafe { sys::ecs_script_vars_push(self.vars) };
+        self.names.push(Vec::new());
+        self
+    }
+
+    /// Pop the current variable scope. This frees the variables declared in
+    /// the scope. The root scope cannot be popped.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_pop`
+    #[doc(alias = "ecs_script_vars_pop")]
+    pub fn pop(&mut self) -> &mut Self {
+        ecs_assert!(
+            !unsafe { (*self.vars).parent }.is_null(),
+            FlecsErrorCode::InvalidOperation,
+            "cannot pop the root variable scope"
+        );
+        if !unsafe { (*self.vars).parent }.is_null() {
+            self.vars = unsafe { sys::ecs_script_vars_pop(self.vars) };
+            self.names.pop();
+        }
+        self
+    }
+
+    /// Declare a variable in the current scope. The type of the value must be
+    /// registered with reflection data for the variable to be usable in a script.
+    ///
+    /// Declaring a variable with a name that already exists in the current scope
+    /// is not allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The variable name, without the `$` prefix.
+    /// * `value` - The variable value.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_define_id`
+    #[doc(alias = "ecs_script_vars_define_id")]
+    pub fn declare<T: ComponentId + DataComponent>(&mut self, name: &str, value: T) -> &mut Self {
+        let id = T::id(self.world);
+        let c_name = CString::new(name).expect("variable name contains a nul byte");
+        let var = unsafe { sys::ecs_script_vars_define_id(self.vars, c_name.as_ptr(), id) };
+        ecs_assert!(
+            !var.is_null(),
+            FlecsErrorCode::InvalidParameter,
+            "variable '{}' is already declared in this scope",
+            name
+        );
+
+        if !var.is_null() {
+            self.names
+                .last_mut()
+                .expect("variable scope is missing")
+                .push(c_name);
+            unsafe {
+                let ptr = (*var).value.ptr as *mut T;
+                if (*(*var).type_info).hooks.ctor.is_some() {
+                    // storage was constructed, so drop the existing value
+                    *ptr = value;
+                } else {
+                    std::ptr::write(ptr, value);
+                }
+            }
+        }
+        self
+    }
+
+    /// Check if a variable exists in the current scope or one of its parents.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_lookup`
+    #[doc(alias = "ecs_script_vars_lookup")]
+    pub fn has(&self, name: &str) -> bool {
+        !self.lookup(name).is_null()
+    }
+
+    /// Get the value of a variable. The variable is looked up in the current
+    /// scope and its parents.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if the variable does not exist.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_lookup`
+    #[doc(alias = "ecs_script_vars_lookup")]
+    pub fn get<T: ComponentId + DataComponent>(&self, name: &str) -> Option<&T> {
+        self.get_ptr::<T>(name).map(|ptr| unsafe { &*ptr })
+    }
+
+    /// Get a mutable reference to the value of a variable. The variable is
+    /// looked up in the current scope and its parents.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if the variable does not exist.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_lookup`
+    #[doc(alias = "ecs_script_vars_lookup")]
+    pub fn get_mut<T: ComponentId + DataComponent>(&mut self, name: &str) -> Option<&mut T> {
+        self.get_ptr::<T>(name).map(|ptr| unsafe { &mut *ptr })
+    }
+
+    /// Get the raw variable scope.
+    pub(crate) fn ptr(&self) -> *mut sys::ecs_script_vars_t {
+        self.vars
+    }
+
+    fn lookup(&self, name: &str) -> *mut sys::ecs_script_var_t {
+        let name = compact_str::format_compact!("{}\0", name);
+        unsafe { sys::ecs_script_vars_lookup(self.vars, name.as_ptr() as *const _) }
+    }
+
+    fn get_ptr<T: ComponentId + DataComponent>(&self, name: &str) -> Option<*mut T> {
+        let var = self.lookup(name);
+        if var.is_null() {
+            return None;
+        }
+
+        let value = unsafe { (*var).value };
+        let id = T::id(self.world);
+        ecs_assert!(
+            value.type_ == id,
+            FlecsErrorCode::InvalidParameter,
+            "variable '{}' is not of type {}",
+            name,
+            std::any::type_name::<T>()
+        );
+        if value.type_ != id || value.ptr.is_null() {
+            return None;
+        }
+        Some(value.ptr as *mut T)
+    }
+}
@@ -3,6 +3,8 @@ use std::ffi::CStr;
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
+use super::ScriptVars;
+
 /// A Script object is not associated to an entity and will be automatically deleted when it goes out of scope.
 /// For scripts that are associated with an entity, use [`ScriptBuilder`][super::ScriptBuilder] alongside [`ScriptEntityView`][super::ScriptEntityView].
 ///
@@ -84,6 +86,25 @@ impl<'a> Script<'a> {
         unsafe { sys::ecs_script_eval(self.script) == 0 }
     }
 
+    /// Evaluate script with variables. This operation evaluates (runs) a parsed script,
+    /// which can access the provided variables with `$name`.
+    ///
+    /// # Arguments
+    ///
+    /// * vars - The variables to make available to the script.
+    ///
+    /// # Returns
+    ///
+    /// True if success, false if failed.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_eval`
+    #[doc(alias = "ecs_script_eval")]
+    pub fn eval_w_vars(&self, vars: &ScriptVars) -> bool {
+        unsafe { sys::ecs_rust_script_eval(self.script, vars.ptr()) == 0 }
+    }
+
     pub fn destroy(self) {
         // Drop
     }
@@ -193,7 +214,7 @@ impl<'a> Script<'a> {
     ) -> String {
         let id = *id_of_value.into();
         let world = world.world_ptr_mut();
-        let expr = unsafe { sys::ecs_ptr_to_expr(world, id, value) };
+        let expr = unsafe { sys::ecs_rust_ptr_to_expr(world, id, value) };
         let c_str = unsafe { CStr::from_ptr(expr) };
         let str = c_str.to_str().unwrap().to_owned();
         unsafe { sys::ecs_os_api.free_.expect("os api is missing")(expr as *mut std::ffi::c_void) };
@@ -0,0 +1,535 @@
+//! Snapshots store the entities of a world together with their components, so
+//! that the world can later be restored to the state it was in when the
+//! snapshot was taken.
+//!
+//! Component values are stored as copies made with the copy hooks of the
+//! component, so restored values are exactly equal to the stored values.
+//! Components that have a destructor but can't be copied, such as Rust
+//! components that don't implement `Clone`, are stored with their reflection
+//! data instead, and are restored into the value the entity has, or a newly
+//! constructed value. Taking a snapshot of an entity with a component that
+//! can't be copied and has no reflection data returns an error.
+//!
+//! Builtin entities (components, modules, systems, observers, queries and their
+//! children) are not stored in a snapshot and are never deleted by a restore.
+
+use std::alloc::Layout;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{c_void, CStr};
+
+use crate::core::*;
+use crate::sys;
+
+/// A component value stored in a snapshot.
+enum SnapshotValue {
+    /// A copy made with the copy hooks of the component.
+    Copy { data: *mut u8, layout: Layout },
+    /// A value of a component without copy hooks, encoded with its
+    /// reflection data.
+    #[cfg(feature = "flecs_meta")]
+    Encoded(Vec<u8>),
+}
+
+/// A stored entity with its ids and component values.
+struct SnapshotEntity {
+    id: Entity,
+    name: Option<String>,
+    /// The ids of the entity, with the value for components.
+    ids: Vec<(Id, Option<SnapshotValue>)>,
+}
+
+/// A snapshot of the entities in a world, which can be restored with [`World::restore()`].
+///
+/// Snapshots are created with [`World::snapshot()`] or [`World::snapshot_query()`].
+pub struct Snapshot<'a> {
+    world: WorldRef<'a>,
+    entities: Vec<SnapshotEntity>,
+    stored: HashSet<u64>,
+    /// The type info of the stored values, by id. This is a copy, so that the
+    /// values can be destructed when the component no longer exists.
+    types: HashMap<u64, sys::ecs_type_info_t>,
+    filtered: bool,
+}
+
+impl<'a> Snapshot<'a> {
+    /// Returns the number of entities stored in the snapshot.
+    pub fn count(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns true if the snapshot was created from a query.
+    pub fn is_filtered(&self) -> bool {
+        self.filtered
+    }
+
+    /// Returns true if the snapshot stores the entity.
+    pub fn contains(&self, entity: impl Into<Entity>) -> bool {
+        self.stored.contains(&*entity.into())
+    }
+
+    /// Returns an iterator over the entities stored in the snapshot.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.iter().map(|e| e.id)
+    }
+
+    fn new(world: WorldRef<'a>, filtered: bool) -> Self {
+        Snapshot {
+            world,
+            entities: Vec::new(),
+            stored: HashSet::new(),
+            types: HashMap::new(),
+            filtered,
+        }
+    }
+
+    /// Store an entity with its name, ids and component values.
+    fn store(&mut self, entity: Entity) -> Result<(), FlecsError> {
+        let world = self.world.world_ptr();
+        let name = unsafe { sys::ecs_get_name(world, *entity) };
+        let name = if name.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(name) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        };
+
+        // add the entity before its values, so they are dropped if a value can't be stored
+        self.stored.insert(*entity);
+        self.entities.push(SnapshotEntity {
+            id: entity,
+            name,
+            ids: Vec::new(),
+        });
+
+        let type_ = unsafe { sys::ecs_get_type(world, *entity) };
+        if type_.is_null() {
+            return Ok(());
+        }
+        let type_ids =
+            unsafe { std::slice::from_raw_parts((*type_).array, (*type_).count as usize) };
+        for &id in type_ids {
+            let id = Id(id);
+            if !is_snapshot_id(id) {
+                continue;
+            }
+
+            let value = match snapshot_type_info(world, id) {
+                Some(type_info) => {
+                    let type_info = *self.types.entry(*id).or_insert(type_info);
+                    Some(copy_value(world, entity, id, &type_info)?)
+                }
+                None => None,
+            };
+
+            self.entities
+                .last_mut()
+                .expect("entity was added")
+                .ids
+                .push((id, value));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Snapshot<'_> {
+    fn drop(&mut self) {
+        for entity in &mut self.entities {
+            for (id, value) in entity.ids.drain(..) {
+                if let Some(SnapshotValue::Copy { data, layout }) = value {
+                    let type_info = &self.types[&*id];
+                    unsafe {
+                        if let Some(dtor) = type_info.hooks.dtor {
+                            dtor(data as *mut c_void, 1, type_info);
+                        }
+                        std::alloc::dealloc(data, layout);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if an id is managed by a snapshot. Identifiers are restored
+/// separately.
+fn is_snapshot_id(id: Id) -> bool {
+    !(ecs_is_pair(id) && ecs_first(id) == ECS_IDENTIFIER)
+}
+
+/// Returns the type info of an id if it has data that is stored in a snapshot.
+fn snapshot_type_info(world: *const sys::ecs_world_t, id: Id) -> Option<sys::ecs_type_info_t> {
+    let type_info = unsafe { sys::ecs_get_type_info(world, *id) };
+    if type_info.is_null() || unsafe { (*type_info).size } == 0 {
+        None
+    } else {
+        Some(unsafe { *type_info })
+    }
+}
+
+/// Returns true if a value of a type can be copied with `memcpy`, which is the
+/// case for types that don't have lifecycle hooks.
+fn is_trivial(type_info: &sys::ecs_type_info_t) -> bool {
+    let hooks = &type_info.hooks;
+    hooks.dtor.is_none() && hooks.copy.is_none() && hooks.copy_ctor.is_none()
+}
+
+/// Returns the name of an id, for error messages.
+fn id_name(world: *const sys::ecs_world_t, id: Id) -> String {
+    let id_str = unsafe { sys::ecs_id_str(world, *id) };
+    let name = unsafe { CStr::from_ptr(id_str) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { sys::ecs_os_api.free_.expect("os api is missing")(id_str as *mut c_void) };
+    name
+}
+
+/// Copy the value of a component of an entity into a new allocation, or
+/// encode it with its reflection data if it can't be copied.
+fn copy_value(
+    world: *const sys::ecs_world_t,
+    entity: Entity,
+    id: Id,
+    type_info: &sys::ecs_type_info_t,
+) -> Result<SnapshotValue, FlecsError> {
+    let src = unsafe { sys::ecs_get_id(world, *entity, *id) };
+    ecs_assert!(!src.is_null(), FlecsErrorCode::InternalError);
+
+    let copy_ctor = type_info.hooks.copy_ctor;
+    if copy_ctor.is_none() && !is_trivial(type_info) {
+        #[cfg(feature = "flecs_meta")]
+        if crate::addons::meta::has_reflection(world, type_info.component) {
+            let world = world as *mut sys::ecs_world_t;
+            return unsafe { crate::addons::meta::encode_value(world, type_info.component, src) }
+                .map(SnapshotValue::Encoded)
+                .map_err(|err| {
+                    FlecsError::new(
+                        FlecsErrorCode::InvalidOperation,
+                        format!(
+                            "component '{}' can't be stored in a snapshot: {}",
+                            id_name(world, id),
+                            err
+                        ),
+                    )
+                    .with_entity(entity)
+                    .with_id(id)
+                });
+        }
+
+        return Err(FlecsError::new(
+            FlecsErrorCode::InvalidOperation,
+            format!(
+                "component '{}' can't be stored in a snapshot, because it has no copy hook or reflection data (implement Clone or add #[meta] for Rust components)",
+                id_name(world, id)
+            ),
+        )
+        .with_entity(entity)
+        .with_id(id));
+    }
+
+    let layout = Layout::from_size_align(type_info.size as usize, type_info.alignment as usize)
+        .expect("invalid component layout");
+    unsafe {
+        let data = std::alloc::alloc(layout);
+        if data.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        match copy_ctor {
+            Some(copy_ctor) => copy_ctor(data as *mut c_void, src, 1, type_info),
+            None => std::ptr::copy_nonoverlapping(src as *const u8, data, layout.size()),
+        }
+        Ok(SnapshotValue::Copy { data, layout })
+    }
+}
+
+impl World {
+    /// Take a snapshot of all entities in the world.
+    ///
+    /// Restoring the snapshot with [`World::restore()`] deletes entities that
+    /// were created after the snapshot was taken, and restores the ids, names
+    /// and component values of the stored entities.
+    ///
+    /// # Returns
+    ///
+    /// The snapshot, or an error if an entity has a component that can't be
+    /// copied and has no reflection data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity_named("e").set(Position { x: 10.0, y: 20.0 });
+    ///
+    /// let snapshot = world.snapshot().unwrap();
+    ///
+    /// e.set(Position { x: 30.0, y: 40.0 });
+    /// let e2 = world.entity();
+    ///
+    /// world.restore(&snapshot).unwrap();
+    ///
+    /// assert!(!e2.is_alive());
+    /// e.get::<&Position>(|pos| {
+    ///     assert_eq!(pos.x, 10.0);
+    ///     assert_eq!(pos.y, 20.0);
+    /// });
+    /// ```
+    pub fn snapshot(&self) -> Result<Snapshot<'_>, FlecsError> {
+        let mut snapshot = Snapshot::new(self.world(), false);
+        for e in self.alive_entities() {
+            if self.is_snapshot_entity(e) {
+                snapshot.store(e)?;
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Take a snapshot of the entities that match a query.
+    ///
+    /// Restoring a filtered snapshot with [`World::restore()`] restores the
+    /// stored entities, but does not delete entities that are not stored in
+    /// the snapshot.
+    ///
+    /// # Returns
+    ///
+    /// The snapshot, or an error if an entity has a component that can't be
+    /// copied and has no reflection data.
+    pub fn snapshot_query(&self, query: &impl IterOperations) -> Result<Snapshot<'_>, FlecsError> {
+        let mut snapshot = Snapshot::new(self.world(), true);
+        let mut iter = query.retrieve_iter();
+        while query.iter_next(&mut iter) {
+            for i in 0..iter.count as usize {
+                let e = Entity(unsafe { *iter.entities.add(i) });
+                if self.is_snapshot_entity(e) {
+                    if let Err(err) = snapshot.store(e) {
+                        unsafe { sys::ecs_iter_fini(&mut iter) };
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Restore the world to the state stored in a snapshot.
+    ///
+    /// For a snapshot of the whole world this deletes all entities that are
+    /// not stored in the snapshot. Stored entities are recreated with the same
+    /// id if they were deleted, and get the same ids, names and component
+    /// values they had when the snapshot was taken.
+    ///
+    /// # Errors
+    ///
+    /// The world is not changed if one of these errors is returned:
+    ///
+    /// * [`FlecsErrorCode::InvalidParameter`] if the snapshot was taken from a
+    ///   different world.
+    /// * [`FlecsErrorCode::InvalidOperation`] if the world is deferred, or if
+    ///   the id of a deleted entity in the snapshot was recycled for an entity
+    ///   that the restore doesn't delete.
+    ///
+    /// [`FlecsErrorCode::InvalidOperation`] is also returned if a value that
+    /// was stored with its reflection data can't be restored, for example
+    /// because an opaque type rejects it. The world is then partially restored.
+    pub fn restore(&self, snapshot: &Snapshot) -> Result<(), FlecsError> {
+        if snapshot.world.world_ptr() != self.world_ptr() {
+            return Err(FlecsError::new(
+                FlecsErrorCode::InvalidParameter,
+                "snapshot was taken from a different world",
+            ));
+        }
+        if self.is_deferred() {
+            return Err(FlecsError::new(
+                FlecsErrorCode::InvalidOperation,
+                "cannot restore a snapshot while the world is deferred",
+            ));
+        }
+
+        let world = self.ptr_mut();
+        let stored = &snapshot.stored;
+
+        // a deleted entity can only be recreated with the same id if the
+        // entity that recycled its id is deleted by the restore
+        for entity in &snapshot.entities {
+            if unsafe { sys::ecs_is_alive(world, *entity.id) } {
+                continue;
+            }
+            let any = Entity(unsafe { sys::ecs_get_alive(world, *entity.id & 0xFFFF_FFFF) });
+            if any != 0 && (snapshot.filtered || !self.is_snapshot_entity(any)) {
+                return Err(FlecsError::new(
+                    FlecsErrorCode::InvalidOperation,
+                    format!("cannot restore entity {}, its id was recycled", entity.id),
+                )
+                .with_entity(entity.id));
+            }
+        }
+
+        if !snapshot.filtered {
+            self.delete_unstored(stored, None);
+        }
+
+        let existing: HashSet<u64> = self.alive_entities().into_iter().map(|e| *e).collect();
+
+        // make sure all stored entities exist before adding ids that refer to them
+        for entity in &snapshot.entities {
+            if unsafe { sys::ecs_is_alive(world, *entity.id) } {
+                continue;
+            }
+            unsafe { sys::ecs_make_alive(world, *entity.id) };
+        }
+
+        // names are restored after all entities are in their final scope, so
+        // that they can't conflict with the current names of other entities
+        for entity in &snapshot.entities {
+            if unsafe { sys::ecs_is_alive(world, *entity.id) } {
+                unsafe { sys::ecs_set_name(world, *entity.id, std::ptr::null()) };
+            }
+        }
+
+        for entity in &snapshot.entities {
+            if unsafe { sys::ecs_is_alive(world, *entity.id) } {
+                self.restore_entity(entity, &snapshot.types)?;
+            }
+        }
+
+        // adding an instance of a prefab with children creates new children,
+        // which are not part of the snapshot
+        self.delete_unstored(stored, Some(&existing));
+
+        for entity in &snapshot.entities {
+            if let Some(name) = &entity.name {
+                if unsafe { sys::ecs_is_alive(world, *entity.id) } {
+                    let name = compact_str::format_compact!("{}\0", name);
+                    unsafe { sys::ecs_set_name(world, *entity.id, name.as_ptr() as *const _) };
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns all alive entities in the world.
+    fn alive_entities(&self) -> Vec<Entity> {
+        let entities = unsafe { sys::ecs_get_entities(self.world_ptr()) };
+        let ids =
+            unsafe { std::slice::from_raw_parts(entities.ids, entities.alive_count as usize) };
+        ids.iter().map(|&id| Entity(id)).collect()
+    }
+
+    /// Returns true if the entity should be stored in a snapshot, which is
+    /// the case if neither the entity nor one of its parents is builtin.
+    fn is_snapshot_entity(&self, entity: Entity) -> bool {
+        let world = self.ptr_mut();
+        let mut current = *entity;
+        while current != 0 {
+            let table = unsafe { sys::ecs_get_table(world, current) };
+            if !table.is_null()
+                && unsafe { sys::ecs_table_has_flags(table, sys::EcsTableHasBuiltins) }
+            {
+                return false;
+            }
+            current = unsafe { sys::ecs_get_target(world, current, ECS_CHILD_OF, 0) };
+        }
+        true
+    }
+
+    fn restore_entity(
+        &self,
+        entity: &SnapshotEntity,
+        types: &HashMap<u64, sys::ecs_type_info_t>,
+    ) -> Result<(), FlecsError> {
+        let world = self.ptr_mut();
+        let ids: HashSet<u64> = entity.ids.iter().map(|(id, _)| **id).collect();
+
+        // remove ids that the entity did not have when the snapshot was taken
+        let type_ = unsafe { sys::ecs_get_type(world, *entity.id) };
+        if !type_.is_null() {
+            let current: Vec<Id> =
+                unsafe { std::slice::from_raw_parts((*type_).array, (*type_).count as usize) }
+                    .iter()
+                    .map(|&id| Id(id))
+                    .collect();
+            for id in current {
+                if is_snapshot_id(id) && !ids.contains(&*id) {
+                    unsafe { sys::ecs_remove_id(world, *entity.id, *id) };
+                }
+            }
+        }
+
+        for (id, value) in &entity.ids {
+            if !unsafe { sys::ecs_id_is_valid(world, **id) } {
+                continue;
+            }
+
+            let Some(value) = value else {
+                unsafe { sys::ecs_add_id(world, *entity.id, **id) };
+                continue;
+            };
+
+            let type_info = &types[&**id];
+            match value {
+                SnapshotValue::Copy { data, layout } => unsafe {
+                    let mut is_new = false;
+                    let ptr = sys::ecs_emplace_id(world, *entity.id, **id, &mut is_new);
+                    match (is_new, type_info.hooks.copy_ctor, type_info.hooks.copy) {
+                        (true, Some(copy_ctor), _) => {
+                            copy_ctor(ptr, *data as *const c_void, 1, type_info);
+                        }
+                        (false, _, Some(copy)) => copy(ptr, *data as *const c_void, 1, type_info),
+                        _ => std::ptr::copy_nonoverlapping(
+                            *data as *const u8,
+                            ptr as *mut u8,
+                            layout.size(),
+                        ),
+                    }
+                },
+                #[cfg(feature = "flecs_meta")]
+                SnapshotValue::Encoded(data) => unsafe {
+                    let ptr = sys::ecs_ensure_id(world, *entity.id, **id);
+                    crate::addons::meta::decode_value(world, type_info.component, data, ptr)
+                        .map_err(|err| {
+                            FlecsError::new(
+                                FlecsErrorCode::InvalidOperation,
+                                format!(
+                                    "component '{}' can't be restored from a snapshot: {}",
+                                    id_name(world, *id),
+                                    err
+                                ),
+                            )
+                            .with_entity(entity.id)
+                            .with_id(*id)
+                        })?;
+                },
+            }
+            unsafe { sys::ecs_modified_id(world, *entity.id, **id) };
+        }
+        Ok(())
+    }
+
+    /// Delete entities that are not stored in the snapshot. If `existing` is
+    /// provided, only entities that are not in it are deleted.
+    fn delete_unstored(&self, stored: &HashSet<u64>, existing: Option<&HashSet<u64>>) {
+        let world = self.ptr_mut();
+        let delete: Vec<Entity> = self
+            .alive_entities()
+            .into_iter()
+            .filter(|e| !stored.contains(&**e))
+            .filter(|e| existing.map_or(true, |existing| !existing.contains(&**e)))
+            .filter(|&e| self.is_snapshot_entity(e))
+            .collect();
+
+        for e in delete {
+            if unsafe { sys::ecs_is_alive(world, *e) } {
+                unsafe { sys::ecs_delete(world, *e) };
+            }
+        }
+    }
+}
@@ -1,9 +1,18 @@
 //! Periodically tracks statistics for the world and systems.
+//!
+//! The raw statistics components collected by the [`Stats`] module can be read
+//! through typed wrappers such as [`WorldStatistics`], which are returned by
+//! [`World::stats()`] and [`World::stats_history()`].
+
+mod statistics;
+
+pub use statistics::*;
+
 use crate::core::{World, WorldProvider};
 use crate::sys;
 
 #[cfg(feature = "flecs_module")]
-use super::module::Module;
+use crate::addons::module::Module;
 
 /// Component that stores world statistics
 pub type WorldStats = sys::EcsWorldStats;
@@ -20,15 +29,15 @@ pub struct Stats;
 #[cfg(feature = "flecs_module")]
 impl Module for Stats {
     fn module(world: &World) {
-        // TODO UNITS addon
-        /*
-        #ifdef FLECS_UNITS
-        world.import<flecs::units>();
-        #endif
-         */
+        #[cfg(feature = "flecs_units")]
+        world.import::<crate::addons::units::Units>();
 
+        // Import the C module before the module is moved to its Rust path. See
+        // `World::import_c_module`.
+        world.import_c_module(Some(sys::FlecsStatsImport), c"FlecsStats", unsafe {
+            sys::FLECS_IDFlecsStatsID_
+        });
         world.module::<Stats>("flecs::rust::stats");
-        unsafe { sys::FlecsStatsImport(world.ptr_mut()) };
         world.component::<WorldSummary>();
         world.component::<WorldStats>();
         world.component::<SystemStats>();
@@ -0,0 +1,946 @@
+//! Typed access to world, system, query and pipeline statistics.
+//!
+//! Statistics are stored in ring buffers of [`STAT_WINDOW`] samples. Each call
+//! to an `update` function records a new sample, after which the statistics
+//! can be read from fields such as `stats.entities.count`. A [`Gauge`] or
+//! [`Counter`] only holds the samples that were recorded, so aggregates such as
+//! [`Gauge::mean()`] don't include the empty slots of a window that is not
+//! filled yet.
+
+use crate::core::*;
+use crate::sys;
+
+use super::{Stats, SystemStats, WorldStats};
+
+/// The number of samples stored by a statistics ring buffer.
+pub const STAT_WINDOW: usize = sys::ECS_STAT_WINDOW as usize;
+
+#[inline]
+fn t_prev(t: usize) -> usize {
+    (t + STAT_WINDOW - 1) % STAT_WINDOW
+}
+
+/// Returns the slots of a ring buffer from the oldest to the most recent sample.
+#[inline]
+fn window(t: usize) -> impl Iterator<Item = usize> {
+    (1..=STAT_WINDOW).map(move |i| (t + i) % STAT_WINDOW)
+}
+
+/// Returns the slots of the last `count` samples of a ring buffer, from the
+/// oldest to the most recent sample.
+fn recent_slots(t: usize, count: usize) -> Vec<usize> {
+    (0..count.min(STAT_WINDOW))
+        .rev()
+        .map(|i| (t + STAT_WINDOW - i) % STAT_WINDOW)
+        .collect()
+}
+
+/// Returns the slots of a world statistics ring buffer that hold a sample.
+fn world_slots(stats: &sys::ecs_world_stats_t) -> Vec<usize> {
+    // a world always has builtin entities, so a recorded sample never has an
+    // entity count of zero
+    let count = unsafe { &stats.entities.count.gauge };
+    window(stats.t as usize)
+        .filter(|&i| count.max[i] != 0.0)
+        .collect()
+}
+
+/// A metric that stores the value of something at a specific point in time,
+/// such as the number of entities.
+#[derive(Clone, Default)]
+pub struct Gauge {
+    avg: Vec<f32>,
+    min: Vec<f32>,
+    max: Vec<f32>,
+}
+
+impl Gauge {
+    fn new(gauge: &sys::ecs_gauge_t, slots: &[usize]) -> Self {
+        Gauge {
+            avg: slots.iter().map(|&i| gauge.avg[i]).collect(),
+            min: slots.iter().map(|&i| gauge.min[i]).collect(),
+            max: slots.iter().map(|&i| gauge.max[i]).collect(),
+        }
+    }
+
+    /// Returns the number of recorded samples.
+    pub fn sample_count(&self) -> usize {
+        self.avg.len()
+    }
+
+    /// Returns the most recent value, or zero if no sample was recorded.
+    pub fn latest(&self) -> f32 {
+        self.avg.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the smallest value measured for the most recent sample.
+    ///
+    /// This only differs from [`Gauge::latest()`] when the statistics were reduced
+    /// from a larger number of samples.
+    pub fn latest_min(&self) -> f32 {
+        self.min.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the largest value measured for the most recent sample.
+    ///
+    /// This only differs from [`Gauge::latest()`] when the statistics were reduced
+    /// from a larger number of samples.
+    pub fn latest_max(&self) -> f32 {
+        self.max.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the average value of each recorded sample, from oldest to most
+    /// recent.
+    pub fn avg_samples(&self) -> &[f32] {
+        &self.avg
+    }
+
+    /// Returns the minimum value of each recorded sample, from oldest to most
+    /// recent.
+    pub fn min_samples(&self) -> &[f32] {
+        &self.min
+    }
+
+    /// Returns the maximum value of each recorded sample, from oldest to most
+    /// recent.
+    pub fn max_samples(&self) -> &[f32] {
+        &self.max
+    }
+
+    /// Iterate the recorded values, from oldest to most recent.
+    pub fn history(&self) -> impl Iterator<Item = f32> + '_ {
+        self.avg.iter().copied()
+    }
+
+    /// Returns the average value of the recorded samples, or zero if no sample
+    /// was recorded.
+    pub fn mean(&self) -> f32 {
+        if self.avg.is_empty() {
+            return 0.0;
+        }
+        self.avg.iter().sum::<f32>() / self.avg.len() as f32
+    }
+
+    /// Returns the smallest value measured in the recorded samples, or zero if
+    /// no sample was recorded.
+    pub fn min(&self) -> f32 {
+        self.min.iter().copied().reduce(f32::min).unwrap_or(0.0)
+    }
+
+    /// Returns the largest value measured in the recorded samples, or zero if
+    /// no sample was recorded.
+    pub fn max(&self) -> f32 {
+        self.max.iter().copied().reduce(f32::max).unwrap_or(0.0)
+    }
+}
+
+impl std::fmt::Debug for Gauge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Gauge")
+            .field("latest", &self.latest())
+            .field("sample_count", &self.sample_count())
+            .finish()
+    }
+}
+
+/// A metric that stores a monotonically increasing value, such as the number
+/// of frames. The change between two samples is stored as a [`Gauge`].
+#[derive(Clone, Default)]
+pub struct Counter {
+    value: Vec<f64>,
+    rate: Gauge,
+}
+
+impl Counter {
+    fn new(counter: &sys::ecs_counter_t, slots: &[usize]) -> Self {
+        Counter {
+            value: slots.iter().map(|&i| counter.value[i]).collect(),
+            rate: Gauge::new(&counter.rate, slots),
+        }
+    }
+
+    /// Returns the most recent (total) value of the counter, or zero if no
+    /// sample was recorded.
+    pub fn latest(&self) -> f64 {
+        self.value.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns how much the counter increased in the most recent sample.
+    pub fn delta(&self) -> f32 {
+        self.rate.latest()
+    }
+
+    /// Returns the increase of the counter per sample.
+    pub fn rate(&self) -> &Gauge {
+        &self.rate
+    }
+
+    /// Returns the counter value of each recorded sample, from oldest to most
+    /// recent.
+    pub fn value_samples(&self) -> &[f64] {
+        &self.value
+    }
+
+    /// Iterate the recorded counter values, from oldest to most recent.
+    pub fn history(&self) -> impl Iterator<Item = f64> + '_ {
+        self.value.iter().copied()
+    }
+
+    /// Returns the total increase of the counter over the recorded samples.
+    pub fn sum(&self) -> f64 {
+        self.rate.avg.iter().map(|&v| v as f64).sum()
+    }
+}
+
+impl std::fmt::Debug for Counter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Counter")
+            .field("latest", &self.latest())
+            .field("delta", &self.delta())
+            .finish()
+    }
+}
+
+trait FromMetric {
+    fn from_metric(metric: &sys::ecs_metric_t, slots: &[usize]) -> Self;
+}
+
+impl FromMetric for Gauge {
+    fn from_metric(metric: &sys::ecs_metric_t, slots: &[usize]) -> Self {
+        Gauge::new(unsafe { &metric.gauge }, slots)
+    }
+}
+
+impl FromMetric for Counter {
+    fn from_metric(metric: &sys::ecs_metric_t, slots: &[usize]) -> Self {
+        Counter::new(unsafe { &metric.counter }, slots)
+    }
+}
+
+/// Declares a group of world statistics that maps to a C stats struct.
+macro_rules! stats_group {
+    ($(#[$attr:meta])* $name:ident: $c_type:ty {
+        $($(#[$field_attr:meta])* $field:ident: $kind:ident,)*
+    }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $name {
+            $($(#[$field_attr])* pub $field: $kind,)*
+        }
+
+        impl $name {
+            fn new(stats: &$c_type, slots: &[usize]) -> Self {
+                $name {
+                    $($field: <$kind as FromMetric>::from_metric(&stats.$field, slots),)*
+                }
+            }
+        }
+    };
+}
+
+stats_group! {
+    /// Entity statistics.
+    EntityStatistics: sys::ecs_world_stats_t__bindgen_ty_1 {
+        /// Number of entities.
+        count: Gauge,
+        /// Number of not alive (recyclable) entity ids.
+        not_alive_count: Gauge,
+    }
+}
+
+stats_group! {
+    /// Component and id statistics.
+    ComponentStatistics: sys::ecs_world_stats_t__bindgen_ty_2 {
+        /// Number of tag ids (ids without data).
+        tag_count: Gauge,
+        /// Number of component ids (ids with data).
+        component_count: Gauge,
+        /// Number of pair ids.
+        pair_count: Gauge,
+        /// Number of registered types.
+        type_count: Gauge,
+        /// Number of times an id was created.
+        create_count: Counter,
+        /// Number of times an id was deleted.
+        delete_count: Counter,
+    }
+}
+
+stats_group! {
+    /// Table statistics.
+    TableStatistics: sys::ecs_world_stats_t__bindgen_ty_3 {
+        /// Number of tables.
+        count: Gauge,
+        /// Number of empty tables.
+        empty_count: Gauge,
+        /// Number of times a table was created.
+        create_count: Counter,
+        /// Number of times a table was deleted.
+        delete_count: Counter,
+    }
+}
+
+stats_group! {
+    /// Query, observer and system counts.
+    QueryCountStatistics: sys::ecs_world_stats_t__bindgen_ty_4 {
+        /// Number of queries.
+        query_count: Gauge,
+        /// Number of observers.
+        observer_count: Gauge,
+        /// Number of systems.
+        system_count: Gauge,
+    }
+}
+
+stats_group! {
+    /// Deferred command statistics.
+    CommandStatistics: sys::ecs_world_stats_t__bindgen_ty_5 {
+        /// Number of add commands.
+        add_count: Counter,
+        /// Number of remove commands.
+        remove_count: Counter,
+        /// Number of delete commands.
+        delete_count: Counter,
+        /// Number of clear commands.
+        clear_count: Counter,
+        /// Number of set commands.
+        set_count: Counter,
+        /// Number of ensure commands.
+        ensure_count: Counter,
+        /// Number of modified commands.
+        modified_count: Counter,
+        /// Number of other commands.
+        other_count: Counter,
+        /// Number of commands that were discarded.
+        discard_count: Counter,
+        /// Number of entities for which commands were batched.
+        batched_entity_count: Counter,
+        /// Number of commands that were batched.
+        batched_count: Counter,
+    }
+}
+
+stats_group! {
+    /// Frame statistics.
+    FrameStatistics: sys::ecs_world_stats_t__bindgen_ty_6 {
+        /// Number of frames processed.
+        frame_count: Counter,
+        /// Number of merges executed.
+        merge_count: Counter,
+        /// Number of query rematches.
+        rematch_count: Counter,
+        /// Number of pipeline rebuilds.
+        pipeline_build_count: Counter,
+        /// Number of systems ran.
+        systems_ran: Counter,
+        /// Number of observers ran.
+        observers_ran: Counter,
+        /// Number of events emitted.
+        event_emit_count: Counter,
+    }
+}
+
+stats_group! {
+    /// Timing statistics, in seconds.
+    PerformanceStatistics: sys::ecs_world_stats_t__bindgen_ty_7 {
+        /// Actual time passed since simulation start.
+        world_time_raw: Counter,
+        /// Simulation time passed since start, including time scale.
+        world_time: Counter,
+        /// Time spent processing a frame.
+        frame_time: Counter,
+        /// Time spent on running systems.
+        system_time: Counter,
+        /// Time spent on notifying observers.
+        emit_time: Counter,
+        /// Time spent on merging commands.
+        merge_time: Counter,
+        /// Time spent on rematching queries.
+        rematch_time: Counter,
+        /// Frames per second.
+        fps: Gauge,
+        /// Delta time.
+        delta_time: Gauge,
+    }
+}
+
+stats_group! {
+    /// Memory allocation statistics.
+    MemoryStatistics: sys::ecs_world_stats_t__bindgen_ty_8 {
+        /// Number of allocations.
+        alloc_count: Counter,
+        /// Number of reallocations.
+        realloc_count: Counter,
+        /// Number of frees.
+        free_count: Counter,
+        /// Number of allocations that have not been freed.
+        outstanding_alloc_count: Gauge,
+        /// Number of block allocations.
+        block_alloc_count: Counter,
+        /// Number of block frees.
+        block_free_count: Counter,
+        /// Number of block allocations that have not been freed.
+        block_outstanding_alloc_count: Gauge,
+        /// Number of stack allocations.
+        stack_alloc_count: Counter,
+        /// Number of stack frees.
+        stack_free_count: Counter,
+        /// Number of stack allocations that have not been freed.
+        stack_outstanding_alloc_count: Gauge,
+    }
+}
+
+stats_group! {
+    /// HTTP server statistics.
+    HttpStatistics: sys::ecs_world_stats_t__bindgen_ty_9 {
+        /// Number of requests received.
+        request_received_count: Counter,
+        /// Number of invalid requests.
+        request_invalid_count: Counter,
+        /// Number of requests handled successfully.
+        request_handled_ok_count: Counter,
+        /// Number of requests handled with an error.
+        request_handled_error_count: Counter,
+        /// Number of requests that were not handled.
+        request_not_handled_count: Counter,
+        /// Number of preflight requests.
+        request_preflight_count: Counter,
+        /// Number of successful replies sent.
+        send_ok_count: Counter,
+        /// Number of replies that failed to send.
+        send_error_count: Counter,
+        /// Number of requests rejected because the server was busy.
+        busy_count: Counter,
+    }
+}
+
+/// Statistics collected for a world.
+///
+/// Obtained from [`World::stats()`] or [`World::stats_history()`], or created
+/// empty and filled with [`WorldStatistics::update()`].
+#[derive(Clone)]
+pub struct WorldStatistics {
+    /// Entity statistics.
+    pub entities: EntityStatistics,
+    /// Component and id statistics.
+    pub components: ComponentStatistics,
+    /// Table statistics.
+    pub tables: TableStatistics,
+    /// Query, observer and system counts.
+    pub queries: QueryCountStatistics,
+    /// Deferred command statistics.
+    pub commands: CommandStatistics,
+    /// Frame statistics.
+    pub frame: FrameStatistics,
+    /// Timing statistics.
+    pub performance: PerformanceStatistics,
+    /// Memory allocation statistics.
+    pub memory: MemoryStatistics,
+    /// HTTP server statistics.
+    pub http: HttpStatistics,
+    stats: Box<sys::ecs_world_stats_t>,
+}
+
+impl Default for WorldStatistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorldStatistics {
+    /// Create empty world statistics.
+    pub fn new() -> Self {
+        Self::from_raw(unsafe { std::mem::zeroed() })
+    }
+
+    fn from_raw(stats: sys::ecs_world_stats_t) -> Self {
+        let mut result = WorldStatistics {
+            entities: Default::default(),
+            components: Default::default(),
+            tables: Default::default(),
+            queries: Default::default(),
+            commands: Default::default(),
+            frame: Default::default(),
+            performance: Default::default(),
+            memory: Default::default(),
+            http: Default::default(),
+            stats: Box::new(stats),
+        };
+        result.refresh();
+        result
+    }
+
+    fn refresh(&mut self) {
+        let stats = &*self.stats;
+        let slots = world_slots(stats);
+        self.entities = EntityStatistics::new(&stats.entities, &slots);
+        self.components = ComponentStatistics::new(&stats.components, &slots);
+        self.tables = TableStatistics::new(&stats.tables, &slots);
+        self.queries = QueryCountStatistics::new(&stats.queries, &slots);
+        self.commands = CommandStatistics::new(&stats.commands, &slots);
+        self.frame = FrameStatistics::new(&stats.frame, &slots);
+        self.performance = PerformanceStatistics::new(&stats.performance, &slots);
+        self.memory = MemoryStatistics::new(&stats.memory, &slots);
+        self.http = HttpStatistics::new(&stats.http, &slots);
+    }
+
+    /// Record a new sample of the world statistics.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_world_stats_get`
+    pub fn update<'a>(&mut self, world: impl WorldProvider<'a>) {
+        unsafe { sys::ecs_world_stats_get(world.world_ptr(), &mut *self.stats) };
+        self.refresh();
+    }
+
+    /// Reduce all samples of `src` into a single new sample of `self`.
+    ///
+    /// Gauges store the average, minimum and maximum of the source window, and
+    /// counters store the most recent source value.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_world_stats_reduce`
+    pub fn reduce(&mut self, src: &WorldStatistics) {
+        unsafe { sys::ecs_world_stats_reduce(&mut *self.stats, &*src.stats) };
+        self.refresh();
+    }
+
+    /// Returns the underlying C statistics.
+    pub fn raw(&self) -> &sys::ecs_world_stats_t {
+        &self.stats
+    }
+}
+
+/// Statistics collected for a query.
+///
+/// Obtained from [`Query::stats()`], or created empty and filled with
+/// [`QueryStatistics::update()`].
+#[derive(Clone)]
+pub struct QueryStatistics {
+    /// Number of query results.
+    pub result_count: Gauge,
+    /// Number of tables matched by the query.
+    pub matched_table_count: Gauge,
+    /// Number of entities matched by the query.
+    pub matched_entity_count: Gauge,
+    stats: sys::ecs_query_stats_t,
+    samples: usize,
+}
+
+impl Default for QueryStatistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QueryStatistics {
+    /// Create empty query statistics.
+    pub fn new() -> Self {
+        QueryStatistics {
+            result_count: Gauge::default(),
+            matched_table_count: Gauge::default(),
+            matched_entity_count: Gauge::default(),
+            stats: unsafe { std::mem::zeroed() },
+            samples: 0,
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.samples = (self.samples + 1).min(STAT_WINDOW);
+        let slots = recent_slots(self.stats.t as usize, self.samples);
+        self.result_count = Gauge::from_metric(&self.stats.result_count, &slots);
+        self.matched_table_count = Gauge::from_metric(&self.stats.matched_table_count, &slots);
+        self.matched_entity_count = Gauge::from_metric(&self.stats.matched_entity_count, &slots);
+    }
+
+    /// Record a new sample of the query statistics.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_query_stats_get`
+    pub fn update<T: QueryTuple>(&mut self, query: &Query<T>) {
+        unsafe {
+            sys::ecs_query_stats_get(query.world_ptr(), query.query_ptr(), &mut self.stats);
+        }
+        self.refresh();
+    }
+
+    /// Reduce all samples of `src` into a single new sample of `self`.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_query_cache_stats_reduce`
+    pub fn reduce(&mut self, src: &QueryStatistics) {
+        unsafe { sys::ecs_query_cache_stats_reduce(&mut self.stats, &src.stats) };
+        self.refresh();
+    }
+
+    /// Returns the underlying C statistics.
+    pub fn raw(&self) -> &sys::ecs_query_stats_t {
+        &self.stats
+    }
+}
+
+/// Statistics collected for a system.
+///
+/// Obtained from [`World::system_stats()`] or [`World::system_stats_history()`],
+/// or created empty and filled with [`SystemStatistics::update()`].
+#[derive(Clone)]
+pub struct SystemStatistics {
+    /// Time spent running the system, in seconds.
+    pub time_spent: Counter,
+    /// Whether the system is a task, which is a system without a query that
+    /// matches entities.
+    pub task: bool,
+    /// Number of results matched by the system query.
+    pub result_count: Gauge,
+    /// Number of tables matched by the system query.
+    pub matched_table_count: Gauge,
+    /// Number of entities matched by the system query.
+    pub matched_entity_count: Gauge,
+    stats: sys::ecs_system_stats_t,
+    samples: usize,
+}
+
+impl Default for SystemStatistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemStatistics {
+    /// Create empty system statistics.
+    pub fn new() -> Self {
+        SystemStatistics {
+            time_spent: Counter::default(),
+            task: false,
+            result_count: Gauge::default(),
+            matched_table_count: Gauge::default(),
+            matched_entity_count: Gauge::default(),
+            stats: unsafe { std::mem::zeroed() },
+            samples: 0,
+        }
+    }
+
+    fn refresh(&mut self, slots: &[usize]) {
+        let query = &self.stats.query;
+        self.time_spent = Counter::from_metric(&self.stats.time_spent, slots);
+        self.task = self.stats.task;
+        self.result_count = Gauge::from_metric(&query.result_count, slots);
+        self.matched_table_count = Gauge::from_metric(&query.matched_table_count, slots);
+        self.matched_entity_count = Gauge::from_metric(&query.matched_entity_count, slots);
+    }
+
+    fn add_sample(&mut self) {
+        self.samples = (self.samples + 1).min(STAT_WINDOW);
+        let slots = recent_slots(self.stats.query.t as usize, self.samples);
+        self.refresh(&slots);
+    }
+
+    /// Record a new sample of the system statistics.
+    ///
+    /// # Returns
+    ///
+    /// False if the entity is not a system, in which case no sample is recorded.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_system_stats_get`
+    pub fn update<'a>(&mut self, world: impl WorldProvider<'a>, system: impl Into<Entity>) -> bool {
+        let recorded = unsafe {
+            sys::ecs_system_stats_get(world.world_ptr(), *system.into(), &mut self.stats)
+        };
+        if recorded {
+            self.add_sample();
+        }
+        recorded
+    }
+
+    /// Reduce all samples of `src` into a single new sample of `self`.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_system_stats_reduce`
+    pub fn reduce(&mut self, src: &SystemStatistics) {
+        unsafe { sys::ecs_system_stats_reduce(&mut self.stats, &src.stats) };
+        self.add_sample();
+    }
+
+    /// Returns the underlying C statistics.
+    pub fn raw(&self) -> &sys::ecs_system_stats_t {
+        &self.stats
+    }
+}
+
+/// Statistics for a synchronization point in a pipeline, where commands of the
+/// preceding systems are merged.
+#[derive(Debug, Clone)]
+pub struct SyncPointStatistics {
+    /// Time spent merging commands, in seconds.
+    pub time_spent: Counter,
+    /// Number of commands enqueued by the preceding systems.
+    pub commands_enqueued: Counter,
+    /// Number of systems that run before this sync point.
+    pub system_count: i32,
+    /// Whether the preceding systems are multi threaded.
+    pub multi_threaded: bool,
+    /// Whether the preceding systems run in immediate mode.
+    pub immediate: bool,
+}
+
+/// Statistics collected for a pipeline.
+///
+/// Obtained from [`World::pipeline_stats()`], or created empty and filled with
+/// [`PipelineStatistics::update()`].
+pub struct PipelineStatistics {
+    /// The active systems of the pipeline, in the order they run.
+    pub systems: Vec<Entity>,
+    /// The synchronization points of the pipeline, in the order they run.
+    pub sync_points: Vec<SyncPointStatistics>,
+    stats: sys::ecs_pipeline_stats_t,
+    samples: usize,
+}
+
+impl Default for PipelineStatistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for PipelineStatistics {
+    fn drop(&mut self) {
+        unsafe { sys::ecs_pipeline_stats_fini(&mut self.stats) };
+    }
+}
+
+impl PipelineStatistics {
+    /// Create empty pipeline statistics.
+    pub fn new() -> Self {
+        PipelineStatistics {
+            systems: Vec::new(),
+            sync_points: Vec::new(),
+            stats: unsafe { std::mem::zeroed() },
+            samples: 0,
+        }
+    }
+
+    /// Record a new sample of the pipeline statistics.
+    ///
+    /// # Returns
+    ///
+    /// False if the entity is not a pipeline or the pipeline has no systems, in
+    /// which case no sample is recorded.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_pipeline_stats_get`
+    pub fn update<'a>(
+        &mut self,
+        world: impl WorldProvider<'a>,
+        pipeline: impl Into<Entity>,
+    ) -> bool {
+        let recorded = unsafe {
+            sys::ecs_pipeline_stats_get(world.world_ptr_mut(), *pipeline.into(), &mut self.stats)
+        };
+        if recorded {
+            self.samples = (self.samples + 1).min(STAT_WINDOW);
+            self.refresh();
+        }
+        recorded
+    }
+
+    fn refresh(&mut self) {
+        // merge points are stored as 0 in the list of systems
+        self.systems = vec_slice::<sys::ecs_entity_t>(&self.stats.systems)
+            .iter()
+            .filter(|&&id| id != 0)
+            .map(|&id| Entity::new(id))
+            .collect();
+
+        // the pipeline advances its sample index after recording a sample
+        let slots = recent_slots(t_prev(self.stats.t as usize), self.samples);
+        self.sync_points = vec_slice::<sys::ecs_sync_stats_t>(&self.stats.sync_points)
+            .iter()
+            .map(|sync| SyncPointStatistics {
+                time_spent: Counter::from_metric(&sync.time_spent, &slots),
+                commands_enqueued: Counter::from_metric(&sync.commands_enqueued, &slots),
+                system_count: sync.system_count,
+                multi_threaded: sync.multi_threaded,
+                immediate: sync.immediate,
+            })
+            .collect();
+    }
+}
+
+fn vec_slice<T>(vec: &sys::ecs_vec_t) -> &[T] {
+    let count = unsafe { sys::ecs_vec_count(vec) } as usize;
+    if count == 0 {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(sys::ecs_vec_first(vec) as *const T, count) }
+}
+
+/// The period over which the [`Stats`] module aggregates statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPeriod {
+    /// Each sample covers one second, the window covers one minute.
+    OneSecond,
+    /// Each sample covers one minute, the window covers one hour.
+    OneMinute,
+    /// Each sample covers one hour, the window covers two and a half days.
+    OneHour,
+    /// Each sample covers one day, the window covers two months.
+    OneDay,
+    /// Each sample covers one week, the window covers over a year.
+    OneWeek,
+}
+
+impl StatsPeriod {
+    /// Returns the entity that represents this period.
+    ///
+    /// The stats module must be imported for the returned entity to be valid.
+    pub fn id(self) -> Entity {
+        Entity::new(unsafe {
+            match self {
+                StatsPeriod::OneSecond => sys::EcsPeriod1s,
+                StatsPeriod::OneMinute => sys::EcsPeriod1m,
+                StatsPeriod::OneHour => sys::EcsPeriod1h,
+                StatsPeriod::OneDay => sys::EcsPeriod1d,
+                StatsPeriod::OneWeek => sys::EcsPeriod1w,
+            }
+        })
+    }
+}
+
+impl From<StatsPeriod> for Entity {
+    #[inline]
+    fn from(period: StatsPeriod) -> Self {
+        period.id()
+    }
+}
+
+/// Statistics mixin implementation
+impl World {
+    /// Collect a single sample of the world statistics.
+    ///
+    /// Counters in the returned statistics have no previous sample, so their
+    /// [`Counter::delta()`] equals their total value. To track statistics over
+    /// time, keep a [`WorldStatistics`] around and call
+    /// [`WorldStatistics::update()`] periodically, or use
+    /// [`World::stats_history()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// let world = World::new();
+    /// world.entity();
+    ///
+    /// let stats = world.stats();
+    /// assert!(stats.entities.count.latest() > 0.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_world_stats_get`
+    pub fn stats(&self) -> WorldStatistics {
+        let mut stats = WorldStatistics::new();
+        stats.update(self);
+        stats
+    }
+
+    /// Returns the world statistics that the [`Stats`] module collected for a period.
+    ///
+    /// This imports the [`Stats`] module if it has not been imported yet.
+    /// Statistics are collected while the world is progressed, so this returns
+    /// `None` until the first frame has run.
+    pub fn stats_history(&self, period: StatsPeriod) -> Option<WorldStatistics> {
+        self.import::<Stats>();
+        let id = ecs_pair(*self.component_id::<WorldStats>(), *period.id());
+        let ptr = unsafe { sys::ecs_get_id(self.world_ptr(), ECS_WORLD, id) } as *const WorldStats;
+        if ptr.is_null() {
+            return None;
+        }
+        Some(WorldStatistics::from_raw(unsafe { (*ptr).stats }))
+    }
+
+    /// Collect a single sample of the statistics of a system.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the entity is not a system.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_system_stats_get`
+    pub fn system_stats(&self, system: impl Into<Entity>) -> Option<SystemStatistics> {
+        let mut stats = SystemStatistics::new();
+        stats.update(self, system).then_some(stats)
+    }
+
+    /// Returns the statistics that the [`Stats`] module collected for a system
+    /// over a period.
+    ///
+    /// This imports the [`Stats`] module if it has not been imported yet.
+    /// Statistics are collected while the world is progressed, so this returns
+    /// `None` until the first frame has run. Samples that were recorded before
+    /// the system existed are zero.
+    pub fn system_stats_history(
+        &self,
+        system: impl Into<Entity>,
+        period: StatsPeriod,
+    ) -> Option<SystemStatistics> {
+        // the module records samples of systems at the same time as samples of
+        // the world, so the world statistics tell which slots hold a sample
+        let slots = world_slots(self.stats_history(period)?.raw());
+        let id = ecs_pair(*self.component_id::<SystemStats>(), *period.id());
+        let ptr = unsafe { sys::ecs_get_id(self.world_ptr(), ECS_WORLD, id) } as *const SystemStats;
+        if ptr.is_null() {
+            return None;
+        }
+        let value = unsafe { sys::ecs_map_get(&(*ptr).stats, *system.into()) };
+        if value.is_null() {
+            return None;
+        }
+        let stats = unsafe { *value } as *const sys::ecs_system_stats_t;
+        if stats.is_null() {
+            return None;
+        }
+        let mut result = SystemStatistics {
+            stats: unsafe { *stats },
+            samples: slots.len(),
+            ..SystemStatistics::new()
+        };
+        result.refresh(&slots);
+        Some(result)
+    }
+
+    /// Collect a single sample of the statistics of a pipeline.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the entity is not a pipeline or the pipeline has no systems.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_pipeline_stats_get`
+    pub fn pipeline_stats(&self, pipeline: impl Into<Entity>) -> Option<PipelineStatistics> {
+        let mut stats = PipelineStatistics::new();
+        stats.update(self, pipeline).then_some(stats)
+    }
+}
+
+impl<T: QueryTuple> Query<T> {
+    /// Collect a single sample of the statistics of this query.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_query_stats_get`
+    pub fn stats(&self) -> QueryStatistics {
+        let mut stats = QueryStatistics::new();
+        stats.update(self);
+        stats
+    }
+}
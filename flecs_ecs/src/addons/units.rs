@@ -0,0 +1,566 @@
+//! Builtin units and quantities that can be used to annotate component members.
+//!
+//! The units addon defines a set of SI (and some non-SI) units, grouped by the
+//! quantity they measure. Units are entities, which are exposed here as Rust
+//! marker types so that they can be used wherever a component type can be used
+//! to refer to an entity, such as [`Component::member_unit_type()`]:
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//! use flecs_ecs::addons::units::*;
+//!
+//! #[derive(Component)]
+//! struct Speed {
+//!     value: f32,
+//! }
+//!
+//! let world = World::new();
+//! world.import::<Units>();
+//!
+//! world
+//!     .component::<Speed>()
+//!     .member_unit_type::<f32, speed::MetersPerSecond>(("value", Count(1), 0));
+//! ```
+//!
+//! Members can also be annotated by the `Component` derive:
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//! use flecs_ecs::addons::units::*;
+//!
+//! #[derive(Component)]
+//! #[meta]
+//! struct Distance {
+//!     #[meta(unit = length::KiloMeters)]
+//!     value: f32,
+//! }
+//! ```
+//!
+//! The id of a unit marker type is looked up in the world by the path of the
+//! unit entity, which imports the [`Units`] module if it has not been imported
+//! yet.
+//!
+//! # See also
+//!
+//! * C++ API: `flecs::units`
+//!
+//! [`Component::member_unit_type()`]: crate::core::Component::member_unit_type
+
+use std::ffi::CStr;
+
+use crate::core::*;
+use crate::sys;
+
+#[cfg(feature = "flecs_module")]
+use super::module::Module;
+
+/// Module that imports the builtin units and quantities.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Units;
+
+#[cfg(feature = "flecs_module")]
+impl Module for Units {
+    fn module(world: &World) {
+        world.module::<Units>("flecs::rust::units");
+        unsafe { sys::FlecsUnitsImport(world.ptr_mut()) };
+    }
+}
+
+/// Returns the id of a builtin unit entity, caching it for the marker type `T`.
+fn unit_id<'a, T: ComponentId>(world: impl WorldProvider<'a>, path: &CStr) -> sys::ecs_entity_t {
+    let world = world.world();
+    let index = T::index() as usize;
+    let components_array = world.components_array();
+    if let Some(&id) = components_array.get(index) {
+        if id != 0 {
+            return id;
+        }
+    }
+
+    #[cfg(feature = "flecs_module")]
+    world.import::<Units>();
+
+    let id = unsafe {
+        sys::ecs_lookup_path_w_sep(
+            world.world_ptr(),
+            0,
+            path.as_ptr(),
+            c".".as_ptr(),
+            std::ptr::null(),
+            false,
+        )
+    };
+
+    ecs_assert!(
+        id != 0,
+        FlecsErrorCode::InvalidOperation,
+        "unit {:?} not found, import the Units module",
+        path
+    );
+
+    let components_array = world.components_array();
+    let len = components_array.len();
+    if len <= index {
+        components_array.resize(index + 1, 0);
+    }
+    components_array[index] = id;
+    #[cfg(feature = "flecs_meta")]
+    {
+        world
+            .components_map()
+            .insert(std::any::TypeId::of::<T>(), id);
+    }
+    id
+}
+
+/// Declares marker types for builtin unit entities, identified by their path.
+macro_rules! unit_types {
+    ($($(#[$attr:meta])* $name:ident => $path:literal;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct $name;
+
+            impl $crate::core::TagComponent for $name {}
+
+            impl $crate::core::ComponentType<$crate::core::Struct> for $name {}
+
+            impl $crate::core::component_registration::registration_traits::ComponentInfo
+                for $name
+            {
+                const IS_GENERIC: bool = false;
+                const IS_ENUM: bool = false;
+                const IS_TAG: bool = true;
+                type TagType =
+                    $crate::core::component_registration::registration_traits::FlecsFirstIsATag;
+                const IMPLS_CLONE: bool = true;
+                const IMPLS_DEFAULT: bool = true;
+                const IS_REF: bool = false;
+                const IS_MUT: bool = false;
+            }
+
+            impl $crate::core::component_registration::registration_traits::ComponentId
+                for $name
+            {
+                type UnderlyingType = $name;
+                type UnderlyingEnumType = $crate::core::component_registration::NoneEnum;
+
+                #[inline(always)]
+                fn index() -> u32 {
+                    static INDEX: std::sync::atomic::AtomicU32 =
+                        std::sync::atomic::AtomicU32::new(u32::MAX);
+                    Self::get_or_init_index(&INDEX)
+                }
+
+                fn __register_or_get_id<'a, const MANUAL_REGISTRATION_CHECK: bool>(
+                    world: impl $crate::core::WorldProvider<'a>,
+                ) -> $crate::sys::ecs_entity_t {
+                    $crate::addons::units::unit_id::<Self>(world, $path)
+                }
+
+                fn __register_or_get_id_named<'a, const MANUAL_REGISTRATION_CHECK: bool>(
+                    world: impl $crate::core::WorldProvider<'a>,
+                    _name: &str,
+                ) -> $crate::sys::ecs_entity_t {
+                    $crate::addons::units::unit_id::<Self>(world, $path)
+                }
+            }
+        )*
+    };
+}
+
+unit_types! {
+    /// Scope that contains the unit prefixes.
+    Prefixes => c"flecs.units.prefixes";
+
+    /// Prefix for 10^-24.
+    Yocto => c"flecs.units.prefixes.Yocto";
+    /// Prefix for 10^-21.
+    Zepto => c"flecs.units.prefixes.Zepto";
+    /// Prefix for 10^-18.
+    Atto => c"flecs.units.prefixes.Atto";
+    /// Prefix for 10^-15.
+    Femto => c"flecs.units.prefixes.Femto";
+    /// Prefix for 10^-12.
+    Pico => c"flecs.units.prefixes.Pico";
+    /// Prefix for 10^-9.
+    Nano => c"flecs.units.prefixes.Nano";
+    /// Prefix for 10^-6.
+    Micro => c"flecs.units.prefixes.Micro";
+    /// Prefix for 10^-3.
+    Milli => c"flecs.units.prefixes.Milli";
+    /// Prefix for 10^-2.
+    Centi => c"flecs.units.prefixes.Centi";
+    /// Prefix for 10^-1.
+    Deci => c"flecs.units.prefixes.Deci";
+    /// Prefix for 10^1.
+    Deca => c"flecs.units.prefixes.Deca";
+    /// Prefix for 10^2.
+    Hecto => c"flecs.units.prefixes.Hecto";
+    /// Prefix for 10^3.
+    Kilo => c"flecs.units.prefixes.Kilo";
+    /// Prefix for 10^6.
+    Mega => c"flecs.units.prefixes.Mega";
+    /// Prefix for 10^9.
+    Giga => c"flecs.units.prefixes.Giga";
+    /// Prefix for 10^12.
+    Tera => c"flecs.units.prefixes.Tera";
+    /// Prefix for 10^15.
+    Peta => c"flecs.units.prefixes.Peta";
+    /// Prefix for 10^18.
+    Exa => c"flecs.units.prefixes.Exa";
+    /// Prefix for 10^21.
+    Zetta => c"flecs.units.prefixes.Zetta";
+    /// Prefix for 10^24.
+    Yotta => c"flecs.units.prefixes.Yotta";
+    /// Prefix for 2^10.
+    Kibi => c"flecs.units.prefixes.Kibi";
+    /// Prefix for 2^20.
+    Mebi => c"flecs.units.prefixes.Mebi";
+    /// Prefix for 2^30.
+    Gibi => c"flecs.units.prefixes.Gibi";
+    /// Prefix for 2^40.
+    Tebi => c"flecs.units.prefixes.Tebi";
+    /// Prefix for 2^50.
+    Pebi => c"flecs.units.prefixes.Pebi";
+    /// Prefix for 2^60.
+    Exbi => c"flecs.units.prefixes.Exbi";
+    /// Prefix for 2^70.
+    Zebi => c"flecs.units.prefixes.Zebi";
+    /// Prefix for 2^80.
+    Yobi => c"flecs.units.prefixes.Yobi";
+
+    /// Quantity for durations.
+    Duration => c"flecs.units.Duration";
+    /// Quantity for points in time.
+    Time => c"flecs.units.Time";
+    /// Quantity for mass.
+    Mass => c"flecs.units.Mass";
+    /// Quantity for electric current.
+    ElectricCurrent => c"flecs.units.ElectricCurrent";
+    /// Quantity for amount of substance.
+    Amount => c"flecs.units.Amount";
+    /// Quantity for luminous intensity.
+    LuminousIntensity => c"flecs.units.LuminousIntensity";
+    /// Quantity for force.
+    Force => c"flecs.units.Force";
+    /// Quantity for length.
+    Length => c"flecs.units.Length";
+    /// Quantity for pressure.
+    Pressure => c"flecs.units.Pressure";
+    /// Quantity for speed.
+    Speed => c"flecs.units.Speed";
+    /// Quantity for temperature.
+    Temperature => c"flecs.units.Temperature";
+    /// Quantity for amounts of data.
+    Data => c"flecs.units.Data";
+    /// Quantity for data rates.
+    DataRate => c"flecs.units.DataRate";
+    /// Quantity for angles.
+    Angle => c"flecs.units.Angle";
+    /// Quantity for frequency.
+    Frequency => c"flecs.units.Frequency";
+    /// Quantity for uniform resource identifiers.
+    Uri => c"flecs.units.Uri";
+    /// Quantity for colors.
+    Color => c"flecs.units.Color";
+
+    /// Percentage unit, which is also its own quantity.
+    Percentage => c"flecs.units.Percentage";
+    /// Acceleration unit (meters per second squared).
+    Acceleration => c"flecs.units.Acceleration";
+    /// Bel unit.
+    Bel => c"flecs.units.Bel";
+    /// Decibel unit.
+    DeciBel => c"flecs.units.DeciBel";
+}
+
+/// Units for the [`Duration`] quantity.
+pub mod duration {
+    unit_types! {
+        /// Picoseconds.
+        PicoSeconds => c"flecs.units.Duration.PicoSeconds";
+        /// Nanoseconds.
+        NanoSeconds => c"flecs.units.Duration.NanoSeconds";
+        /// Microseconds.
+        MicroSeconds => c"flecs.units.Duration.MicroSeconds";
+        /// Milliseconds.
+        MilliSeconds => c"flecs.units.Duration.MilliSeconds";
+        /// Seconds.
+        Seconds => c"flecs.units.Duration.Seconds";
+        /// Minutes.
+        Minutes => c"flecs.units.Duration.Minutes";
+        /// Hours.
+        Hours => c"flecs.units.Duration.Hours";
+        /// Days.
+        Days => c"flecs.units.Duration.Days";
+    }
+}
+
+/// Units for the [`Time`] quantity.
+pub mod time {
+    unit_types! {
+        /// Date, stored as seconds since the unix epoch.
+        Date => c"flecs.units.Time.Date";
+    }
+}
+
+/// Units for the [`Mass`] quantity.
+pub mod mass {
+    unit_types! {
+        /// Grams.
+        Grams => c"flecs.units.Mass.Grams";
+        /// Kilograms.
+        KiloGrams => c"flecs.units.Mass.KiloGrams";
+    }
+}
+
+/// Units for the [`ElectricCurrent`] quantity.
+pub mod electric_current {
+    unit_types! {
+        /// Ampere.
+        Ampere => c"flecs.units.ElectricCurrent.Ampere";
+    }
+}
+
+/// Units for the [`Amount`] quantity.
+pub mod amount {
+    unit_types! {
+        /// Mole.
+        Mole => c"flecs.units.Amount.Mole";
+    }
+}
+
+/// Units for the [`LuminousIntensity`] quantity.
+pub mod luminous_intensity {
+    unit_types! {
+        /// Candela.
+        Candela => c"flecs.units.LuminousIntensity.Candela";
+    }
+}
+
+/// Units for the [`Force`] quantity.
+pub mod force {
+    unit_types! {
+        /// Newton.
+        Newton => c"flecs.units.Force.Newton";
+    }
+}
+
+/// Units for the [`Length`] quantity.
+pub mod length {
+    unit_types! {
+        /// Meters.
+        Meters => c"flecs.units.Length.Meters";
+        /// Picometers.
+        PicoMeters => c"flecs.units.Length.PicoMeters";
+        /// Nanometers.
+        NanoMeters => c"flecs.units.Length.NanoMeters";
+        /// Micrometers.
+        MicroMeters => c"flecs.units.Length.MicroMeters";
+        /// Millimeters.
+        MilliMeters => c"flecs.units.Length.MilliMeters";
+        /// Centimeters.
+        CentiMeters => c"flecs.units.Length.CentiMeters";
+        /// Kilometers.
+        KiloMeters => c"flecs.units.Length.KiloMeters";
+        /// Miles.
+        Miles => c"flecs.units.Length.Miles";
+        /// Pixels.
+        Pixels => c"flecs.units.Length.Pixels";
+    }
+}
+
+/// Units for the [`Pressure`] quantity.
+pub mod pressure {
+    unit_types! {
+        /// Pascal.
+        Pascal => c"flecs.units.Pressure.Pascal";
+        /// Bar.
+        Bar => c"flecs.units.Pressure.Bar";
+    }
+}
+
+/// Units for the [`Speed`] quantity.
+pub mod speed {
+    unit_types! {
+        /// Meters per second.
+        MetersPerSecond => c"flecs.units.Speed.MetersPerSecond";
+        /// Kilometers per second.
+        KiloMetersPerSecond => c"flecs.units.Speed.KiloMetersPerSecond";
+        /// Kilometers per hour.
+        KiloMetersPerHour => c"flecs.units.Speed.KiloMetersPerHour";
+        /// Miles per hour.
+        MilesPerHour => c"flecs.units.Speed.MilesPerHour";
+    }
+}
+
+/// Units for the [`Temperature`] quantity.
+pub mod temperature {
+    unit_types! {
+        /// Kelvin.
+        Kelvin => c"flecs.units.Temperature.Kelvin";
+        /// Celsius.
+        Celsius => c"flecs.units.Temperature.Celsius";
+        /// Fahrenheit.
+        Fahrenheit => c"flecs.units.Temperature.Fahrenheit";
+    }
+}
+
+/// Units for the [`Data`] quantity.
+pub mod data {
+    unit_types! {
+        /// Bits.
+        Bits => c"flecs.units.Data.Bits";
+        /// Kilobits.
+        KiloBits => c"flecs.units.Data.KiloBits";
+        /// Megabits.
+        MegaBits => c"flecs.units.Data.MegaBits";
+        /// Gigabits.
+        GigaBits => c"flecs.units.Data.GigaBits";
+        /// Bytes.
+        Bytes => c"flecs.units.Data.Bytes";
+        /// Kilobytes.
+        KiloBytes => c"flecs.units.Data.KiloBytes";
+        /// Megabytes.
+        MegaBytes => c"flecs.units.Data.MegaBytes";
+        /// Gigabytes.
+        GigaBytes => c"flecs.units.Data.GigaBytes";
+        /// Kibibytes.
+        KibiBytes => c"flecs.units.Data.KibiBytes";
+        /// Mebibytes.
+        MebiBytes => c"flecs.units.Data.MebiBytes";
+        /// Gibibytes.
+        GibiBytes => c"flecs.units.Data.GibiBytes";
+    }
+}
+
+/// Units for the [`DataRate`] quantity.
+pub mod datarate {
+    unit_types! {
+        /// Bits per second.
+        BitsPerSecond => c"flecs.units.DataRate.BitsPerSecond";
+        /// Kilobits per second.
+        KiloBitsPerSecond => c"flecs.units.DataRate.KiloBitsPerSecond";
+        /// Megabits per second.
+        MegaBitsPerSecond => c"flecs.units.DataRate.MegaBitsPerSecond";
+        /// Gigabits per second.
+        GigaBitsPerSecond => c"flecs.units.DataRate.GigaBitsPerSecond";
+        /// Bytes per second.
+        BytesPerSecond => c"flecs.units.DataRate.BytesPerSecond";
+        /// Kilobytes per second.
+        KiloBytesPerSecond => c"flecs.units.DataRate.KiloBytesPerSecond";
+        /// Megabytes per second.
+        MegaBytesPerSecond => c"flecs.units.DataRate.MegaBytesPerSecond";
+        /// Gigabytes per second.
+        GigaBytesPerSecond => c"flecs.units.DataRate.GigaBytesPerSecond";
+    }
+}
+
+/// Units for the [`Angle`] quantity.
+pub mod angle {
+    unit_types! {
+        /// Radians.
+        Radians => c"flecs.units.Angle.Radians";
+        /// Degrees.
+        Degrees => c"flecs.units.Angle.Degrees";
+    }
+}
+
+/// Units for the [`Frequency`] quantity.
+pub mod frequency {
+    unit_types! {
+        /// Hertz.
+        Hertz => c"flecs.units.Frequency.Hertz";
+        /// Kilohertz.
+        KiloHertz => c"flecs.units.Frequency.KiloHertz";
+        /// Megahertz.
+        MegaHertz => c"flecs.units.Frequency.MegaHertz";
+        /// Gigahertz.
+        GigaHertz => c"flecs.units.Frequency.GigaHertz";
+    }
+}
+
+/// Units for the [`Uri`] quantity.
+pub mod uri {
+    unit_types! {
+        /// Hyperlink.
+        Hyperlink => c"flecs.units.Uri.Hyperlink";
+        /// Image.
+        Image => c"flecs.units.Uri.Image";
+        /// File.
+        File => c"flecs.units.Uri.File";
+    }
+}
+
+/// Units for the [`Color`] quantity.
+pub mod color {
+    unit_types! {
+        /// RGB color.
+        Rgb => c"flecs.units.Color.Rgb";
+        /// HSL color.
+        Hsl => c"flecs.units.Color.Hsl";
+        /// CSS color.
+        Css => c"flecs.units.Color.Css";
+    }
+}
+
+///////////////////////////
+// trait implementations
+///////////////////////////
+
+impl flecs_ecs::core::DataComponent for Units {}
+
+impl flecs_ecs::core::ComponentType<flecs_ecs::core::Struct> for Units {}
+
+impl flecs_ecs::core::component_registration::registration_traits::ComponentInfo for Units {
+    const IS_GENERIC: bool = false;
+    const IS_ENUM: bool = false;
+    const IS_TAG: bool = false;
+    type TagType =
+        flecs_ecs::core::component_registration::registration_traits::FlecsFirstIsNotATag;
+    const IMPLS_CLONE: bool = true;
+    const IMPLS_DEFAULT: bool = true;
+    const IS_REF: bool = false;
+    const IS_MUT: bool = false;
+}
+
+impl flecs_ecs::core::component_registration::registration_traits::ComponentId for Units
+where
+    Self: 'static,
+{
+    type UnderlyingType = Units;
+    type UnderlyingEnumType = flecs_ecs::core::component_registration::NoneEnum;
+    #[inline(always)]
+    fn index() -> u32 {
+        static INDEX: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(u32::MAX);
+        Self::get_or_init_index(&INDEX)
+    }
+    fn __register_lifecycle_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        flecs_ecs::core::lifecycle_traits::register_lifecycle_actions::<Units>(type_hooks);
+    }
+    fn __register_default_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_DEFAULT: bool = Units::IMPLS_DEFAULT;
+        if IMPLS_DEFAULT {
+            flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,Units>as flecs_ecs::core::component_registration::registration_traits::FlecsDefaultType> ::Type, >(type_hooks);
+        }
+    }
+    fn __register_clone_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+        const IMPLS_CLONE: bool = Units::IMPLS_CLONE;
+        if IMPLS_CLONE {
+            flecs_ecs::core::lifecycle_traits::register_copy_lifecycle_action:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_CLONE,Units>as flecs_ecs::core::component_registration::registration_traits::FlecsCloneType> ::Type, >(type_hooks);
+        } else {
+            flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<Units>(
+                type_hooks,
+            );
+        }
+    }
+
+    fn __register_or_get_id<'a, const MANUAL_REGISTRATION_CHECK: bool>(
+        world: impl WorldProvider<'a>,
+    ) -> sys::ecs_entity_t {
+        Self::__register_or_get_id_named::<MANUAL_REGISTRATION_CHECK>(world, "flecs::units")
+    }
+}
@@ -184,6 +184,12 @@ pub trait ComponentId: Sized + ComponentInfo + 'static + Send + Sync {
                 >(world);
 
                 components_array[index] = id;
+                #[cfg(feature = "flecs_meta")]
+                {
+                    world
+                        .components_map()
+                        .insert(std::any::TypeId::of::<Self>(), id);
+                }
                 id
             }
         } else {
@@ -269,6 +275,12 @@ pub trait ComponentId: Sized + ComponentInfo + 'static + Send + Sync {
                     Self,
                 >(world, name);
                 components_array[index] = id;
+                #[cfg(feature = "flecs_meta")]
+                {
+                    world
+                        .components_map()
+                        .insert(std::any::TypeId::of::<Self>(), id);
+                }
                 id
             }
         } else {
@@ -338,6 +350,11 @@ pub trait ComponentId: Sized + ComponentInfo + 'static + Send + Sync {
     #[doc(hidden)]
     fn __register_clone_hooks(_type_hooks: &mut sys::ecs_type_hooks_t) {}
 
+    /// Registers the ctor hook of a generic component, which uses `Default`.
+    ///
+    /// The hook is registered automatically when `Default` follows from the bounds
+    /// on the generic parameters, so this is only needed for instantiations of
+    /// components such as `struct Generic<T>`, once per world.
     fn register_ctor_hook<'a>(world: impl WorldProvider<'a>)
     where
         Self: Default,
@@ -357,6 +374,11 @@ pub trait ComponentId: Sized + ComponentInfo + 'static + Send + Sync {
         }
     }
 
+    /// Registers the copy hook of a generic component, which uses `Clone`.
+    ///
+    /// The hook is registered automatically when `Clone` follows from the bounds
+    /// on the generic parameters, so this is only needed for instantiations of
+    /// components such as `struct Generic<T>`, once per world.
     fn register_clone_hook<'a>(world: impl WorldProvider<'a>)
     where
         Self: Clone,
@@ -73,6 +73,8 @@ impl<'a> EntityView<'a> {
             let desc = sys::ecs_entity_desc_t::default();
             unsafe { sys::ecs_entity_init(world_ptr, &desc) }
         };
+        #[cfg(feature = "flecs_journal")]
+        crate::addons::journal::record_new(world_ptr, id);
         Self {
             world: world.world(),
             id: id.into(),
@@ -26,6 +26,124 @@ impl<'a> EntityView<'a> {
             }
         }
     }
+
+    /// Returns an error if the entity is not alive.
+    fn validate_alive(self) -> Result<(), FlecsError> {
+        if unsafe { sys::ecs_is_alive(self.world.world_ptr(), *self.id) } {
+            Ok(())
+        } else {
+            Err(
+                FlecsError::new(FlecsErrorCode::InvalidParameter, "entity is not alive")
+                    .with_entity(self.id),
+            )
+        }
+    }
+
+    /// Returns an error if adding `id` to the entity would violate one of the
+    /// checks below. These are the common constraints that are asserted by the
+    /// C library when adding an id, not all of them.
+    fn validate_id(self, id: u64) -> Result<(), FlecsError> {
+        let world = self.world.world_ptr();
+        let error = |code, message: &str| {
+            Err(FlecsError::new(code, message)
+                .with_entity(self.id)
+                .with_id(id))
+        };
+
+        self.validate_alive()?;
+
+        if !unsafe { sys::ecs_id_is_valid(world, id) } {
+            return error(
+                FlecsErrorCode::InvalidParameter,
+                "id is not a valid component, pair or entity",
+            );
+        }
+
+        if ecs_is_pair(id) {
+            let first = unsafe { sys::ecs_get_alive(world, *ecs_first(id)) };
+            let second = unsafe { sys::ecs_get_alive(world, *ecs_second(id)) };
+            if first == 0 {
+                return error(
+                    FlecsErrorCode::InvalidParameter,
+                    "relationship of pair is not alive",
+                );
+            }
+            if second == 0 {
+                return error(
+                    FlecsErrorCode::InvalidParameter,
+                    "target of pair is not alive",
+                );
+            }
+
+            let has = |entity, id| unsafe { sys::ecs_has_id(world, entity, id) };
+            if has(second, ECS_RELATIONSHIP) && !has(first, ECS_TRAIT) {
+                return error(
+                    FlecsErrorCode::ConstraintViolated,
+                    "relationship cannot be used as target of pair",
+                );
+            }
+            if has(first, ECS_TARGET) {
+                return error(
+                    FlecsErrorCode::ConstraintViolated,
+                    "target cannot be used as relationship of pair",
+                );
+            }
+
+            let one_of = if has(first, ECS_ONE_OF) {
+                first
+            } else {
+                unsafe { sys::ecs_get_target(world, first, ECS_ONE_OF, 0) }
+            };
+            if one_of != 0 && !ecs_has_pair(world, second, ECS_CHILD_OF, one_of) {
+                return error(
+                    FlecsErrorCode::ConstraintViolated,
+                    "target of pair is not a child of the OneOf scope of the relationship",
+                );
+            }
+
+            if first == ECS_IS_A && has(second, ECS_FINAL) {
+                return error(
+                    FlecsErrorCode::ConstraintViolated,
+                    "cannot inherit from a final entity",
+                );
+            }
+        } else if id & RUST_ecs_id_FLAGS_MASK == 0 && !unsafe { sys::ecs_is_alive(world, id) } {
+            return error(FlecsErrorCode::InvalidParameter, "id is not alive");
+        }
+
+        Ok(())
+    }
+
+    /// Returns an error if the name can't be used for the entity in the
+    /// scope of `parent`.
+    fn validate_name(self, parent: u64, name: &str) -> Result<(), FlecsError> {
+        let world = self.world.world_ptr();
+
+        if name.len() > 1 && name.starts_with('#') && name[1..].bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(FlecsError::new(
+                FlecsErrorCode::InvalidParameter,
+                format!("name '{name}' is reserved for looking up entities by id"),
+            )
+            .with_entity(self.id));
+        }
+
+        let c_name = compact_str::format_compact!("{}\0", name);
+        let existing = unsafe { sys::ecs_lookup_child(world, parent, c_name.as_ptr() as *const _) };
+        if existing != 0 && existing != *self.id {
+            let scope = EntityView::new_from(self.world, parent)
+                .path()
+                .unwrap_or_default();
+            return Err(FlecsError::new(
+                FlecsErrorCode::NameInUse,
+                format!("name '{name}' is already in use by entity {existing} in scope '{scope}'"),
+            )
+            .with_entity(self.id));
+        }
+
+        Ok(())
+    }
+
     /// Add an id to an entity.
     /// This Id can be a component, a pair, a tag or another entity.
     ///
@@ -48,6 +166,33 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Add an id to an entity, returning an error instead of panicking or
+    /// aborting when the id can't be added.
+    ///
+    /// This is the fallible version of [`EntityView::add_id()`].
+    ///
+    /// # Errors
+    ///
+    /// * [`FlecsErrorCode::InvalidParameter`] if the entity or id is not alive,
+    ///   or if the id is not a valid component, pair or entity.
+    /// * [`FlecsErrorCode::ConstraintViolated`] if the id violates a
+    ///   constraint of its relationship, such as inheriting from a final entity.
+    ///
+    /// Other failures are not checked up front, see [`FlecsError`].
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::add_id()`]
+    pub fn try_add_id(self, id: impl IntoId) -> Result<Self, FlecsError> {
+        let id = *id.into();
+        let world = self.world.world_ptr_mut();
+
+        self.validate_id(id)?;
+
+        unsafe { sys::ecs_add_id(world, *self.id, id) }
+        Ok(self)
+    }
+
     pub(crate) unsafe fn add_id_unchecked(self, id: impl IntoId) -> Self {
         let id = *id.into();
         let world = self.world.world_ptr_mut();
@@ -67,10 +212,12 @@ impl<'a> EntityView<'a> {
         T: ComponentOrPairId,
     {
         const {
-            if T::CastType::IS_GENERIC {
-                panic!("Adding a generic type requires to use the set function. This is due to Rust type system limitations.");
-            } else if !T::CastType::IS_TAG && !T::CastType::IMPLS_DEFAULT {
-                panic!("Adding an element that is not a Tag / Zero sized type requires to implement Default");
+            if !T::CastType::IS_TAG && !T::CastType::IMPLS_DEFAULT {
+                if T::CastType::IS_GENERIC {
+                    panic!("Adding a generic type requires Default to follow from its bounds, otherwise use the set function. This is due to Rust type system limitations.");
+                } else {
+                    panic!("Adding an element that is not a Tag / Zero sized type requires to implement Default");
+                }
             }
         }
         let world = self.world;
@@ -571,6 +718,71 @@ impl<'a> EntityView<'a> {
         self.child_of_id(T::id(world))
     }
 
+    /// Shortcut for `try_add_id((flecs::ChildOf::ID, parent))`.
+    ///
+    /// This is the fallible version of [`EntityView::child_of_id()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `parent`: The parent entity.
+    ///
+    /// # Errors
+    ///
+    /// * [`FlecsErrorCode::InvalidParameter`] if the entity or parent is not alive.
+    /// * [`FlecsErrorCode::CycleDetected`] if the parent is the entity itself
+    ///   or one of its descendants.
+    /// * [`FlecsErrorCode::NameInUse`] if the parent already has a child with
+    ///   the name of the entity.
+    ///
+    /// Other failures are not checked up front, see [`FlecsError`].
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::child_of_id()`]
+    pub fn try_child_of_id(self, parent: impl Into<Entity>) -> Result<Self, FlecsError> {
+        let parent = *parent.into();
+        let world = self.world.world_ptr();
+        let pair = ecs_pair(ECS_CHILD_OF, parent);
+
+        self.validate_id(pair)?;
+
+        let mut ancestor = parent;
+        while ancestor != 0 {
+            if ancestor == *self.id {
+                return Err(FlecsError::new(
+                    FlecsErrorCode::CycleDetected,
+                    "entity can't be a child of itself or of one of its descendants",
+                )
+                .with_entity(self.id)
+                .with_id(pair));
+            }
+            ancestor = unsafe { sys::ecs_get_target(world, ancestor, ECS_CHILD_OF, 0) };
+        }
+
+        if let Some(name) = self.get_name() {
+            self.validate_name(parent, name)
+                .map_err(|err| err.with_id(pair))?;
+        }
+
+        Ok(unsafe { self.add_id_unchecked(pair) })
+    }
+
+    /// Shortcut for `try_add_id((flecs::ChildOf::ID, T))`.
+    ///
+    /// This is the fallible version of [`EntityView::child_of()`].
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T`: the type associated with the parent entity.
+    ///
+    /// # Errors
+    ///
+    /// See [`EntityView::try_child_of_id()`].
+    pub fn try_child_of<T: ComponentId>(self) -> Result<Self, FlecsError> {
+        let world = self.world;
+        self.try_child_of_id(T::id(world))
+    }
+
     /// Shortcut for `add_id((flecs::DependsOn::ID, entity))`.
     ///
     /// # Arguments
@@ -867,6 +1079,47 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Sets a component of type `T` on the entity, returning an error instead
+    /// of panicking or aborting when the component can't be set.
+    ///
+    /// This is the fallible version of [`EntityView::set()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `component` - The component to set on the entity.
+    ///
+    /// # Errors
+    ///
+    /// * [`FlecsErrorCode::ComponentNotRegistered`] if the `flecs_manual_registration`
+    ///   feature is enabled and `T` is not registered with the world.
+    /// * [`FlecsErrorCode::InvalidParameter`] if the entity is not alive.
+    ///
+    /// Other failures are not checked up front, see [`FlecsError`].
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set()`]
+    pub fn try_set<T: ComponentId + DataComponent>(self, component: T) -> Result<Self, FlecsError> {
+        #[cfg(feature = "flecs_manual_registration")]
+        if !T::UnderlyingType::is_registered_with_world(self.world) {
+            return Err(FlecsError::new(
+                FlecsErrorCode::ComponentNotRegistered,
+                format!(
+                    "component {} is not registered with the world",
+                    std::any::type_name::<T>()
+                ),
+            )
+            .with_entity(self.id));
+        }
+
+        self.validate_alive()?;
+        let id = T::id(self.world);
+        self.validate_id(id)?;
+
+        set_helper(self.world.world_ptr_mut(), *self.id, component, id);
+        Ok(self)
+    }
+
     /// Sets the data of the specified id. Can be a pair or Component.
     ///
     /// # Safety
@@ -1149,6 +1402,37 @@ impl<'a> EntityView<'a> {
         self
     }
 
+    /// Sets the name of the entity, returning an error instead of aborting
+    /// when the name can't be used.
+    ///
+    /// This is the fallible version of [`EntityView::set_name()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice that holds the name to be set.
+    ///
+    /// # Errors
+    ///
+    /// * [`FlecsErrorCode::InvalidParameter`] if the entity is not alive, or if
+    ///   the name has the `#<id>` form that is reserved for lookups by id.
+    /// * [`FlecsErrorCode::NameInUse`] if another entity in the same scope
+    ///   already has the name.
+    ///
+    /// Other failures are not checked up front, see [`FlecsError`].
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::set_name()`]
+    pub fn try_set_name(self, name: &str) -> Result<Self, FlecsError> {
+        self.validate_alive()?;
+
+        let parent =
+            unsafe { sys::ecs_get_target(self.world.world_ptr(), *self.id, ECS_CHILD_OF, 0) };
+        self.validate_name(parent, name)?;
+
+        Ok(self.set_name(name))
+    }
+
     /// Removes the name of the entity.
     pub fn remove_name(self) -> Self {
         unsafe {
@@ -1627,5 +1911,7 @@ impl<'a> EntityView<'a> {
     #[doc(alias = "entity::destruct")]
     pub fn destruct(self) {
         unsafe { sys::ecs_delete(self.world.world_ptr_mut(), *self.id) }
+        #[cfg(feature = "flecs_journal")]
+        crate::addons::journal::record_delete(self.world, *self.id);
     }
 }
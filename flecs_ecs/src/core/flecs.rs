@@ -404,6 +404,30 @@ pub mod script {
 }
 pub use script::Script;
 
+#[cfg(feature = "flecs_metrics")]
+pub mod metrics {
+    use super::*;
+    use crate::sys::{FLECS_IDEcsMetricSourceID_, FLECS_IDEcsMetricValueID_};
+    /// Component that stores the current value of a metric instance.
+    pub type Value = crate::sys::EcsMetricValue;
+    impl_component_traits_binding_type_w_static_id!(Value, FLECS_IDEcsMetricValueID_);
+    /// Component that stores the entity a metric instance was created for.
+    pub type Source = crate::sys::EcsMetricSource;
+    impl_component_traits_binding_type_w_static_id!(Source, FLECS_IDEcsMetricSourceID_);
+}
+
+#[cfg(feature = "flecs_alerts")]
+pub mod alerts {
+    use super::*;
+    use crate::sys::{FLECS_IDEcsAlertInstanceID_, FLECS_IDEcsAlertsActiveID_};
+    /// Component that stores the generated message of an alert instance.
+    pub type Instance = crate::sys::EcsAlertInstance;
+    impl_component_traits_binding_type_w_static_id!(Instance, FLECS_IDEcsAlertInstanceID_);
+    /// Component added to entities that have active alerts.
+    pub type AlertsActive = crate::sys::EcsAlertsActive;
+    impl_component_traits_binding_type_w_static_id!(AlertsActive, FLECS_IDEcsAlertsActiveID_);
+}
+
 create_pre_registered_component!(
     Sparse,
     ECS_SPARSE,
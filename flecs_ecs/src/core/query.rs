@@ -123,7 +123,7 @@ where
         self.query.as_ptr()
     }
 
-    fn iter_next_func(&self) -> unsafe extern "C" fn(*mut sys::ecs_iter_t) -> bool {
+    fn iter_next_func(&self) -> sys::ecs_iter_next_fn_t {
         sys::ecs_query_next
     }
 }
@@ -362,6 +362,197 @@ where
             std::ptr::null_mut()
         }
     }
+
+    /// Parallel each iterator. Divides the matched entities across `threads`
+    /// threads, and invokes the function for each matching entity.
+    ///
+    /// The world is put in readonly mode while the threads run, and each thread
+    /// iterates from its own stage. Operations that modify the world are
+    /// deferred, and merged when all threads are done. This means the query
+    /// can't be used while the world is already readonly, for example from
+    /// inside a system.
+    ///
+    /// Components that are added from the function must be registered before
+    /// calling this, since components can't be registered while the world is
+    /// readonly.
+    ///
+    /// # Panics
+    ///
+    /// Threads only get disjoint rows of the tables matched by `$this`, so a
+    /// field can only be mutable (`&mut T`) if it is matched on `$this` itself.
+    /// Mutable fields that may be shared between entities, such as singletons,
+    /// fields with a fixed source and fields that are matched through up
+    /// traversal (parents, inherited components), panic. Use `&T` for those.
+    ///
+    /// # Arguments
+    ///
+    /// * `threads` - The number of threads to run the function on.
+    /// * `func` - The function invoked for each matching entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    /// let world = World::new();
+    /// for i in 0..100 {
+    ///     world.entity().set(Position { x: i as f32, y: 0.0 });
+    /// }
+    ///
+    /// let query = world.new_query::<&mut Position>();
+    /// query.par_each(4, |p| {
+    ///     p.y = p.x * 2.0;
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Query::par_each_entity()`]
+    /// * [`QueryIter::worker()`]
+    pub fn par_each(&self, threads: i32, func: impl Fn(T::TupleType<'_>) + Send + Sync) {
+        const {
+            assert!(
+                !T::CONTAINS_ANY_TAG_TERM,
+                "a type provided in the query signature is a Tag and cannot be used with `.par_each`. provide the tag with `.with()` instead"
+            );
+        }
+
+        self.par_run(threads, |_, iter| {
+            let mut components_data = T::create_ptrs(iter);
+            let iter_count = if iter.count == 0 && iter.table.is_null() {
+                1_usize
+            } else {
+                iter.count as usize
+            };
+
+            for i in 0..iter_count {
+                func(components_data.get_tuple(iter, i));
+            }
+        });
+    }
+
+    /// Parallel each iterator. Divides the matched entities across `threads`
+    /// threads, and invokes the function for each matching entity.
+    ///
+    /// The entity belongs to the stage of the thread, so operations on the
+    /// entity are deferred until all threads are done. See [`Query::par_each()`]
+    /// for details, and for the fields that can be mutable.
+    ///
+    /// # Arguments
+    ///
+    /// * `threads` - The number of threads to run the function on.
+    /// * `func` - The function invoked for each matching entity.
+    ///
+    /// # See also
+    ///
+    /// * [`Query::par_each()`]
+    pub fn par_each_entity(
+        &self,
+        threads: i32,
+        func: impl Fn(EntityView, T::TupleType<'_>) + Send + Sync,
+    ) {
+        const {
+            assert!(
+                !T::CONTAINS_ANY_TAG_TERM,
+                "a type provided in the query signature is a Tag and cannot be used with `.par_each_entity`. provide the tag with `.with()` instead"
+            );
+        }
+
+        self.par_run(threads, |stage, iter| unsafe {
+            ecs_assert!(
+                iter.count > 0,
+                FlecsErrorCode::InvalidOperation,
+                "no entities returned, use par_each() without flecs::entity argument",
+            );
+
+            let mut components_data = T::create_ptrs(iter);
+            for i in 0..iter.count as usize {
+                let entity = EntityView::new_from(stage, *iter.entities.add(i));
+                func(entity, components_data.get_tuple(iter, i));
+            }
+        });
+    }
+
+    /// Runs `func` for each result of a worker iterator on `threads` threads,
+    /// with the world in readonly mode.
+    fn par_run(&self, threads: i32, func: impl Fn(WorldRef, &sys::ecs_iter_t) + Sync) {
+        ecs_assert!(
+            threads > 0,
+            FlecsErrorCode::InvalidParameter,
+            "thread count must be at least 1"
+        );
+
+        let world = self.world_ptr_mut();
+        ecs_assert!(
+            unsafe { !sys::ecs_stage_is_readonly(world) && !sys::ecs_is_deferred(world) },
+            FlecsErrorCode::InvalidWhileReadonly,
+            "parallel iteration can't be used while the world is readonly or deferred"
+        );
+
+        // Threads get disjoint rows of the tables matched by `$this`. Fields of
+        // other sources are shared by all threads, so writing to them from more
+        // than one thread is a data race. This is checked in release builds too.
+        let flags = unsafe { self.query.as_ref().flags };
+        assert!(
+            flags & sys::EcsQueryHasNonThisOutTerms == 0,
+            "{}: parallel iteration can't write to fields that aren't matched on `$this` itself, \
+            such as singletons, parents or inherited components. Use `&T` for these fields",
+            FlecsErrorCode::InvalidOperation
+        );
+
+        /// Ends readonly mode, which merges the commands of the stages, and
+        /// removes the stages that were added for the threads. This also runs
+        /// when one of the threads panics.
+        struct ReadonlyGuard {
+            world: *mut sys::ecs_world_t,
+            stage_count: Option<i32>,
+        }
+
+        impl Drop for ReadonlyGuard {
+            fn drop(&mut self) {
+                unsafe {
+                    sys::ecs_readonly_end(self.world);
+                    if let Some(stage_count) = self.stage_count {
+                        sys::ecs_set_stage_count(self.world, stage_count);
+                    }
+                }
+            }
+        }
+
+        let stage_count = unsafe { sys::ecs_get_stage_count(world) };
+        if stage_count < threads {
+            unsafe { sys::ecs_set_stage_count(world, threads) };
+        }
+        unsafe { sys::ecs_readonly_begin(world, true) };
+        let _guard = ReadonlyGuard {
+            world,
+            stage_count: (stage_count < threads).then_some(stage_count),
+        };
+
+        // raw pointers aren't Send, the world and query outlive the scope
+        let world = world as usize;
+        let query = self.query.as_ptr() as usize;
+        let func = &func;
+
+        std::thread::scope(|scope| {
+            for index in 0..threads {
+                scope.spawn(move || unsafe {
+                    let stage = sys::ecs_get_stage(world as *const sys::ecs_world_t, index);
+                    let mut iter = sys::ecs_query_iter(stage, query as *const sys::ecs_query_t);
+                    iter.flags |= sys::EcsIterCppEach;
+                    let mut worker = sys::ecs_worker_iter(&iter, index, threads);
+
+                    while sys::ecs_worker_next(&mut worker) {
+                        func(WorldRef::from_ptr(stage), &worker);
+                    }
+                });
+            }
+        });
+    }
 }
 
 impl<T: QueryTuple> From<&Query<T>> for NonNull<sys::ecs_query_t> {
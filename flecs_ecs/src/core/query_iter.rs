@@ -1,4 +1,5 @@
 //! Class that extends the capabilities of a [`Query`] by providing additional operations on the query's iterator.
+use std::cell::UnsafeCell;
 use std::ffi::c_void;
 
 use crate::core::*;
@@ -9,21 +10,120 @@ where
     T: QueryTuple,
 {
     iter: sys::ecs_iter_t,
-    iter_next: unsafe extern "C" fn(*mut sys::ecs_iter_t) -> bool,
+    iter_next: sys::ecs_iter_next_fn_t,
+    chain: Option<Box<ChainedIter<'a, P, T>>>,
     _phantom: std::marker::PhantomData<&'a (P, T)>,
 }
 
+/// The iterator that a page or worker iterator reads its results from. It's boxed
+/// so that the `chain_it` pointer of the chained iterator stays valid when the
+/// [`QueryIter`] is moved.
+struct ChainedIter<'a, P, T>
+where
+    T: QueryTuple,
+{
+    iter: UnsafeCell<sys::ecs_iter_t>,
+    _parent: QueryIter<'a, P, T>,
+}
+
 impl<'a, P, T> QueryIter<'a, P, T>
 where
     T: QueryTuple,
 {
-    pub fn new(
-        iter: sys::ecs_iter_t,
-        iter_next: unsafe extern "C" fn(*mut sys::ecs_iter_t) -> bool,
-    ) -> Self {
+    pub fn new(iter: sys::ecs_iter_t, iter_next: sys::ecs_iter_next_fn_t) -> Self {
         Self {
             iter,
             iter_next,
+            chain: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Limit the results of the iterator to a range of entities. This can be
+    /// used to paginate the results of a query.
+    ///
+    /// Variables and groups should be set before calling this function. A page
+    /// can be divided across workers with [`QueryIter::worker()`], but a worker
+    /// iterator can't be paged.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: the number of entities to skip
+    /// * `limit`: the maximum number of entities to return, or 0 for no limit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    /// # let world = World::new();
+    /// for i in 0..10 {
+    ///     world.entity().set(Position { x: i as f32, y: 0.0 });
+    /// }
+    ///
+    /// let query = world.new_query::<&Position>();
+    ///
+    /// let mut xs = vec![];
+    /// query.iterable().page(2, 3).each(|p| xs.push(p.x));
+    /// assert_eq!(xs, [2.0, 3.0, 4.0]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `iter_iterable::page`
+    #[doc(alias = "iter_iterable::page")]
+    pub fn page(self, offset: i32, limit: i32) -> QueryIter<'a, P, T> {
+        self.chain(sys::ecs_page_next, |it| unsafe {
+            sys::ecs_page_iter(it, offset, limit)
+        })
+    }
+
+    /// Limit the results of the iterator to the share of one of `count` workers.
+    /// Entities are divided equally across workers, which allows for splitting
+    /// the work of a query across threads.
+    ///
+    /// Variables and groups should be set before calling this function.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: the index of the worker, in the range `0..count`
+    /// * `count`: the total number of workers
+    ///
+    /// # See also
+    ///
+    /// * C++ API: `iter_iterable::worker`
+    #[doc(alias = "iter_iterable::worker")]
+    pub fn worker(self, index: i32, count: i32) -> QueryIter<'a, P, T> {
+        ecs_assert!(
+            count > 0 && (0..count).contains(&index),
+            FlecsErrorCode::InvalidParameter,
+            "worker index {} out of range for {} workers",
+            index,
+            count
+        );
+        self.chain(sys::ecs_worker_next, |it| unsafe {
+            sys::ecs_worker_iter(it, index, count)
+        })
+    }
+
+    fn chain(
+        self,
+        iter_next: sys::ecs_iter_next_fn_t,
+        create: impl FnOnce(*const sys::ecs_iter_t) -> sys::ecs_iter_t,
+    ) -> QueryIter<'a, P, T> {
+        let chain = Box::new(ChainedIter {
+            iter: UnsafeCell::new(self.retrieve_iter()),
+            _parent: self,
+        });
+        let iter = create(chain.iter.get());
+        QueryIter {
+            iter,
+            iter_next,
+            chain: Some(chain),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -167,7 +267,7 @@ where
         self.iter.query
     }
 
-    fn iter_next_func(&self) -> unsafe extern "C" fn(*mut sys::ecs_iter_t) -> bool {
+    fn iter_next_func(&self) -> sys::ecs_iter_next_fn_t {
         self.iter_next
     }
 }
@@ -193,5 +293,3 @@ where
         unsafe { WorldRef::from_ptr(self.iter.world) }
     }
 }
-
-// TODO : worker_iterable and page_iterable not implemented yet
@@ -0,0 +1,137 @@
+//! Turn aborts of the C library into Rust panics.
+//!
+//! By default, a failed assert or an abort in the C library terminates the
+//! process without a Rust backtrace. With the `flecs_abort_panic` feature and
+//! after calling [`enable_abort_panics()`], the C library panics instead, with
+//! a message that contains the [`FlecsErrorCode`], the message and the source
+//! location of the failure. This allows `#[should_panic]` tests and
+//! [`std::panic::catch_unwind`].
+//!
+//! The feature builds the C library with unwind support and declares its
+//! functions as unwinding, which it is not without the feature.
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::sync::Once;
+
+use super::{install_log_dispatch, take_last_fatal, update_os_api, FlecsErrorCode};
+use crate::sys;
+
+static INSTALL: Once = Once::new();
+
+std::thread_local! {
+    static LAST_ABORT: RefCell<Option<FlecsAbort>> = const { RefCell::new(None) };
+}
+
+/// Describes an abort of the C library, see [`enable_abort_panics()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlecsAbort {
+    code: Option<FlecsErrorCode>,
+    message: String,
+    file: Option<String>,
+    line: i32,
+}
+
+impl FlecsAbort {
+    /// The error code of the failure, if the C library reported a known one.
+    pub fn code(&self) -> Option<FlecsErrorCode> {
+        self.code
+    }
+
+    /// The message that the C library logged for the failure.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The C source file of the failure, if known.
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// The line in the C source file of the failure.
+    pub fn line(&self) -> i32 {
+        self.line
+    }
+}
+
+impl Display for FlecsAbort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "{code}: ")?,
+            None => write!(f, "flecs aborted: ")?,
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(file) = &self.file {
+            write!(f, " at {file}:{}", self.line)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FlecsAbort {}
+
+/// Make failed asserts and aborts of the C library panic instead of terminating
+/// the process.
+///
+/// The panic message is the [`Display`] output of [`FlecsAbort`], which starts
+/// with the error code, e.g. `ECS_INVALID_PARAMETER`. The details of the last
+/// abort on a thread can be retrieved with [`last_abort()`].
+///
+/// This is opt-in: it requires the `flecs_abort_panic` feature, and only has
+/// an effect the first time it is called. It applies to all worlds.
+///
+/// # Note
+///
+/// The C library is not exception safe. The panic unwinds through the C
+/// functions that were running, which leaves the world the failure occurred in
+/// in an inconsistent state. The world must not be used or dropped after the
+/// panic, since dropping it runs `ecs_fini` on that state. Leak it instead, with
+/// [`std::mem::forget`] or [`std::mem::ManuallyDrop`]. Aborts on threads created
+/// by the C library, and in callbacks invoked by the C library such as systems
+/// and hooks, still terminate the process.
+///
+/// # Example
+///
+/// ```
+/// # use flecs_ecs::prelude::*;
+/// enable_abort_panics();
+///
+/// let world = World::new();
+/// let result = std::panic::catch_unwind(|| {
+///     world.entity_from_id(0).add_id(world.entity());
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(
+///     last_abort().unwrap().code(),
+///     Some(FlecsErrorCode::InvalidParameter)
+/// );
+/// // the world is inconsistent after the panic and must be leaked
+/// std::mem::forget(world);
+/// ```
+pub fn enable_abort_panics() {
+    INSTALL.call_once(|| unsafe {
+        install_log_dispatch();
+        update_os_api(|api| api.abort_ = Some(abort_panic));
+    });
+}
+
+/// Returns the last abort of the C library on the current thread, see
+/// [`enable_abort_panics()`].
+pub fn last_abort() -> Option<FlecsAbort> {
+    LAST_ABORT.with(|last| last.borrow().clone())
+}
+
+unsafe extern "C-unwind" fn abort_panic() {
+    let code = std::ptr::replace(std::ptr::addr_of_mut!(sys::ecs_os_api.log_last_error_), 0);
+    let fatal = take_last_fatal();
+    let abort = FlecsAbort {
+        code: FlecsErrorCode::from_int(code),
+        message: fatal
+            .as_ref()
+            .map(|fatal| fatal.message.clone())
+            .unwrap_or_default(),
+        file: fatal.as_ref().and_then(|fatal| fatal.file.clone()),
+        line: fatal.as_ref().map_or(0, |fatal| fatal.line),
+    };
+    LAST_ABORT.with(|last| *last.borrow_mut() = Some(abort.clone()));
+    panic!("{abort}");
+}
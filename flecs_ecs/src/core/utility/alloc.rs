@@ -0,0 +1,268 @@
+//! Routing the allocations of the C library through a Rust allocator.
+//!
+//! By default the C library allocates memory with the allocator of the C
+//! runtime. [`set_os_allocator()`] replaces the memory functions of the OS API,
+//! so that all allocations go through a Rust [`GlobalAlloc`] and are counted.
+//! The counters can be queried with [`os_memory_usage()`], for example to
+//! enforce a memory budget.
+//!
+//! The OS API is process-wide, so the counters include the memory of every
+//! world in the process. They can't be attributed to a single world: the
+//! memory functions of the OS API don't receive the world they allocate for,
+//! the C library also allocates memory that isn't owned by any world, such as
+//! the OS API itself and log buffers, and a world can run on multiple threads
+//! at the same time, so the calling thread doesn't identify the world either.
+use std::alloc::{GlobalAlloc, Layout};
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use crate::sys;
+
+/// Size of the header that stores the size of an allocation. This is also the
+/// alignment of allocations, which matches the guarantees of `malloc`.
+const HEADER: usize = 16;
+
+static ALLOCATOR: OnceLock<&'static (dyn GlobalAlloc + Sync)> = OnceLock::new();
+static WORLD_CREATED: AtomicBool = AtomicBool::new(false);
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATION_COUNT: AtomicU64 = AtomicU64::new(0);
+static FREE_COUNT: AtomicU64 = AtomicU64::new(0);
+static REALLOC_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// A [`GlobalAlloc`] that forwards to the global allocator of the Rust program,
+/// which is the allocator registered with `#[global_allocator]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RustAllocator;
+
+unsafe impl GlobalAlloc for RustAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        std::alloc::alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        std::alloc::alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        std::alloc::dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        std::alloc::realloc(ptr, layout, new_size)
+    }
+}
+
+/// Memory allocated by the C library of the whole process through the allocator
+/// installed with [`set_os_allocator()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The number of bytes that are currently allocated.
+    pub live_bytes: usize,
+    /// The highest number of bytes that were allocated at the same time.
+    pub peak_bytes: usize,
+    /// The total number of allocations.
+    pub allocation_count: u64,
+    /// The total number of deallocations.
+    pub free_count: u64,
+    /// The total number of allocations that were resized in place or moved.
+    /// A resize doesn't change the number of live allocations.
+    pub realloc_count: u64,
+}
+
+impl MemoryUsage {
+    /// The number of allocations that are currently live.
+    pub fn live_allocations(&self) -> u64 {
+        self.allocation_count.saturating_sub(self.free_count)
+    }
+}
+
+/// Route the allocations of the C library through a Rust allocator, and count
+/// the memory it uses.
+///
+/// This replaces `malloc`, `calloc`, `realloc` and `free` of the OS API, and
+/// must be called before the first [`World`](crate::core::World) is created, since memory that was
+/// allocated before can't be freed by a different allocator. The allocator can
+/// only be set once.
+///
+/// The OS API is shared by all worlds, which means that the counters include
+/// the memory of every world in the process.
+///
+/// With the `flecs_use_os_alloc` feature, the C library doesn't free the
+/// buckets of its maps one by one, so a fixed amount of memory of each
+/// destroyed world stays allocated.
+///
+/// # Arguments
+///
+/// * `allocator` - The allocator to use, for example [`RustAllocator`] or [`std::alloc::System`].
+///
+/// # Returns
+///
+/// Whether the allocator was installed. Returns false if a world was already
+/// created or an allocator was already set.
+///
+/// # Example
+///
+/// ```no_run
+/// # use flecs_ecs::prelude::*;
+/// set_os_allocator(RustAllocator);
+///
+/// let world = World::new();
+/// let usage = os_memory_usage().unwrap();
+/// assert!(usage.live_bytes > 0);
+/// ```
+///
+/// # See also
+///
+/// * [`os_memory_usage()`]
+pub fn set_os_allocator(allocator: impl GlobalAlloc + Sync + 'static) -> bool {
+    if WORLD_CREATED.load(Ordering::Acquire) || ALLOCATOR.get().is_some() {
+        return false;
+    }
+
+    let allocator: &'static (dyn GlobalAlloc + Sync) = Box::leak(Box::new(allocator));
+    if ALLOCATOR.set(allocator).is_err() {
+        return false;
+    }
+
+    unsafe {
+        super::update_os_api(|api| {
+            api.malloc_ = Some(os_malloc);
+            api.calloc_ = Some(os_calloc);
+            api.realloc_ = Some(os_realloc);
+            api.free_ = Some(os_free);
+        });
+    }
+    true
+}
+
+/// Returns the memory allocated by the C library, or `None` if no allocator was
+/// set with [`set_os_allocator()`].
+///
+/// The usage is process-wide: it includes the memory of every world, as well
+/// as memory the C library allocates outside of a world.
+///
+/// # See also
+///
+/// * [`set_os_allocator()`]
+pub fn os_memory_usage() -> Option<MemoryUsage> {
+    ALLOCATOR.get()?;
+    Some(MemoryUsage {
+        live_bytes: LIVE_BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed),
+        allocation_count: ALLOCATION_COUNT.load(Ordering::Relaxed),
+        free_count: FREE_COUNT.load(Ordering::Relaxed),
+        realloc_count: REALLOC_COUNT.load(Ordering::Relaxed),
+    })
+}
+
+/// Record that a world was created, after which the allocator can't be changed.
+pub(crate) fn on_world_created() {
+    WORLD_CREATED.store(true, Ordering::Release);
+}
+
+fn layout(size: usize) -> Option<Layout> {
+    Layout::from_size_align(size.checked_add(HEADER)?, HEADER).ok()
+}
+
+fn allocator() -> &'static (dyn GlobalAlloc + Sync) {
+    *ALLOCATOR.get().expect("allocator is not set")
+}
+
+fn track_alloc(size: usize) {
+    ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+fn track_free(size: usize) {
+    FREE_COUNT.fetch_add(1, Ordering::Relaxed);
+    LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+}
+
+fn track_realloc(old_size: usize, new_size: usize) {
+    REALLOC_COUNT.fetch_add(1, Ordering::Relaxed);
+    if new_size >= old_size {
+        let grown = new_size - old_size;
+        let live = LIVE_BYTES.fetch_add(grown, Ordering::Relaxed) + grown;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    } else {
+        LIVE_BYTES.fetch_sub(old_size - new_size, Ordering::Relaxed);
+    }
+}
+
+/// Increments one of the allocation counters of the C library, which are
+/// reported in the memory statistics of a world.
+unsafe fn count(counter: *mut i64) {
+    AtomicI64::from_ptr(counter).fetch_add(1, Ordering::Relaxed);
+}
+
+/// Writes the size to the header of an allocation, and returns the memory after the header.
+unsafe fn write_header(ptr: *mut u8, size: usize) -> *mut c_void {
+    (ptr as *mut usize).write(size);
+    ptr.add(HEADER) as *mut c_void
+}
+
+unsafe fn finish_alloc(ptr: *mut u8, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+    track_alloc(size);
+    write_header(ptr, size)
+}
+
+/// Returns the start of an allocation and its size.
+unsafe fn header(ptr: *mut c_void) -> (*mut u8, usize) {
+    let ptr = (ptr as *mut u8).sub(HEADER);
+    (ptr, (ptr as *const usize).read())
+}
+
+unsafe extern "C" fn os_malloc(size: sys::ecs_size_t) -> *mut c_void {
+    count(std::ptr::addr_of_mut!(sys::ecs_os_api_malloc_count));
+    let size = size.max(0) as usize;
+    match layout(size) {
+        Some(layout) => finish_alloc(allocator().alloc(layout), size),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn os_calloc(size: sys::ecs_size_t) -> *mut c_void {
+    count(std::ptr::addr_of_mut!(sys::ecs_os_api_calloc_count));
+    let size = size.max(0) as usize;
+    match layout(size) {
+        Some(layout) => finish_alloc(allocator().alloc_zeroed(layout), size),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn os_realloc(ptr: *mut c_void, size: sys::ecs_size_t) -> *mut c_void {
+    if ptr.is_null() {
+        return os_malloc(size);
+    }
+
+    count(std::ptr::addr_of_mut!(sys::ecs_os_api_realloc_count));
+    let size = size.max(0) as usize;
+    let Some(new_layout) = layout(size) else {
+        return std::ptr::null_mut();
+    };
+    let (old_ptr, old_size) = header(ptr);
+    let old_layout = layout(old_size).unwrap();
+    let new_ptr = allocator().realloc(old_ptr, old_layout, new_layout.size());
+    if new_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+    track_realloc(old_size, size);
+    write_header(new_ptr, size)
+}
+
+unsafe extern "C" fn os_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    count(std::ptr::addr_of_mut!(sys::ecs_os_api_free_count));
+    let (ptr, size) = header(ptr);
+    track_free(size);
+    allocator().dealloc(ptr, layout(size).unwrap());
+}
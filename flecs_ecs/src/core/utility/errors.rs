@@ -1,8 +1,11 @@
-#![doc(hidden)]
 use std::fmt::{Display, Formatter};
 
-#[doc(hidden)]
-/// Enum representing the error codes that can be used by `ecs_asserts` and `ecs_abort`
+use crate::core::{Entity, Id};
+
+/// Enum representing the error codes that can be used by `ecs_asserts` and `ecs_abort`.
+///
+/// The codes match the error codes of the C library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FlecsErrorCode {
     InvalidOperation,
     InvalidParameter,
@@ -18,6 +21,7 @@ pub enum FlecsErrorCode {
     IdInUse,
     CycleDetected,
     LeakDetected,
+    DoubleFree,
     InconsistentName,
     NameInUse,
     NotAComponent,
@@ -57,9 +61,10 @@ impl Display for FlecsErrorCode {
                 FlecsErrorCode::MissingOsApi => "ECS_MISSING_OS_API",
                 FlecsErrorCode::OperationFailed => "ECS_OPERATION_FAILED",
                 FlecsErrorCode::InvalidConversion => "ECS_INVALID_CONVERSION",
-                FlecsErrorCode::IdInUse => "ecs_id_IN_USE",
+                FlecsErrorCode::IdInUse => "ECS_ID_IN_USE",
                 FlecsErrorCode::CycleDetected => "ECS_CYCLE_DETECTED",
                 FlecsErrorCode::LeakDetected => "ECS_LEAK_DETECTED",
+                FlecsErrorCode::DoubleFree => "ECS_DOUBLE_FREE",
                 FlecsErrorCode::InconsistentName => "ECS_INCONSISTENT_NAME",
                 FlecsErrorCode::NameInUse => "ECS_NAME_IN_USE",
                 FlecsErrorCode::NotAComponent => "ECS_NOT_A_COMPONENT",
@@ -86,6 +91,7 @@ impl Display for FlecsErrorCode {
 }
 
 impl FlecsErrorCode {
+    /// Returns the error code as used by the C library.
     pub fn to_int(&self) -> i32 {
         match self {
             FlecsErrorCode::InvalidOperation => 1,
@@ -102,6 +108,7 @@ impl FlecsErrorCode {
             FlecsErrorCode::IdInUse => 12,
             FlecsErrorCode::CycleDetected => 13,
             FlecsErrorCode::LeakDetected => 14,
+            FlecsErrorCode::DoubleFree => 15,
             FlecsErrorCode::InconsistentName => 20,
             FlecsErrorCode::NameInUse => 21,
             FlecsErrorCode::NotAComponent => 22,
@@ -124,8 +131,127 @@ impl FlecsErrorCode {
             FlecsErrorCode::UnwrapFailed => 73,
         }
     }
+
+    /// Converts an error code of the C library to a [`FlecsErrorCode`].
+    ///
+    /// Returns `None` if the code is unknown.
+    pub fn from_int(code: i32) -> Option<Self> {
+        Some(match code {
+            1 => FlecsErrorCode::InvalidOperation,
+            2 => FlecsErrorCode::InvalidParameter,
+            3 => FlecsErrorCode::ConstraintViolated,
+            4 => FlecsErrorCode::OutOfMemory,
+            5 => FlecsErrorCode::OutOfRange,
+            6 => FlecsErrorCode::Unsupported,
+            7 => FlecsErrorCode::InternalError,
+            8 => FlecsErrorCode::AlreadyDefined,
+            9 => FlecsErrorCode::MissingOsApi,
+            10 => FlecsErrorCode::OperationFailed,
+            11 => FlecsErrorCode::InvalidConversion,
+            12 => FlecsErrorCode::IdInUse,
+            13 => FlecsErrorCode::CycleDetected,
+            14 => FlecsErrorCode::LeakDetected,
+            15 => FlecsErrorCode::DoubleFree,
+            20 => FlecsErrorCode::InconsistentName,
+            21 => FlecsErrorCode::NameInUse,
+            22 => FlecsErrorCode::NotAComponent,
+            23 => FlecsErrorCode::InvalidComponentSize,
+            24 => FlecsErrorCode::InvalidComponentAlignment,
+            25 => FlecsErrorCode::ComponentNotRegistered,
+            26 => FlecsErrorCode::InconsistentComponentId,
+            27 => FlecsErrorCode::InconsistentComponentAction,
+            28 => FlecsErrorCode::ModuleUndefined,
+            29 => FlecsErrorCode::MissingSymbol,
+            30 => FlecsErrorCode::AlreadyInUse,
+            40 => FlecsErrorCode::AccessViolation,
+            41 => FlecsErrorCode::ColumnIndexOutOfRange,
+            42 => FlecsErrorCode::ColumnIsNotShared,
+            43 => FlecsErrorCode::ColumnIsShared,
+            45 => FlecsErrorCode::ColumnTypeMismatch,
+            70 => FlecsErrorCode::InvalidWhileReadonly,
+            71 => FlecsErrorCode::LockedStorage,
+            72 => FlecsErrorCode::InvalidFromWorker,
+            73 => FlecsErrorCode::UnwrapFailed,
+            _ => return None,
+        })
+    }
 }
 
+/// Error returned by the fallible `try_` operations, such as
+/// [`EntityView::try_set()`](crate::core::EntityView::try_set).
+///
+/// Carries the [`FlecsErrorCode`] of the failure, together with the entity and
+/// id the operation was applied to, when known.
+///
+/// The `try_` operations check their preconditions in Rust before calling the
+/// C library, and only return the errors listed in their documentation. They
+/// don't catch asserts of the C library: a failure that isn't checked up
+/// front, such as a panic in a hook or observer, or an operation on a world
+/// that is being deleted, still panics or aborts like the operation without
+/// the `try_` prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlecsError {
+    code: FlecsErrorCode,
+    entity: Option<Entity>,
+    id: Option<Id>,
+    message: String,
+}
+
+impl FlecsError {
+    pub(crate) fn new(code: FlecsErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            entity: None,
+            id: None,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn with_entity(mut self, entity: impl Into<Entity>) -> Self {
+        self.entity = Some(entity.into());
+        self
+    }
+
+    pub(crate) fn with_id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// The error code of the failure.
+    pub fn code(&self) -> FlecsErrorCode {
+        self.code
+    }
+
+    /// The entity the operation was applied to, if any.
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// The id (component, pair or entity) involved in the operation, if any.
+    pub fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    /// Description of what went wrong.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for FlecsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)?;
+        match (self.entity, self.id) {
+            (Some(entity), Some(id)) => write!(f, " (entity: {entity}, id: {id})"),
+            (Some(entity), None) => write!(f, " (entity: {entity})"),
+            (None, Some(id)) => write!(f, " (id: {id})"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for FlecsError {}
+
 /// Macro to assert a condition.
 /// In release mode, the condition is not checked.
 /// Can be turned off by disabling the `flecs_ecs_asserts` feature
@@ -485,3 +485,16 @@ pub(crate) fn strip_prefix_str_raw<'a>(str: &'a str, prefix: &str) -> Option<&'a
         None
     }
 }
+
+/// Updates the OS API of the C library.
+///
+/// The update is applied whether or not the OS API is already initialized, and
+/// the defaults are set first so they don't overwrite the update when the first
+/// world is created.
+pub(crate) unsafe fn update_os_api(update: impl Fn(&mut sys::ecs_os_api_t)) {
+    sys::ecs_os_set_api_defaults();
+    let mut api = sys::ecs_os_get_api();
+    update(&mut api);
+    sys::ecs_os_set_api(&mut api);
+    update(&mut *std::ptr::addr_of_mut!(sys::ecs_os_api));
+}
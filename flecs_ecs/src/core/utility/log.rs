@@ -53,3 +53,279 @@ pub fn enable_timedelta_logging(enabled: bool) {
         sys::ecs_log_enable_timedelta(enabled);
     }
 }
+
+/// The severity of a log message of the C library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    /// A fatal error, after which the application is aborted.
+    Fatal,
+    /// An error.
+    Error,
+    /// A warning.
+    Warning,
+    /// An informational message (level 0).
+    Info,
+    /// A debug message with a verbosity level from 1 to 3.
+    Debug(i32),
+    /// A message from the journal addon (level 4 and up).
+    Journal,
+}
+
+impl LogLevel {
+    /// Converts a C log level to a [`LogLevel`].
+    pub fn from_raw(level: i32) -> Self {
+        match level {
+            i32::MIN..=-4 => LogLevel::Fatal,
+            -3 => LogLevel::Error,
+            -2 => LogLevel::Warning,
+            -1 | 0 => LogLevel::Info,
+            1..=3 => LogLevel::Debug(level),
+            _ => LogLevel::Journal,
+        }
+    }
+}
+
+/// A log message emitted by the C library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMessage {
+    /// The severity of the message.
+    pub level: LogLevel,
+    /// The C log level of the message.
+    pub raw_level: i32,
+    /// The source file that emitted the message, if known.
+    pub file: Option<String>,
+    /// The line in the source file that emitted the message.
+    pub line: i32,
+    /// The indentation of the message, which increases for nested operations.
+    pub indent: i32,
+    /// The message text.
+    pub message: String,
+}
+
+type LogHook = std::sync::Arc<dyn Fn(&LogMessage) + Send + Sync>;
+
+static LOG_HOOK: std::sync::RwLock<Option<LogHook>> = std::sync::RwLock::new(None);
+static DEFAULT_LOG: std::sync::OnceLock<sys::ecs_os_api_log_t> = std::sync::OnceLock::new();
+
+std::thread_local! {
+    static CAPTURED_LOGS: std::cell::RefCell<Vec<Vec<LogMessage>>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+    #[cfg(feature = "flecs_abort_panic")]
+    static LAST_FATAL: std::cell::RefCell<Option<LogMessage>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+unsafe extern "C" fn log_dispatch(
+    level: i32,
+    file: *const std::ffi::c_char,
+    line: i32,
+    msg: *const std::ffi::c_char,
+) {
+    let to_string = |ptr: *const std::ffi::c_char| {
+        (!ptr.is_null()).then(|| {
+            unsafe { std::ffi::CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned()
+        })
+    };
+
+    let message = LogMessage {
+        level: LogLevel::from_raw(level),
+        raw_level: level,
+        file: to_string(file),
+        line,
+        indent: unsafe { sys::ecs_os_api.log_indent_ },
+        message: to_string(msg).unwrap_or_default(),
+    };
+
+    // fatal messages precede an abort, which may want to report them
+    #[cfg(feature = "flecs_abort_panic")]
+    if message.level == LogLevel::Fatal {
+        let _ = LAST_FATAL.try_with(|last| *last.borrow_mut() = Some(message.clone()));
+    }
+
+    // capture_logs takes precedence, so that tests don't depend on the installed hook
+    let message = match CAPTURED_LOGS.try_with(|captured| {
+        let mut captured = captured.borrow_mut();
+        match captured.last_mut() {
+            Some(logs) => {
+                logs.push(message);
+                None
+            }
+            None => Some(message),
+        }
+    }) {
+        Ok(Some(message)) => message,
+        _ => return,
+    };
+
+    let hook = LOG_HOOK.read().ok().and_then(|hook| hook.clone());
+    if let Some(hook) = hook {
+        hook(&message);
+    } else if let Some(Some(default_log)) = DEFAULT_LOG.get() {
+        unsafe { default_log(level, file, line, msg) };
+    }
+}
+
+/// Route the log output of the C library through [`log_dispatch`].
+pub(crate) fn install_log_dispatch() {
+    DEFAULT_LOG.get_or_init(|| unsafe {
+        let default_log = sys::ecs_os_get_api().log_;
+        super::update_os_api(|api| api.log_ = Some(log_dispatch));
+        default_log
+    });
+}
+
+/// Take the last fatal message that was logged on the current thread.
+#[cfg(feature = "flecs_abort_panic")]
+pub(crate) fn take_last_fatal() -> Option<LogMessage> {
+    LAST_FATAL
+        .try_with(|last| last.borrow_mut().take())
+        .ok()
+        .flatten()
+}
+
+/// Forward log messages of the C library to a Rust function instead of printing
+/// them to the console.
+///
+/// Which messages are emitted is still controlled by [`set_log_level()`].
+///
+/// # Arguments
+///
+/// * `hook` - The function invoked for each log message.
+///
+/// # Example
+///
+/// ```
+/// # use flecs_ecs::prelude::*;
+/// set_log_hook(|msg: &LogMessage| {
+///     if msg.level == LogLevel::Error {
+///         // report the error
+///     }
+/// });
+/// # reset_log_hook();
+/// ```
+///
+/// # See also
+///
+/// * [`reset_log_hook()`]
+/// * [`capture_logs()`]
+pub fn set_log_hook(hook: impl Fn(&LogMessage) + Send + Sync + 'static) {
+    install_log_dispatch();
+    if let Ok(mut current) = LOG_HOOK.write() {
+        *current = Some(std::sync::Arc::new(hook));
+    }
+}
+
+/// Restore the default log output of the C library, removing a hook installed
+/// with [`set_log_hook()`] or one of the facade forwarding functions.
+pub fn reset_log_hook() {
+    if let Ok(mut current) = LOG_HOOK.write() {
+        *current = None;
+    }
+}
+
+/// Forward log messages of the C library to the [`log`](https://docs.rs/log) facade.
+///
+/// Messages are logged with the `flecs` target. Journal messages are logged at
+/// the trace level, and fatal messages at the error level.
+#[cfg(feature = "flecs_log_facade")]
+pub fn forward_logs_to_log() {
+    set_log_hook(|msg: &LogMessage| {
+        let level = match msg.level {
+            LogLevel::Fatal | LogLevel::Error => log::Level::Error,
+            LogLevel::Warning => log::Level::Warn,
+            LogLevel::Info => log::Level::Info,
+            LogLevel::Debug(_) => log::Level::Debug,
+            LogLevel::Journal => log::Level::Trace,
+        };
+        let indent = msg.indent.max(0) as usize * 2;
+        log::logger().log(
+            &log::Record::builder()
+                .args(format_args!("{:indent$}{}", "", msg.message))
+                .level(level)
+                .target("flecs")
+                .file(msg.file.as_deref())
+                .line(u32::try_from(msg.line).ok())
+                .build(),
+        );
+    });
+}
+
+/// Forward log messages of the C library to the [`tracing`](https://docs.rs/tracing) facade.
+///
+/// Messages are emitted as events with the `flecs` target, and carry the
+/// `file`, `line` and `indent` of the message as fields.
+#[cfg(feature = "flecs_log_tracing")]
+pub fn forward_logs_to_tracing() {
+    set_log_hook(|msg: &LogMessage| {
+        let file = msg.file.as_deref().unwrap_or_default();
+        macro_rules! event {
+            ($level:expr) => {
+                tracing::event!(
+                    target: "flecs",
+                    $level,
+                    file,
+                    line = msg.line,
+                    indent = msg.indent,
+                    "{}",
+                    msg.message
+                )
+            };
+        }
+        match msg.level {
+            LogLevel::Fatal | LogLevel::Error => event!(tracing::Level::ERROR),
+            LogLevel::Warning => event!(tracing::Level::WARN),
+            LogLevel::Info => event!(tracing::Level::INFO),
+            LogLevel::Debug(_) => event!(tracing::Level::DEBUG),
+            LogLevel::Journal => event!(tracing::Level::TRACE),
+        }
+    });
+}
+
+/// Run a function and collect the log messages that the C library emits on the
+/// current thread while it runs.
+///
+/// Captured messages are not forwarded to the log hook or printed. This is
+/// useful to assert on warnings and errors in tests.
+///
+/// # Returns
+///
+/// The result of the function and the captured messages.
+///
+/// # Example
+///
+/// ```
+/// # use flecs_ecs::prelude::*;
+/// let world = World::new();
+/// let (_, logs) = capture_logs(|| {
+///     world.entity();
+/// });
+/// assert!(logs.is_empty());
+/// ```
+pub fn capture_logs<R>(f: impl FnOnce() -> R) -> (R, Vec<LogMessage>) {
+    install_log_dispatch();
+
+    struct CaptureGuard;
+
+    impl Drop for CaptureGuard {
+        fn drop(&mut self) {
+            CAPTURED_LOGS.with(|captured| {
+                captured.borrow_mut().pop();
+            });
+        }
+    }
+
+    CAPTURED_LOGS.with(|captured| captured.borrow_mut().push(Vec::new()));
+    let guard = CaptureGuard;
+    let result = f();
+    let logs = CAPTURED_LOGS.with(|captured| {
+        captured
+            .borrow_mut()
+            .last_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    });
+    drop(guard);
+    (result, logs)
+}
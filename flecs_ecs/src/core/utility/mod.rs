@@ -1,15 +1,25 @@
 //! contains traits that define what a component is and also the API's for [`Query`][super::Query], [`Observer`][super::Observer] and [`System`][crate::addons::system::System].
 //! Also contains lower level utility functions on ECS IDs. This is mostly used internally by the library.
 
+#[cfg(feature = "flecs_abort_panic")]
+mod abort;
+mod alloc;
 mod errors;
 mod functions;
 mod log;
+#[cfg(feature = "flecs_os_api_rust")]
+mod os_api;
 pub mod traits;
 pub mod types;
 
+#[cfg(feature = "flecs_abort_panic")]
+pub use abort::*;
+pub use alloc::*;
 pub use errors::*;
 pub use functions::*;
 pub use log::*;
+#[cfg(feature = "flecs_os_api_rust")]
+pub use os_api::*;
 
 #[doc(hidden)]
 pub use traits::*;
@@ -0,0 +1,252 @@
+//! Rust implementation of the OS API of the C library.
+//!
+//! Threads are mapped to [`std::thread`], mutexes and condition variables to
+//! [`std::sync`], time and sleep to [`std::time`] and atomic counters to
+//! [`std::sync::atomic`]. The implementation is installed before the first world
+//! is created, which means the C implementation of the OS API is not used.
+//!
+//! Threads created by the C library are named, which makes them easy to identify
+//! in debuggers and profilers. Worker threads are named `flecs-worker-<n>` and
+//! task threads `flecs-task-<n>` by default, see [`set_thread_name_prefix()`].
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicI32, AtomicI64, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, Once, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::sys;
+
+static INSTALL: Once = Once::new();
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+static THREAD_NAME_PREFIX: RwLock<Option<String>> = RwLock::new(None);
+static THREAD_COUNT: AtomicUsize = AtomicUsize::new(0);
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+
+std::thread_local! {
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Install the Rust implementation of the OS API.
+///
+/// This is called automatically when a [`World`](crate::core::World) is created,
+/// and only has an effect the first time it is called. The OS API can't be
+/// changed once a world exists, so applications that replace parts of the OS
+/// API themselves should call this first.
+pub fn init_os_api() {
+    INSTALL.call_once(|| unsafe {
+        EPOCH.get_or_init(Instant::now);
+        super::update_os_api(|api| {
+            api.thread_new_ = Some(thread_new);
+            api.thread_join_ = Some(thread_join);
+            api.thread_self_ = Some(thread_self);
+            api.task_new_ = Some(task_new);
+            api.task_join_ = Some(thread_join);
+            api.ainc_ = Some(ainc);
+            api.adec_ = Some(adec);
+            api.lainc_ = Some(lainc);
+            api.ladec_ = Some(ladec);
+            api.mutex_new_ = Some(mutex_new);
+            api.mutex_free_ = Some(mutex_free);
+            api.mutex_lock_ = Some(mutex_lock);
+            api.mutex_unlock_ = Some(mutex_unlock);
+            api.cond_new_ = Some(cond_new);
+            api.cond_free_ = Some(cond_free);
+            api.cond_signal_ = Some(cond_signal);
+            api.cond_broadcast_ = Some(cond_broadcast);
+            api.cond_wait_ = Some(cond_wait);
+            api.sleep_ = Some(sleep);
+            api.now_ = Some(now);
+            api.get_time_ = Some(get_time);
+        });
+    });
+}
+
+/// Set the prefix of the names of threads created by the C library.
+///
+/// Worker threads are named `<prefix>-worker-<n>` and task threads
+/// `<prefix>-task-<n>`. The default prefix is `flecs`. Only affects threads
+/// that are created after the prefix is set.
+///
+/// # Arguments
+///
+/// * `prefix` - The prefix of the thread names.
+pub fn set_thread_name_prefix(prefix: impl Into<String>) {
+    if let Ok(mut current) = THREAD_NAME_PREFIX.write() {
+        *current = Some(prefix.into());
+    }
+}
+
+fn thread_name(kind: &str) -> String {
+    let index = THREAD_COUNT.fetch_add(1, Ordering::Relaxed);
+    let prefix = THREAD_NAME_PREFIX.read().ok();
+    let prefix = prefix
+        .as_ref()
+        .and_then(|p| p.as_deref())
+        .unwrap_or("flecs");
+    format!("{prefix}-{kind}-{index}")
+}
+
+/// A pointer that is passed to the thread callback.
+struct ThreadParam(*mut c_void);
+
+// SAFETY: the C library hands the parameter over to the new thread.
+unsafe impl Send for ThreadParam {}
+
+fn spawn(kind: &str, callback: sys::ecs_os_thread_callback_t, param: *mut c_void) -> usize {
+    let Some(callback) = callback else {
+        return 0;
+    };
+    let param = ThreadParam(param);
+    let handle = std::thread::Builder::new()
+        .name(thread_name(kind))
+        .spawn(move || {
+            let param = param;
+            unsafe { callback(param.0) as usize }
+        })
+        .expect("failed to spawn thread");
+    Box::into_raw(Box::new(handle)) as usize
+}
+
+unsafe extern "C" fn thread_new(
+    callback: sys::ecs_os_thread_callback_t,
+    param: *mut c_void,
+) -> sys::ecs_os_thread_t {
+    spawn("worker", callback, param)
+}
+
+unsafe extern "C" fn task_new(
+    callback: sys::ecs_os_thread_callback_t,
+    param: *mut c_void,
+) -> sys::ecs_os_thread_t {
+    spawn("task", callback, param)
+}
+
+unsafe extern "C" fn thread_join(thread: sys::ecs_os_thread_t) -> *mut c_void {
+    if thread == 0 {
+        return std::ptr::null_mut();
+    }
+    let handle = Box::from_raw(thread as *mut std::thread::JoinHandle<usize>);
+    handle.join().unwrap_or_default() as *mut c_void
+}
+
+unsafe extern "C" fn thread_self() -> sys::ecs_os_thread_id_t {
+    THREAD_ID.with(|id| *id)
+}
+
+unsafe extern "C" fn ainc(value: *mut i32) -> i32 {
+    AtomicI32::from_ptr(value).fetch_add(1, Ordering::AcqRel) + 1
+}
+
+unsafe extern "C" fn adec(value: *mut i32) -> i32 {
+    AtomicI32::from_ptr(value).fetch_sub(1, Ordering::AcqRel) - 1
+}
+
+unsafe extern "C" fn lainc(value: *mut i64) -> i64 {
+    AtomicI64::from_ptr(value).fetch_add(1, Ordering::AcqRel) + 1
+}
+
+unsafe extern "C" fn ladec(value: *mut i64) -> i64 {
+    AtomicI64::from_ptr(value).fetch_sub(1, Ordering::AcqRel) - 1
+}
+
+/// A mutex that can be locked and unlocked from C, without holding on to a guard.
+#[derive(Default)]
+struct OsMutex {
+    locked: Mutex<bool>,
+    unlocked: Condvar,
+}
+
+impl OsMutex {
+    fn state(&self) -> MutexGuard<'_, bool> {
+        self.locked.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn acquire<'a>(&'a self, mut locked: MutexGuard<'a, bool>) {
+        while *locked {
+            locked = self
+                .unlocked
+                .wait(locked)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        *locked = true;
+    }
+
+    fn release(&self, locked: &mut MutexGuard<'_, bool>) {
+        **locked = false;
+        self.unlocked.notify_one();
+    }
+}
+
+unsafe fn os_mutex<'a>(mutex: sys::ecs_os_mutex_t) -> &'a OsMutex {
+    &*(mutex as *const OsMutex)
+}
+
+unsafe extern "C" fn mutex_new() -> sys::ecs_os_mutex_t {
+    Box::into_raw(Box::<OsMutex>::default()) as usize
+}
+
+unsafe extern "C" fn mutex_free(mutex: sys::ecs_os_mutex_t) {
+    drop(Box::from_raw(mutex as *mut OsMutex));
+}
+
+unsafe extern "C" fn mutex_lock(mutex: sys::ecs_os_mutex_t) {
+    let mutex = os_mutex(mutex);
+    mutex.acquire(mutex.state());
+}
+
+unsafe extern "C" fn mutex_unlock(mutex: sys::ecs_os_mutex_t) {
+    let mutex = os_mutex(mutex);
+    mutex.release(&mut mutex.state());
+}
+
+unsafe fn os_cond<'a>(cond: sys::ecs_os_cond_t) -> &'a Condvar {
+    &*(cond as *const Condvar)
+}
+
+unsafe extern "C" fn cond_new() -> sys::ecs_os_cond_t {
+    Box::into_raw(Box::new(Condvar::new())) as usize
+}
+
+unsafe extern "C" fn cond_free(cond: sys::ecs_os_cond_t) {
+    drop(Box::from_raw(cond as *mut Condvar));
+}
+
+unsafe extern "C" fn cond_signal(cond: sys::ecs_os_cond_t) {
+    os_cond(cond).notify_one();
+}
+
+unsafe extern "C" fn cond_broadcast(cond: sys::ecs_os_cond_t) {
+    os_cond(cond).notify_all();
+}
+
+unsafe extern "C" fn cond_wait(cond: sys::ecs_os_cond_t, mutex: sys::ecs_os_mutex_t) {
+    let cond = os_cond(cond);
+    let mutex = os_mutex(mutex);
+
+    // The state lock is held from releasing the mutex until waiting on the
+    // condition, so a thread that signals while holding the mutex can't do so
+    // before this thread waits.
+    let mut state = mutex.state();
+    mutex.release(&mut state);
+    let state = cond.wait(state).unwrap_or_else(|e| e.into_inner());
+    mutex.acquire(state);
+}
+
+unsafe extern "C" fn sleep(sec: i32, nanosec: i32) {
+    std::thread::sleep(Duration::new(sec.max(0) as u64, nanosec.max(0) as u32));
+}
+
+fn elapsed() -> Duration {
+    EPOCH.get_or_init(Instant::now).elapsed()
+}
+
+unsafe extern "C" fn now() -> u64 {
+    elapsed().as_nanos() as u64
+}
+
+unsafe extern "C" fn get_time(time_out: *mut sys::ecs_time_t) {
+    let elapsed = elapsed();
+    *time_out = sys::ecs_time_t {
+        sec: elapsed.as_secs() as u32,
+        nanosec: elapsed.subsec_nanos(),
+    };
+}
@@ -16,7 +16,11 @@ pub use query_api::*;
 pub use system_api::*;
 pub use world_provider::*;
 
+use crate::core::lifecycle_traits::{
+    register_copy_lifecycle_action, register_ctor_lifecycle_actions,
+};
 use crate::core::{ImplementsClone, ImplementsDefault};
+use crate::sys;
 
 #[doc(hidden)]
 pub mod private {
@@ -272,18 +276,33 @@ pub mod private {
     }
 }
 
+/// Fallback of [`ImplementsClone`] and [`ImplementsDefault`] for types that don't implement the
+/// trait, or of which it can't be proven, such as generic types without the trait as bound.
 pub trait DoesNotImpl {
     const IMPLS: bool = false;
+
+    /// Registers no hooks, as the type doesn't implement the trait.
+    fn register_hooks(_type_hooks: &mut sys::ecs_type_hooks_t) {}
 }
 
 impl<T> DoesNotImpl for T {}
 
 impl<T: Clone> ImplementsClone<T> {
     pub const IMPLS: bool = true;
+
+    /// Registers the copy hooks of the type.
+    pub fn register_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        register_copy_lifecycle_action::<T>(type_hooks);
+    }
 }
 
 impl<T: Default> ImplementsDefault<T> {
     pub const IMPLS: bool = true;
+
+    /// Registers the constructor hook of the type.
+    pub fn register_hooks(type_hooks: &mut sys::ecs_type_hooks_t) {
+        register_ctor_lifecycle_actions::<T>(type_hooks);
+    }
 }
 
 pub trait FlecsConstantId {
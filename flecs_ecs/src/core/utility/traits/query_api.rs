@@ -14,7 +14,7 @@ pub trait IterOperations {
     fn iter_next(&self, iter: &mut sys::ecs_iter_t) -> bool;
 
     #[doc(hidden)]
-    fn iter_next_func(&self) -> unsafe extern "C" fn(*mut sys::ecs_iter_t) -> bool;
+    fn iter_next_func(&self) -> sys::ecs_iter_next_fn_t;
 
     #[doc(hidden)]
     fn query_ptr(&self) -> *const sys::ecs_query_t;
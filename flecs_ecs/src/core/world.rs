@@ -57,6 +57,10 @@ unsafe impl Send for World {}
 
 impl Default for World {
     fn default() -> Self {
+        #[cfg(feature = "flecs_os_api_rust")]
+        crate::core::init_os_api();
+        crate::core::utility::on_world_created();
+
         let raw_world = NonNull::new(unsafe { sys::ecs_init() }).unwrap();
         let ctx = Box::leak(Box::new(WorldCtx::new()));
         let components = unsafe { NonNull::new_unchecked(&mut ctx.components) };
@@ -1611,6 +1615,32 @@ impl World {
         self.try_lookup_impl(name, false)
     }
 
+    /// Lookup an entity by its path, returning an error if it doesn't exist.
+    ///
+    /// The path is relative to the current scope, and uses `::` as separator.
+    /// Prefix the path with `::` to look it up from the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the entity to lookup.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsErrorCode::InvalidParameter`] if no entity with the path exists.
+    ///
+    /// # See also
+    ///
+    /// * [`World::lookup()`]
+    /// * [`World::try_lookup()`]
+    pub fn try_lookup_path(&self, path: &str) -> Result<EntityView<'_>, FlecsError> {
+        self.try_lookup_impl(path, false).ok_or_else(|| {
+            FlecsError::new(
+                FlecsErrorCode::InvalidParameter,
+                format!("no entity found with path '{path}'"),
+            )
+        })
+    }
+
     /// Sets a singleton component of type `T` on the world.
     ///
     /// # Arguments
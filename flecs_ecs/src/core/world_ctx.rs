@@ -6,6 +6,10 @@ pub(crate) struct WorldCtx {
     pub(crate) components: FlecsIdMap,
     pub(crate) components_array: FlecsArray,
     is_panicking: bool,
+    #[cfg(feature = "flecs_rest")]
+    pub(crate) rest_server: *mut sys::ecs_http_server_t,
+    #[cfg(feature = "flecs_journal")]
+    pub(crate) journal: Option<Box<crate::addons::journal::JournalRecorder>>,
 }
 
 impl WorldCtx {
@@ -15,6 +19,10 @@ impl WorldCtx {
             components: Default::default(),
             components_array: vec![0; 500],
             is_panicking: false,
+            #[cfg(feature = "flecs_rest")]
+            rest_server: std::ptr::null_mut(),
+            #[cfg(feature = "flecs_journal")]
+            journal: None,
         }
     }
 
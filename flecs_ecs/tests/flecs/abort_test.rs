@@ -0,0 +1,38 @@
+use crate::common_test::*;
+
+#[test]
+#[should_panic(expected = "ECS_ALREADY_DEFINED")]
+fn abort_panic_should_panic() {
+    enable_abort_panics();
+
+    // the world is inconsistent after the panic, so it is leaked instead of
+    // being dropped while unwinding
+    let world = std::mem::ManuallyDrop::new(World::new());
+    world.entity_named("a");
+    world.entity().set_name("a");
+}
+
+#[test]
+fn abort_panic_catch_unwind() {
+    enable_abort_panics();
+
+    let world = World::new();
+    let tag = world.entity();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.entity_from_id(0).add_id(tag);
+    }));
+
+    let payload = result.unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("ECS_INVALID_PARAMETER"));
+
+    let abort = last_abort().unwrap();
+    assert_eq!(abort.code(), Some(FlecsErrorCode::InvalidParameter));
+    assert!(abort.message().starts_with("assert:"));
+    assert!(abort.file().unwrap().ends_with(".c"));
+    assert!(abort.line() > 0);
+    assert_eq!(message, &abort.to_string());
+
+    std::mem::forget(world);
+}
@@ -0,0 +1,247 @@
+#![allow(clippy::float_cmp)]
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::rc::Rc;
+
+use flecs_ecs::prelude::alerts::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+struct Velocity {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+#[meta]
+struct Health {
+    value: f32,
+}
+
+#[derive(Component)]
+struct Production;
+
+#[derive(Component)]
+struct Dangerous;
+
+#[test]
+fn alerts_query_condition() {
+    let world = World::new();
+    world.import::<Alerts>();
+
+    let alert = world
+        .alert_named::<&Position>("position_without_velocity")
+        .without::<Velocity>()
+        .message("$this has Position but not Velocity")
+        .severity(AlertSeverity::Warning)
+        .build();
+
+    assert_eq!(alert.severity(), AlertSeverity::Warning);
+
+    let e1 = world.entity_named("e1").set(Position { x: 0.0, y: 0.0 });
+    let e2 = world
+        .entity_named("e2")
+        .set(Position { x: 0.0, y: 0.0 })
+        .set(Velocity { x: 1.0, y: 1.0 });
+
+    world.progress_time(1.0);
+    world.progress_time(1.0);
+
+    assert_eq!(alert.instance_count(), 1);
+    assert_eq!(e1.alert_count(), 1);
+    assert!(e1.has_alert(alert));
+    assert_eq!(e2.alert_count(), 0);
+    assert!(e2.try_alert(alert).is_none());
+
+    let instance = e1.try_alert(alert).unwrap();
+    assert_eq!(instance.source(), e1);
+    assert_eq!(instance.alert().id(), alert.id());
+    assert_eq!(instance.severity(), AlertSeverity::Warning);
+    assert_eq!(
+        instance.message().unwrap(),
+        "e1 has Position but not Velocity"
+    );
+
+    let mut count = 0;
+    e1.each_alert(|instance| {
+        assert_eq!(instance.alert().id(), alert.id());
+        count += 1;
+    });
+    assert_eq!(count, 1);
+
+    e1.set(Velocity { x: 1.0, y: 1.0 });
+    world.progress_time(1.0);
+
+    assert_eq!(alert.instance_count(), 0);
+    assert_eq!(e1.alert_count(), 0);
+}
+
+#[test]
+fn alerts_severity_filter() {
+    let world = World::new();
+    world.import::<Alerts>();
+
+    let alert = world
+        .alert::<&Position>()
+        .without::<Velocity>()
+        .severity_filter::<Production>(AlertSeverity::Critical)
+        .build();
+
+    let e1 = world.entity().set(Position { x: 0.0, y: 0.0 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 0.0, y: 0.0 })
+        .add::<Production>();
+
+    world.progress_time(1.0);
+
+    assert_eq!(
+        e1.try_alert(alert).unwrap().severity(),
+        AlertSeverity::Error
+    );
+    assert_eq!(
+        e2.try_alert(alert).unwrap().severity(),
+        AlertSeverity::Critical
+    );
+}
+
+#[test]
+fn alerts_member_range() {
+    let world = World::new();
+    world.import::<Alerts>();
+
+    world
+        .component::<Health>()
+        .meta()
+        .warning_range(50.0, 100.0)
+        .error_range(20.0, 100.0);
+
+    let alert = world.alert::<&Health>().member::<Health>("value").build();
+
+    let e1 = world.entity().set(Health { value: 100.0 });
+    let e2 = world.entity().set(Health { value: 30.0 });
+    let e3 = world.entity().set(Health { value: 10.0 });
+
+    world.progress_time(1.0);
+
+    assert_eq!(e1.alert_count(), 0);
+    assert_eq!(
+        e2.try_alert(alert).unwrap().severity(),
+        AlertSeverity::Warning
+    );
+    assert_eq!(
+        e3.try_alert(alert).unwrap().severity(),
+        AlertSeverity::Error
+    );
+}
+
+#[test]
+fn alerts_on_start_on_clear() {
+    let world = World::new();
+    world.import::<Alerts>();
+
+    let alert = world.alert::<&Position>().without::<Velocity>().build();
+
+    let started = Rc::new(RefCell::new(Vec::new()));
+    let cleared = Rc::new(RefCell::new(Vec::new()));
+
+    let started_clone = started.clone();
+    alert.on_start(move |instance| started_clone.borrow_mut().push(instance.source().id()));
+    let cleared_clone = cleared.clone();
+    alert.on_clear(move |instance| cleared_clone.borrow_mut().push(instance.source().id()));
+
+    let e = world.entity().set(Position { x: 0.0, y: 0.0 });
+
+    world.progress_time(1.0);
+
+    assert_eq!(*started.borrow(), vec![e.id()]);
+    assert!(cleared.borrow().is_empty());
+
+    e.set(Velocity { x: 1.0, y: 1.0 });
+    world.progress_time(1.0);
+
+    assert_eq!(*started.borrow(), vec![e.id()]);
+    assert_eq!(*cleared.borrow(), vec![e.id()]);
+}
+
+#[test]
+fn alerts_short_strings() {
+    let world = World::new();
+    world.import::<Alerts>();
+
+    world
+        .component::<Health>()
+        .meta()
+        .warning_range(50.0, 100.0)
+        .error_range(20.0, 100.0);
+
+    let filter_alert = world
+        .alert::<&Position>()
+        .with::<flecs::ChildOf>()
+        .set_second_name("$p")
+        .severity_filter_var(
+            AlertSeverity::Critical,
+            world.component_id::<Dangerous>(),
+            "p",
+        )
+        .message("$this")
+        .brief("Low hp")
+        .doc_name("Hp")
+        .build();
+
+    let member_alert = world
+        .alert::<&Position>()
+        .with::<flecs::ChildOf>()
+        .set_second_name("$p")
+        .with::<&Health>()
+        .set_src_name("$p")
+        .member::<Health>("value")
+        .member_var("p")
+        .build();
+
+    let brief = unsafe { flecs_ecs::sys::ecs_doc_get_brief(world.ptr_mut(), *filter_alert.id()) };
+    assert_eq!(unsafe { CStr::from_ptr(brief) }.to_str().unwrap(), "Low hp");
+    let doc_name = unsafe { flecs_ecs::sys::ecs_doc_get_name(world.ptr_mut(), *filter_alert.id()) };
+    assert_eq!(unsafe { CStr::from_ptr(doc_name) }.to_str().unwrap(), "Hp");
+
+    let parent = world
+        .entity_named("p")
+        .set(Health { value: 30.0 })
+        .add::<Dangerous>();
+    let e = world
+        .entity_named("e")
+        .set(Position { x: 0.0, y: 0.0 })
+        .child_of_id(parent);
+
+    world.progress_time(1.0);
+
+    let instance = e.try_alert(filter_alert).unwrap();
+    assert_eq!(instance.severity(), AlertSeverity::Critical);
+    assert_eq!(instance.message().unwrap(), "p.e");
+    assert_eq!(
+        e.try_alert(member_alert).unwrap().severity(),
+        AlertSeverity::Warning
+    );
+}
+
+#[test]
+fn alerts_import_in_multiple_worlds() {
+    for _ in 0..2 {
+        let world = World::new();
+        world.import::<Alerts>();
+
+        let alert = world.alert::<&Position>().without::<Velocity>().build();
+        let e = world.entity().set(Position { x: 0.0, y: 0.0 });
+
+        world.progress_time(1.0);
+
+        assert_eq!(alert.instance_count(), 1);
+        assert!(e.has_alert(alert));
+    }
+}
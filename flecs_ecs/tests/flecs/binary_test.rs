@@ -0,0 +1,220 @@
+#![allow(clippy::float_cmp)]
+use flecs_ecs::prelude::*;
+
+use crate::common_test::create_world_with_meta;
+
+#[derive(Debug, Component, PartialEq)]
+#[repr(C)]
+#[meta]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Component, PartialEq)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Component, PartialEq)]
+#[meta]
+struct Character {
+    name: String,
+    health: i32,
+    alive: bool,
+    color: Color,
+    inventory: Vec<i32>,
+    target: Entity,
+}
+
+#[derive(Component)]
+struct Unreflected {
+    value: i32,
+}
+
+#[derive(Component)]
+struct Likes;
+
+#[derive(Component)]
+struct Tag;
+
+fn to_binary(world: &World) -> Vec<u8> {
+    let mut data = Vec::new();
+    world.to_binary_world(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn binary_round_trip_world() {
+    let world = create_world_with_meta::<(Color, Position, Character)>();
+
+    let enemy = world.entity_named("enemy").set(Position { x: 1.0, y: 2.0 });
+    let parent = world.entity_named("parent");
+    world
+        .entity_named("child")
+        .child_of_id(parent)
+        .set(Character {
+            name: "Bob".to_string(),
+            health: 100,
+            alive: true,
+            color: Color::Blue,
+            inventory: vec![1, 2, 3],
+            target: enemy.id(),
+        })
+        .set(Unreflected { value: 10 })
+        .add_first::<Likes>(enemy);
+    world
+        .entity()
+        .child_of_id(parent)
+        .add::<Tag>()
+        .set(Position { x: 3.0, y: 4.0 });
+
+    let data = to_binary(&world);
+
+    let world2 = create_world_with_meta::<(Color, Position, Character)>();
+    // Ids are resolved by name, so the tags must exist in the loading world
+    world2.component::<Likes>();
+    world2.component::<Tag>();
+    let loaded = world2.from_binary(data.as_slice()).unwrap();
+    assert_eq!(loaded.len(), 4);
+
+    let enemy2 = world2.lookup("enemy");
+    assert_ne!(enemy2.id(), Entity::null());
+    enemy2.get::<&Position>(|pos| assert_eq!(*pos, Position { x: 1.0, y: 2.0 }));
+
+    let child = world2.lookup("parent::child");
+    assert!(child.has_first::<Likes>(enemy2));
+    assert!(!child.has::<Unreflected>());
+    child.get::<&Character>(|character| {
+        assert_eq!(
+            *character,
+            Character {
+                name: "Bob".to_string(),
+                health: 100,
+                alive: true,
+                color: Color::Blue,
+                inventory: vec![1, 2, 3],
+                target: enemy2.id(),
+            }
+        );
+    });
+
+    let parent2 = world2.lookup("parent");
+    let mut tagged = Vec::new();
+    parent2.each_child(|e| {
+        if e.has::<Tag>() {
+            tagged.push(e.id());
+        }
+    });
+    assert_eq!(tagged.len(), 1);
+    world2
+        .entity_from_id(tagged[0])
+        .get::<&Position>(|pos| assert_eq!(*pos, Position { x: 3.0, y: 4.0 }));
+}
+
+#[test]
+fn binary_reuse_named_entities() {
+    let world = create_world_with_meta::<(Color, Position, Character)>();
+    world.entity_named("e").set(Position { x: 1.0, y: 2.0 });
+    let data = to_binary(&world);
+
+    world
+        .lookup("e")
+        .set(Position { x: 5.0, y: 6.0 })
+        .add::<Tag>();
+    let loaded = world.from_binary(data.as_slice()).unwrap();
+
+    assert_eq!(loaded, vec![world.lookup("e").id()]);
+    world
+        .lookup("e")
+        .get::<&Position>(|pos| assert_eq!(*pos, Position { x: 1.0, y: 2.0 }));
+}
+
+#[test]
+fn binary_entities_subset() {
+    let world = create_world_with_meta::<(Color, Position, Character)>();
+    let enemy = world.entity_named("enemy");
+    let a = world.entity_named("a").set(Character {
+        name: "Alice".to_string(),
+        health: 1,
+        alive: false,
+        color: Color::Green,
+        inventory: vec![],
+        target: enemy.id(),
+    });
+    world.entity_named("b").set(Position { x: 1.0, y: 1.0 });
+
+    let mut data = Vec::new();
+    world.to_binary_entities([a], &mut data).unwrap();
+
+    // referenced entities that are not stored must exist
+    let world2 = create_world_with_meta::<(Color, Position, Character)>();
+    let err = world2.from_binary(data.as_slice()).unwrap_err();
+    assert!(matches!(err, BinaryError::Mismatch(_)));
+    assert!(err.to_string().contains("unresolved entity 'enemy'"));
+
+    let world3 = create_world_with_meta::<(Color, Position, Character)>();
+    let enemy3 = world3.entity_named("enemy");
+    let loaded = world3.from_binary(data.as_slice()).unwrap();
+    assert_eq!(loaded.len(), 1);
+    assert!(world3.try_lookup("b").is_none());
+    world3
+        .entity_from_id(loaded[0])
+        .get::<&Character>(|character| {
+            assert_eq!(character.name, "Alice");
+            assert_eq!(character.color, Color::Green);
+            assert_eq!(character.target, enemy3.id());
+        });
+}
+
+#[test]
+fn binary_schema_mismatch() {
+    #[derive(Component)]
+    #[meta]
+    struct PointA {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component)]
+    #[meta]
+    struct PointB {
+        x: f32,
+        z: f32,
+    }
+
+    let world = World::new();
+    world.component_named::<PointA>("Point").meta();
+    world.entity().set(PointA { x: 1.0, y: 2.0 });
+    let data = to_binary(&world);
+
+    let world2 = World::new();
+    world2.component_named::<PointB>("Point").meta();
+    let err = world2.from_binary(data.as_slice()).unwrap_err();
+    assert!(matches!(err, BinaryError::Mismatch(_)));
+    assert!(err.to_string().contains("type 'Point' differs"));
+}
+
+#[test]
+fn binary_invalid_data() {
+    let world = create_world_with_meta::<(Color, Position, Character)>();
+    world.entity_named("e").set(Position { x: 1.0, y: 2.0 });
+    let data = to_binary(&world);
+
+    let world2 = create_world_with_meta::<(Color, Position, Character)>();
+    let err = world2.from_binary(&b"not binary data"[..]).unwrap_err();
+    assert!(matches!(err, BinaryError::InvalidData(_)));
+
+    let err = world2.from_binary(&data[..data.len() - 1]).unwrap_err();
+    assert!(err.to_string().contains("unexpected end of data"));
+    assert!(world2.try_lookup("e").is_none());
+
+    let mut trailing = data.clone();
+    trailing.push(0);
+    let err = world2.from_binary(trailing.as_slice()).unwrap_err();
+    assert!(matches!(err, BinaryError::InvalidData(_)));
+    assert!(world2.try_lookup("e").is_none());
+}
@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use flecs_ecs::core::{ComponentInfo, World};
+use flecs_ecs::core::{ComponentId, ComponentInfo, World};
 use flecs_ecs_derive::Component;
 
 // normal structs
@@ -26,6 +26,26 @@ struct CloneDefaultDrop {
     data: String,
 }
 
+// generic structs
+#[derive(Default, Clone, Component)]
+struct GenericCloneDefault<T: Default + Clone + Send + Sync> {
+    value: T,
+}
+
+#[derive(Default, Clone, Component)]
+struct GenericWhereCloneDefault<T>
+where
+    T: Default + Clone + Send + Sync,
+{
+    value: T,
+}
+
+// the impls can't be detected without the traits as bound
+#[derive(Default, Clone, Component)]
+struct GenericUnbounded<T: Send + Sync> {
+    value: T,
+}
+
 #[test]
 fn compile_time_check_impls_clone_default() {
     // we do it this way to avoid the warning of constant bools getting optimized away from clippy in test cases.
@@ -33,11 +53,19 @@ fn compile_time_check_impls_clone_default() {
     let clone_default = CloneDefault::IMPLS_CLONE && CloneDefault::IMPLS_DEFAULT;
     let clone_no_default = CloneNoDefault::IMPLS_CLONE && !CloneNoDefault::IMPLS_DEFAULT;
     let default_no_clone = !DefaultNoClone::IMPLS_CLONE && DefaultNoClone::IMPLS_DEFAULT;
+    let generic_clone_default = GenericCloneDefault::<i32>::IMPLS_CLONE
+        && GenericCloneDefault::<i32>::IMPLS_DEFAULT
+        && GenericWhereCloneDefault::<String>::IMPLS_CLONE
+        && GenericWhereCloneDefault::<String>::IMPLS_DEFAULT;
+    let generic_unbounded =
+        !GenericUnbounded::<i32>::IMPLS_CLONE && !GenericUnbounded::<i32>::IMPLS_DEFAULT;
 
     assert!(none_clone_default);
     assert!(clone_default);
     assert!(clone_no_default);
     assert!(default_no_clone);
+    assert!(generic_clone_default);
+    assert!(generic_unbounded);
 }
 
 #[test]
@@ -70,3 +98,73 @@ fn copy_hook_not_implemented_for_drop_types() {
 
     let _entity_cloned = e_orig.duplicate(true); // PANICS
 }
+
+#[test]
+fn generic_hooks_registered_per_instantiation() {
+    let world = World::new();
+    let e_orig = world
+        .entity()
+        .add::<GenericCloneDefault<i32>>()
+        .add::<GenericCloneDefault<String>>()
+        .set(GenericWhereCloneDefault {
+            value: "data".to_string(),
+        });
+
+    e_orig.get::<(&GenericCloneDefault<i32>, &GenericCloneDefault<String>)>(|(a, b)| {
+        assert_eq!(a.value, 0);
+        assert!(b.value.is_empty());
+    });
+
+    e_orig.get::<&mut GenericCloneDefault<String>>(|g| g.value = "value".to_string());
+    let entity_cloned = e_orig.duplicate(true);
+
+    entity_cloned.get::<(
+        &GenericCloneDefault<String>,
+        &GenericWhereCloneDefault<String>,
+    )>(|(a, b)| {
+        assert_eq!(a.value, "value");
+        assert_eq!(b.value, "data");
+    });
+}
+
+#[test]
+fn generic_hooks_prefab_instancing() {
+    let world = World::new();
+    world
+        .component::<GenericCloneDefault<String>>()
+        .add_trait::<(
+            flecs_ecs::core::flecs::OnInstantiate,
+            flecs_ecs::core::flecs::Override,
+        )>();
+
+    let prefab = world.prefab().set(GenericCloneDefault {
+        value: "prefab".to_string(),
+    });
+    let instance = world.entity().is_a_id(prefab);
+
+    assert!(instance.owns::<GenericCloneDefault<String>>());
+    instance.get::<&mut GenericCloneDefault<String>>(|g| g.value.push('!'));
+
+    prefab.get::<&GenericCloneDefault<String>>(|g| assert_eq!(g.value, "prefab"));
+    instance.get::<&GenericCloneDefault<String>>(|g| assert_eq!(g.value, "prefab!"));
+}
+
+#[test]
+fn generic_unbounded_hooks_registered_manually() {
+    let world = World::new();
+    GenericUnbounded::<String>::register_ctor_hook(&world);
+    GenericUnbounded::<String>::register_clone_hook(&world);
+
+    let e_orig = world
+        .entity()
+        .add_id(world.component::<GenericUnbounded<String>>())
+        .set(GenericUnbounded::<i32> { value: 10 });
+    e_orig.get::<&GenericUnbounded<String>>(|g| assert!(g.value.is_empty()));
+    e_orig.get::<&mut GenericUnbounded<String>>(|g| g.value = "value".to_string());
+    let entity_cloned = e_orig.duplicate(true);
+
+    entity_cloned.get::<(&GenericUnbounded<String>, &GenericUnbounded<i32>)>(|(a, b)| {
+        assert_eq!(a.value, "value");
+        assert_eq!(b.value, 10);
+    });
+}
@@ -308,3 +308,33 @@ pub fn create_world_with_flags<T: ComponentId + Default + DataComponent + Compon
 
     world
 }
+
+/// A tuple of components for [`create_world_with_meta`].
+pub trait MetaComponents {
+    fn register_meta(world: &World);
+}
+
+macro_rules! impl_meta_components {
+    ($($t:ident),+) => {
+        impl<$($t: ComponentId<UnderlyingType = $t> + flecs_ecs::addons::Meta<$t>),+> MetaComponents
+            for ($($t,)+)
+        {
+            fn register_meta(world: &World) {
+                $(world.component::<$t>().meta();)+
+            }
+        }
+    };
+}
+
+impl_meta_components!(A);
+impl_meta_components!(A, B);
+impl_meta_components!(A, B, C);
+impl_meta_components!(A, B, C, D);
+
+/// Create a world with the reflection data of the components in `T`, which is
+/// a tuple such as `(Position, Velocity)`.
+pub fn create_world_with_meta<T: MetaComponents>() -> World {
+    let world = World::new();
+    T::register_meta(&world);
+    world
+}
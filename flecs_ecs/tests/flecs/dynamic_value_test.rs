@@ -0,0 +1,213 @@
+#![allow(clippy::float_cmp)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use flecs_ecs::prelude::*;
+
+use crate::common_test::create_world_with_meta;
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[repr(C)]
+#[meta]
+enum Color {
+    #[default]
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[meta]
+struct Transform {
+    position: Position,
+    rotation: f32,
+}
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[meta]
+struct Character {
+    name: String,
+    color: Color,
+    transform: Transform,
+    inventory: Vec<i32>,
+}
+
+fn as_f64(value: ReflectValue) -> f64 {
+    match value.kind() {
+        Some(ReflectKind::Primitive(value)) => value.as_f64().unwrap(),
+        _ => panic!("expected a primitive"),
+    }
+}
+
+#[test]
+fn dynamic_value_get_set_path() {
+    let world = create_world_with_meta::<(Color, Position, Transform, Character)>();
+    let mut value = DynamicValue::new(&world, world.component_id::<Character>());
+    assert_eq!(value.type_id(), world.component_id::<Character>());
+
+    value.set_path("name", "Bob").unwrap();
+    value.set_path("color", "Blue").unwrap();
+    value.set_path("transform.position.x", 10).unwrap();
+    value.set_path("transform.position.y", 2.5f32).unwrap();
+    value.set_path("transform.rotation", 0.5).unwrap();
+
+    assert_eq!(
+        as_f64(value.get_path("transform.position.x").unwrap()),
+        10.0
+    );
+    assert_eq!(as_f64(value.get_path("transform.rotation").unwrap()), 0.5);
+    match value.get_path("color").unwrap().kind() {
+        Some(ReflectKind::Enum(color)) => assert_eq!(color.name(), Some("Blue")),
+        _ => panic!("expected an enum"),
+    }
+
+    let position = value.get_path("transform.position").unwrap();
+    assert_eq!(position.type_id(), world.component_id::<Position>());
+
+    let e = world.entity().set(Character {
+        inventory: vec![1, 2, 3],
+        ..Default::default()
+    });
+    let mut value = e.get_dynamic(world.component_id::<Character>()).unwrap();
+    value.set_path("inventory.1", 20).unwrap();
+    assert_eq!(as_f64(value.get_path("inventory.2").unwrap()), 3.0);
+
+    e.set_dynamic(&value);
+    e.get::<&Character>(|character| assert_eq!(character.inventory, [1, 20, 3]));
+}
+
+#[test]
+fn dynamic_value_path_errors() {
+    let world = create_world_with_meta::<(Color, Position, Transform, Character)>();
+    let mut value = DynamicValue::new(&world, world.component_id::<Character>());
+
+    let Err(err) = value.get_path("transform.scale") else {
+        panic!("expected an error");
+    };
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+    assert!(err.message().contains("'scale'"));
+
+    assert!(value.get_path("transform..rotation").is_err());
+    assert!(value.get_path("inventory.0").is_err());
+    assert!(value.set_path("inventory.0", 1).is_err());
+
+    let err = value
+        .set_path("transform.rotation", "not a number")
+        .unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidConversion);
+
+    #[derive(Component, Default)]
+    struct Unreflected {
+        value: i32,
+    }
+
+    let value = DynamicValue::new(&world, world.component_id::<Unreflected>());
+    let Err(err) = value.get_path("value") else {
+        panic!("expected an error");
+    };
+    assert_eq!(err.code(), FlecsErrorCode::InvalidOperation);
+    assert!(err.message().contains("has no reflection data"));
+}
+
+#[test]
+fn dynamic_value_entity() {
+    let world = create_world_with_meta::<(Color, Position, Transform, Character)>();
+
+    #[derive(Component)]
+    struct Likes;
+
+    let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    assert!(e.get_dynamic(world.component_id::<Transform>()).is_none());
+
+    let value = e.get_dynamic(world.component_id::<Position>()).unwrap();
+    assert_eq!(as_f64(value.get_path("y").unwrap()), 2.0);
+
+    // pairs use the type of the relationship or target
+    let pair = (
+        world.component_id::<Position>(),
+        world.component_id::<Likes>(),
+    );
+    let mut value = DynamicValue::new(&world, pair);
+    assert_eq!(value.id().second_id(), world.component_id::<Likes>());
+    assert_eq!(value.type_id(), world.component_id::<Position>());
+    value.set_path("x", 5).unwrap();
+
+    let copy = value.clone();
+    value.set_path("x", 6).unwrap();
+
+    let other = world.entity().set_dynamic(&copy);
+    assert!(other.has_id(pair));
+    other.get::<&(Position, Likes)>(|pos| assert_eq!(pos.x, 5.0));
+}
+
+#[test]
+fn dynamic_value_json_and_expr() {
+    let world = create_world_with_meta::<(Color, Position, Transform, Character)>();
+    let id = world.component_id::<Transform>();
+
+    let mut value = DynamicValue::new(&world, id);
+    value
+        .from_json(r#"{"position": {"x": 1, "y": 2}, "rotation": 3}"#, None)
+        .unwrap();
+    assert_eq!(
+        value.to_json(),
+        r#"{"position":{"x":1, "y":2}, "rotation":3}"#
+    );
+    assert!(value.from_json("{\"position\": 1", None).is_err());
+
+    let mut value = DynamicValue::new(&world, id);
+    value.from_expr("{rotation: 1 + 2}", None).unwrap();
+    assert_eq!(as_f64(value.get_path("rotation").unwrap()), 3.0);
+    assert_eq!(value.to_expr(), "{position: {x: 0, y: 0}, rotation: 3}");
+    assert!(value.from_expr("{rotation: 1} 2", None).is_err());
+}
+
+static CREATED: AtomicUsize = AtomicUsize::new(0);
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Component)]
+struct Tracked {
+    _value: i32,
+}
+
+impl Default for Tracked {
+    fn default() -> Self {
+        CREATED.fetch_add(1, Ordering::SeqCst);
+        Tracked { _value: 0 }
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPPED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn dynamic_value_drop() {
+    let world = World::new();
+    let id = world.component_id::<Tracked>();
+    {
+        let value = DynamicValue::new(&world, id);
+        let _copy = value.clone();
+        let e = world.entity().set_dynamic(&value);
+        let _get = e.get_dynamic(id).unwrap();
+        e.destruct();
+    }
+    assert_eq!(
+        CREATED.load(Ordering::SeqCst),
+        DROPPED.load(Ordering::SeqCst)
+    );
+}
@@ -3836,3 +3836,94 @@ fn entity_id_reuse() {
     );
     assert_eq!(b.archetype().to_string(), first_archetype);
 }
+
+#[test]
+fn entity_try_set() {
+    let world = World::new();
+
+    let e = world.entity().try_set(Position { x: 10, y: 20 }).unwrap();
+    e.get::<&Position>(|p| {
+        assert_eq!(p.x, 10);
+        assert_eq!(p.y, 20);
+    });
+
+    e.destruct();
+    let err = e.try_set(Position { x: 1, y: 2 }).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+    assert_eq!(err.entity(), Some(e.id()));
+}
+
+#[test]
+fn entity_try_add_id() {
+    let world = World::new();
+
+    let e = world.entity();
+    let tag = world.entity();
+    assert!(e.try_add_id(tag).unwrap().has_id(tag));
+
+    let dead = world.entity();
+    dead.destruct();
+    let err = e.try_add_id(dead).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+    assert_eq!(err.entity(), Some(e.id()));
+    assert_eq!(err.id(), Some(Id::new(*dead.id())));
+
+    let err = e.try_add_id((tag, dead)).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+
+    // Plain data components without a constructor can be added
+    assert!(e
+        .try_add_id(world.component::<Value>())
+        .unwrap()
+        .has::<Value>());
+
+    let base = world.entity().add_trait::<flecs::Final>();
+    let err = e.try_add_id((flecs::IsA::ID, base)).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::ConstraintViolated);
+    assert!(!e.has_id((flecs::IsA::ID, base)));
+}
+
+#[test]
+fn entity_try_child_of() {
+    let world = World::new();
+
+    let parent = world.entity_named("parent");
+    let child = world.entity_named("child").try_child_of_id(parent).unwrap();
+    assert!(child.has_id((flecs::ChildOf::ID, parent)));
+
+    let err = parent.try_child_of_id(child).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::CycleDetected);
+    let err = parent.try_child_of_id(parent).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::CycleDetected);
+
+    let other = world.entity_named("child");
+    let err = other.try_child_of_id(parent).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::NameInUse);
+    assert_eq!(err.entity(), Some(other.id()));
+    assert!(!other.has_id((flecs::ChildOf::ID, parent)));
+}
+
+#[test]
+fn entity_try_set_name() {
+    let world = World::new();
+
+    let parent = world.entity();
+    let a = world
+        .entity()
+        .child_of_id(parent)
+        .try_set_name("a")
+        .unwrap();
+    assert_eq!(a.name(), "a");
+    assert!(a.try_set_name("a").is_ok());
+
+    let b = world.entity().child_of_id(parent);
+    let err = b.try_set_name("a").unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::NameInUse);
+    assert!(b.get_name().is_none());
+
+    // same name in another scope is fine
+    assert!(world.entity().try_set_name("a").is_ok());
+
+    let err = b.try_set_name("#10").unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+}
@@ -0,0 +1,167 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use flecs_ecs::prelude::http::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Health {
+    value: i32,
+}
+
+#[test]
+fn http_route_params_and_body() {
+    let world = World::new();
+
+    let mut server = world.http_server(27760);
+    server
+        .route(HttpMethod::Get, "/hello", |req, _world| {
+            assert_eq!(req.method(), Some(HttpMethod::Get));
+            assert_eq!(req.path(), "hello/world");
+            assert!(req.body().is_none());
+            assert_eq!(req.params().count(), 2);
+            HttpReply::ok(format!(
+                "hello {} {}",
+                req.param("name").unwrap(),
+                req.param("greeting").unwrap()
+            ))
+            .with_content_type("text/plain")
+            .with_header("X-Custom", "value")
+        })
+        .route(HttpMethod::Put, "/echo", |req, _world| {
+            assert_eq!(req.header("Content-Length"), Some("5"));
+            HttpReply::ok(req.body().unwrap_or_default())
+        });
+
+    let reply = server.request(
+        HttpMethod::Get,
+        "/hello/world?name=Sun&greeting=good%20day",
+        None,
+    );
+    assert_eq!(reply.code, 200);
+    assert!(reply.is_ok());
+    assert_eq!(reply.status, "OK");
+    assert_eq!(reply.content_type, "text/plain");
+    assert_eq!(
+        reply.headers,
+        vec![("X-Custom".to_string(), "value".to_string())]
+    );
+    assert_eq!(reply.body, "hello Sun good day");
+
+    let reply = server.request(HttpMethod::Put, "/echo", Some("hello"));
+    assert_eq!(reply.code, 200);
+    assert_eq!(reply.body, "hello");
+}
+
+#[test]
+fn http_route_access_world() {
+    let world = World::new();
+    let e = world.entity_named("e").set(Health { value: 10 });
+
+    let mut server = world.http_server(27761);
+    server.route(HttpMethod::Put, "/health/", |req, world| {
+        let name = req.path().trim_start_matches("health/");
+        let Some(e) = world.try_lookup(name) else {
+            return HttpReply::not_found();
+        };
+        let value = req.param("value").unwrap().parse().unwrap();
+        e.set(Health { value });
+        HttpReply::ok("")
+    });
+
+    let reply = server.request(HttpMethod::Put, "/health/e?value=20", None);
+    assert_eq!(reply.code, 200);
+    e.get::<&Health>(|health| assert_eq!(health.value, 20));
+
+    let reply = server.request(HttpMethod::Put, "/health/missing?value=20", None);
+    assert_eq!(reply.code, 404);
+}
+
+#[test]
+fn http_route_not_found() {
+    let world = World::new();
+
+    let mut server = world.http_server(27762);
+    server.route(HttpMethod::Get, "/hello", |_req, _world| HttpReply::ok(""));
+
+    assert_eq!(server.request(HttpMethod::Put, "/hello", None).code, 404);
+    assert_eq!(server.request(HttpMethod::Get, "/world", None).code, 404);
+}
+
+#[test]
+fn http_request_line_break() {
+    let world = World::new();
+
+    let mut server = world.http_server(27765);
+    server.route(HttpMethod::Get, "/hello", |_req, _world| {
+        HttpReply::ok("hello")
+    });
+
+    let reply = server.request(
+        HttpMethod::Get,
+        "/hello HTTP/1.1\r\nContent-Length: 0\r\n\r\nGET /hello",
+        None,
+    );
+    assert_eq!(reply.code, 400);
+    assert_eq!(reply.status, "Bad Request");
+    assert!(reply.body.is_empty());
+
+    assert_eq!(server.request(HttpMethod::Get, "/hello\n", None).code, 400);
+    assert_eq!(server.request(HttpMethod::Get, "/hello", None).code, 200);
+}
+
+#[test]
+fn http_route_with_rest() {
+    let world = World::new();
+    world.entity_named("Sun");
+
+    let mut server = world.http_server(27763);
+    server
+        .route(HttpMethod::Get, "/custom", |_req, _world| {
+            HttpReply::ok("custom")
+        })
+        .with_rest();
+
+    let reply = server.request(HttpMethod::Get, "/custom", None);
+    assert_eq!(reply.body, "custom");
+
+    let reply = server.request(HttpMethod::Get, "/entity/Sun", None);
+    assert_eq!(reply.code, 200);
+    assert!(reply.body.contains("\"name\":\"Sun\""));
+
+    let reply = server.request(HttpMethod::Get, "/does_not_exist", None);
+    assert_eq!(reply.code, 404);
+}
+
+#[test]
+fn http_server_progress() {
+    let world = World::new();
+
+    let mut server = world.http_server(27764);
+    server.route(HttpMethod::Get, "/frame", |_req, world| {
+        HttpReply::ok(format!("{}", world.info().frame_count_total))
+    });
+    assert!(server.start());
+    assert!(server.is_running());
+
+    let client = std::thread::spawn(|| {
+        let mut stream = TcpStream::connect(("127.0.0.1", 27764)).unwrap();
+        stream
+            .write_all(b"GET /frame HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    });
+
+    while !client.is_finished() {
+        world.progress_time(0.1);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    let response = client.join().unwrap();
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+
+    server.stop();
+    assert!(!server.is_running());
+}
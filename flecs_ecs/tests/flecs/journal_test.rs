@@ -0,0 +1,329 @@
+#![allow(clippy::float_cmp)]
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use flecs_ecs::prelude::journal::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+struct Opaque {
+    _value: u32,
+}
+
+#[derive(Component)]
+struct Tag;
+
+fn setup(world: &World) {
+    world.component_named::<Position>("Position").meta();
+    world.component_named::<Opaque>("Opaque");
+    world.component_named::<Tag>("Tag");
+}
+
+fn record(world: &World) -> Rc<RefCell<Vec<JournalEntry>>> {
+    let entries = Rc::new(RefCell::new(Vec::new()));
+    let sink = entries.clone();
+    world.journal_begin(move |entry| sink.borrow_mut().push(entry.clone()));
+    entries
+}
+
+#[test]
+fn journal_record_new_add_set() {
+    let world = World::new();
+    setup(&world);
+    let entries = record(&world);
+
+    let e = world.entity().add::<Tag>().set(Position { x: 1.0, y: 2.0 });
+    world.journal_end();
+
+    let entries = entries.borrow();
+    let pos = world.component::<Position>().id();
+    let tag = world.component::<Tag>().id();
+    assert_eq!(entries[0], JournalEntry::New { entity: e.id() });
+    assert!(matches!(&entries[1], JournalEntry::Add { entity, id }
+        if *entity == e.id() && id.id == tag && id.first.as_deref() == Some("Tag")));
+    assert!(matches!(&entries[2], JournalEntry::Add { entity, id }
+        if *entity == e.id() && id.id == pos));
+    assert!(
+        matches!(&entries[3], JournalEntry::Set { entity, id, value: Some(value) }
+        if *entity == e.id() && id.id == pos && value == "{\"x\":1, \"y\":2}")
+    );
+    assert_eq!(entries.len(), 4);
+}
+
+#[test]
+fn journal_record_remove_delete() {
+    let world = World::new();
+    setup(&world);
+    let e = world.entity().add::<Tag>().set(Position { x: 1.0, y: 2.0 });
+    let entries = record(&world);
+
+    e.remove::<Tag>();
+    e.destruct();
+    world.journal_end();
+
+    let entries = entries.borrow();
+    assert!(matches!(&entries[0], JournalEntry::Remove { entity, id }
+        if *entity == e.id() && id.id == world.component::<Tag>().id()));
+    assert_eq!(entries[1], JournalEntry::Delete { entity: e.id() });
+    assert_eq!(entries.len(), 2);
+}
+
+#[test]
+fn journal_record_remove_last_component() {
+    let world = World::new();
+    setup(&world);
+    let e = world.entity().add::<Tag>();
+    let entries = record(&world);
+
+    e.remove::<Tag>();
+    world.journal_end();
+
+    // the entity is still alive, so this is recorded as a remove
+    let entries = entries.borrow();
+    assert!(matches!(&entries[0], JournalEntry::Remove { entity, id }
+        if *entity == e.id() && id.id == world.component::<Tag>().id()));
+    assert_eq!(entries.len(), 1);
+}
+
+#[test]
+fn journal_record_empty_entity() {
+    let world = World::new();
+    setup(&world);
+    let entries = record(&world);
+
+    let empty = world.entity();
+    empty.destruct();
+    let e = world.entity().add::<Tag>();
+    world.journal_end();
+
+    let entries = entries.borrow();
+    assert_eq!(entries[0], JournalEntry::New { entity: empty.id() });
+    assert_eq!(entries[1], JournalEntry::Delete { entity: empty.id() });
+    assert_eq!(entries[2], JournalEntry::New { entity: e.id() });
+    assert!(matches!(&entries[3], JournalEntry::Add { entity, .. } if *entity == e.id()));
+    assert_eq!(entries.len(), 4);
+}
+
+#[test]
+fn journal_record_cascading_delete() {
+    let world = World::new();
+    setup(&world);
+    let parent = world.entity().add::<Tag>();
+    let child = world.entity().child_of_id(parent);
+    let entries = record(&world);
+
+    parent.destruct();
+    world.journal_end();
+
+    assert!(!child.is_alive());
+    assert_eq!(
+        *entries.borrow(),
+        vec![
+            JournalEntry::Delete { entity: child.id() },
+            JournalEntry::Delete {
+                entity: parent.id()
+            }
+        ]
+    );
+}
+
+#[test]
+fn journal_record_deferred() {
+    let world = World::new();
+    setup(&world);
+    let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    let entries = record(&world);
+
+    world.defer_begin();
+    e.add::<Tag>();
+    assert!(entries.borrow().is_empty());
+    world.defer_end();
+
+    world.defer_begin();
+    world.remove_all::<Tag>();
+    world.defer_end();
+    world.journal_end();
+
+    let tag = world.component::<Tag>().id();
+    let entries = entries.borrow();
+    assert!(matches!(&entries[0], JournalEntry::Add { entity, id }
+        if *entity == e.id() && id.id == tag));
+    assert!(matches!(&entries[1], JournalEntry::Remove { entity, id }
+        if *entity == e.id() && id.id == tag));
+    assert_eq!(entries.len(), 2);
+    assert!(!e.has::<Tag>());
+}
+
+#[test]
+fn journal_record_unreflected_value() {
+    let world = World::new();
+    setup(&world);
+    let entries = record(&world);
+
+    let e = world.entity().set(Opaque { _value: 10 });
+    world.journal_end();
+
+    assert!(entries.borrow().iter().any(|entry| matches!(entry,
+        JournalEntry::Set { entity, value: None, .. } if *entity == e.id())));
+}
+
+#[test]
+fn journal_end_stops_recording() {
+    let world = World::new();
+    setup(&world);
+    let entries = record(&world);
+    assert!(world.is_journal_active());
+
+    world.entity().add::<Tag>();
+    world.journal_end();
+    assert!(!world.is_journal_active());
+
+    world.entity().set(Position { x: 1.0, y: 2.0 });
+    assert_eq!(entries.borrow().len(), 2);
+}
+
+#[test]
+fn journal_display() {
+    let world = World::new();
+    setup(&world);
+    let entries = record(&world);
+
+    let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    world.journal_end();
+
+    let lines: Vec<String> = entries.borrow().iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        lines,
+        vec![
+            format!("new #{}", e.id()),
+            format!("add #{} Position", e.id()),
+            format!("set #{} Position {{\"x\":1, \"y\":2}}", e.id()),
+        ]
+    );
+}
+
+#[test]
+fn journal_replay() {
+    let world = World::new();
+    setup(&world);
+    let entries = record(&world);
+
+    let parent = world.entity_named("parent");
+    let e = world
+        .entity_named("e")
+        .child_of_id(parent)
+        .add::<Tag>()
+        .set(Position { x: 1.0, y: 2.0 });
+    let deleted = world.entity().add::<Tag>();
+    e.set(Position { x: 3.0, y: 4.0 });
+    deleted.destruct();
+    world.journal_end();
+
+    // register components in a different order, so ids don't match
+    let replay_world = World::new();
+    replay_world.component_named::<Tag>("Tag");
+    replay_world.entity().add::<Tag>();
+    replay_world.component_named::<Opaque>("Opaque");
+    replay_world.component_named::<Position>("Position").meta();
+
+    let replay = replay_world
+        .journal_replay(entries.borrow().iter())
+        .unwrap();
+
+    let replayed = replay.entity(e).unwrap();
+    assert_eq!(replayed.path().unwrap(), "::parent::e");
+    assert!(replayed.has::<Tag>());
+    replayed.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 3.0);
+        assert_eq!(pos.y, 4.0);
+    });
+    assert_eq!(replayed.parent(), replay.entity(parent));
+    assert!(!replay.entity(deleted).unwrap().is_alive());
+    assert_eq!(replay_world.lookup("parent::e"), replayed);
+}
+
+#[test]
+fn journal_replay_empty_entity() {
+    let world = World::new();
+    setup(&world);
+    let entries = record(&world);
+
+    let empty = world.entity();
+    let target = world.entity();
+    empty.destruct();
+    let e = world.entity().add_first::<Tag>(target);
+    world.journal_end();
+
+    let replay_world = World::new();
+    setup(&replay_world);
+    let replay = replay_world
+        .journal_replay(entries.borrow().iter())
+        .unwrap();
+
+    let replayed_target = replay.entity(target).unwrap();
+    assert!(!replay.entity(empty).unwrap().is_alive());
+    assert!(replayed_target.is_alive());
+    assert!(replay.entity(e).unwrap().has_first::<Tag>(replayed_target));
+}
+
+#[test]
+fn journal_replay_incremental() {
+    let world = World::new();
+    setup(&world);
+    let replay_world = World::new();
+    setup(&replay_world);
+
+    let mut replay = JournalReplay::new(&replay_world);
+    let entries = record(&world);
+
+    let e = world.entity().add::<Tag>();
+    for entry in entries.borrow_mut().drain(..) {
+        replay.apply(&entry).unwrap();
+    }
+    assert!(replay.entity(e).unwrap().has::<Tag>());
+
+    e.remove::<Tag>();
+    for entry in entries.borrow_mut().drain(..) {
+        replay.apply(&entry).unwrap();
+    }
+    world.journal_end();
+    assert!(!replay.entity(e).unwrap().has::<Tag>());
+}
+
+#[test]
+fn journal_replay_unresolved_entity() {
+    let world = World::new();
+    setup(&world);
+    let e = world.entity().add::<Tag>();
+    let entries = record(&world);
+
+    e.remove::<Tag>();
+    world.journal_end();
+
+    // the entity wasn't created by the recorded entries and has no name
+    let replay_world = World::new();
+    setup(&replay_world);
+    let result = replay_world.journal_replay(entries.borrow().iter());
+    assert!(matches!(result, Err(err) if err.entity() == Some(e.id())));
+}
+
+#[test]
+fn journal_record_per_world() {
+    let world = World::new();
+    setup(&world);
+    let other = World::new();
+    setup(&other);
+    let entries = record(&world);
+
+    other.entity().add::<Tag>();
+    world.journal_end();
+
+    assert!(entries.borrow().is_empty());
+}
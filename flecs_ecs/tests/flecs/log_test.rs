@@ -0,0 +1,103 @@
+use std::sync::{Arc, Mutex};
+
+use flecs_ecs::prelude::*;
+use flecs_ecs::sys;
+
+fn log(level: i32, line: i32, msg: &std::ffi::CStr) {
+    unsafe {
+        sys::ecs_log_(
+            level,
+            c"log_test.rs".as_ptr(),
+            line,
+            c"%s".as_ptr(),
+            msg.as_ptr(),
+        )
+    };
+}
+
+#[test]
+fn log_capture() {
+    let (value, logs) = capture_logs(|| {
+        log(-2, 10, c"a warning");
+        log(-3, 20, c"an error");
+        42
+    });
+
+    assert_eq!(value, 42);
+    assert_eq!(logs.len(), 2);
+
+    assert_eq!(logs[0].level, LogLevel::Warning);
+    assert_eq!(logs[0].raw_level, -2);
+    assert_eq!(logs[0].file.as_deref(), Some("log_test.rs"));
+    assert_eq!(logs[0].line, 10);
+    assert_eq!(logs[0].message, "a warning");
+
+    assert_eq!(logs[1].level, LogLevel::Error);
+    assert_eq!(logs[1].line, 20);
+    assert_eq!(logs[1].message, "an error");
+}
+
+#[test]
+fn log_capture_indent() {
+    let (_, logs) = capture_logs(|| {
+        log(-2, 0, c"outer");
+        unsafe { sys::ecs_log_push_(-2) };
+        log(-2, 0, c"inner");
+        unsafe { sys::ecs_log_pop_(-2) };
+        log(-2, 0, c"outer again");
+    });
+
+    let indents: Vec<i32> = logs.iter().map(|msg| msg.indent).collect();
+    assert_eq!(indents[1], indents[0] + 1);
+    assert_eq!(indents[2], indents[0]);
+}
+
+#[test]
+fn log_capture_nested() {
+    let (inner, outer) = capture_logs(|| {
+        log(-2, 0, c"outer");
+        let (_, inner) = capture_logs(|| log(-2, 0, c"inner"));
+        inner
+    });
+
+    assert_eq!(outer.len(), 1);
+    assert_eq!(outer[0].message, "outer");
+    assert_eq!(inner.len(), 1);
+    assert_eq!(inner[0].message, "inner");
+}
+
+#[test]
+fn log_capture_filtered_by_level() {
+    let (_, logs) = capture_logs(|| log(3, 0, c"debug message"));
+    assert!(logs.is_empty());
+}
+
+#[test]
+fn log_hook() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let hook_received = received.clone();
+    set_log_hook(move |msg: &LogMessage| {
+        // other tests may log from different threads while the hook is set
+        if msg.message.starts_with("log_hook") {
+            hook_received.lock().unwrap().push(msg.clone());
+        }
+    });
+
+    log(-2, 5, c"log_hook warning");
+    reset_log_hook();
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].level, LogLevel::Warning);
+    assert_eq!(received[0].line, 5);
+}
+
+#[test]
+fn log_level_from_raw() {
+    assert_eq!(LogLevel::from_raw(-4), LogLevel::Fatal);
+    assert_eq!(LogLevel::from_raw(-3), LogLevel::Error);
+    assert_eq!(LogLevel::from_raw(-2), LogLevel::Warning);
+    assert_eq!(LogLevel::from_raw(0), LogLevel::Info);
+    assert_eq!(LogLevel::from_raw(2), LogLevel::Debug(2));
+    assert_eq!(LogLevel::from_raw(4), LogLevel::Journal);
+}
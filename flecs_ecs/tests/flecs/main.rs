@@ -5,19 +5,43 @@
 
 pub mod common_test;
 
+#[cfg(feature = "flecs_abort_panic")]
+mod abort_test;
+mod alerts_test;
+mod binary_test;
+
 mod clone_default_impl_test;
 mod component_test;
+mod dynamic_value_test;
 mod entity_test;
 mod enum_test;
 mod eq_test;
 mod flecs_docs_test;
+mod http_test;
 mod is_ref_test;
+#[cfg(feature = "flecs_journal")]
+mod journal_test;
+mod log_test;
+mod meta_enum_test;
 mod meta_macro_test;
+mod meta_std_types_test;
 mod meta_test;
 mod meta_test_rust;
 mod meta_trait_test;
+mod metrics_test;
+mod module_test;
 mod observer_test;
+#[cfg(feature = "flecs_os_api_rust")]
+mod os_api_test;
 mod query_builder_test;
 mod query_test;
+mod reflect_value_test;
+mod rest_test;
+mod script_test;
+#[cfg(feature = "serde")]
+mod serde_test;
+mod snapshot_test;
+mod stats_test;
 mod system_test;
+mod units_test;
 mod world_test;
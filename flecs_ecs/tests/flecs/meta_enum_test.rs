@@ -0,0 +1,148 @@
+#![allow(clippy::float_cmp)]
+use std::collections::VecDeque;
+
+use flecs_ecs::prelude::*;
+
+use crate::common_test::create_world_with_meta;
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[meta]
+enum Shape {
+    #[default]
+    Empty,
+    Circle {
+        r: f32,
+    },
+    Rect {
+        w: f32,
+        h: f32,
+    },
+    Polygon {
+        points: VecDeque<Position>,
+        #[skip]
+        cached_area: f32,
+    },
+}
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[meta]
+struct Sprite {
+    name: String,
+    shape: Shape,
+}
+
+#[test]
+fn meta_enum_json() {
+    let world = create_world_with_meta::<(Position, Shape, Sprite)>();
+
+    let shape = Shape::Circle { r: 1.0 };
+    assert_eq!(world.to_json::<Shape>(&shape), r#"{"Circle":{"r":1}}"#);
+    assert_eq!(world.to_json::<Shape>(&Shape::Empty), r#"{"Empty":true}"#);
+
+    let shape = Shape::Polygon {
+        points: VecDeque::from([Position { x: 1.0, y: 2.0 }]),
+        cached_area: 3.0,
+    };
+    assert_eq!(
+        world.to_json::<Shape>(&shape),
+        r#"{"Polygon":{"points":[{"x":1, "y":2}]}}"#
+    );
+
+    let mut result = Shape::Circle { r: 1.0 };
+    world.from_json::<Shape>(&mut result, r#"{"Rect": {"w": 2}}"#, None);
+    assert_eq!(result, Shape::Rect { w: 2.0, h: 0.0 });
+
+    // the fields of the active variant are kept
+    world.from_json::<Shape>(&mut result, r#"{"Rect": {"h": 3}}"#, None);
+    assert_eq!(result, Shape::Rect { w: 2.0, h: 3.0 });
+
+    world.from_json::<Shape>(&mut result, r#"{"Empty": true}"#, None);
+    assert_eq!(result, Shape::Empty);
+}
+
+#[test]
+fn meta_enum_nested() {
+    let world = create_world_with_meta::<(Position, Shape, Sprite)>();
+
+    let sprite = Sprite {
+        name: "ball".to_string(),
+        shape: Shape::Circle { r: 2.0 },
+    };
+    let json = world.to_json::<Sprite>(&sprite);
+    assert_eq!(json, r#"{"name":"ball", "shape":{"Circle":{"r":2}}}"#);
+
+    let e = world.entity().set(sprite.clone());
+    let other = world.entity().add::<Sprite>().from_json(&e.to_json(None));
+    other.get::<&Sprite>(|result| assert_eq!(*result, sprite));
+}
+
+#[test]
+fn meta_enum_expr() {
+    let world = create_world_with_meta::<(Position, Shape, Sprite)>();
+
+    let shape = Shape::Rect { w: 1.0, h: 2.0 };
+    let expr = world.to_expr(&shape);
+    assert_eq!(expr, "{Rect: {w: 1, h: 2}}");
+    assert_eq!(world.eval_expr::<Shape>(&expr, None).unwrap(), shape);
+
+    let result = world
+        .eval_expr::<Shape>("{Circle: {r: 1 + 2}}", None)
+        .unwrap();
+    assert_eq!(result, Shape::Circle { r: 3.0 });
+}
+
+#[test]
+fn meta_enum_cursor() {
+    let world = create_world_with_meta::<(Position, Shape, Sprite)>();
+
+    let mut shape = Shape::Empty;
+    let mut cur = Cursor::new(
+        &world,
+        world.component_id::<Shape>(),
+        &mut shape as *mut Shape as *mut std::ffi::c_void,
+    );
+    assert_eq!(cur.push(), 0);
+    assert_eq!(cur.member("Rect"), 0);
+    assert_eq!(cur.push(), 0);
+    assert_eq!(cur.member("h"), 0);
+    assert_eq!(cur.set_float(4.0), 0);
+    assert_eq!(cur.member("w"), 0);
+    assert_eq!(cur.get_float(), 0.0);
+    assert_eq!(cur.set_float(5.0), 0);
+    assert_eq!(cur.pop(), 0);
+    assert_eq!(cur.pop(), 0);
+    assert_eq!(shape, Shape::Rect { w: 5.0, h: 4.0 });
+
+    let mut cur = Cursor::new(
+        &world,
+        world.component_id::<Shape>(),
+        &mut shape as *mut Shape as *mut std::ffi::c_void,
+    );
+    assert_eq!(cur.push(), 0);
+    assert_ne!(cur.member("Square"), 0);
+    assert_eq!(cur.member("Polygon"), 0);
+    assert_eq!(cur.push(), 0);
+    assert_eq!(cur.member("points"), 0);
+    assert_eq!(cur.push(), 0);
+    assert_eq!(cur.push(), 0);
+    assert_eq!(cur.member("y"), 0);
+    assert_eq!(cur.set_float(1.0), 0);
+    assert_eq!(cur.pop(), 0);
+    assert_eq!(cur.pop(), 0);
+    assert_eq!(cur.pop(), 0);
+    assert_eq!(cur.pop(), 0);
+    assert_eq!(
+        shape,
+        Shape::Polygon {
+            points: VecDeque::from([Position { x: 0.0, y: 1.0 }]),
+            cached_area: 0.0
+        }
+    );
+}
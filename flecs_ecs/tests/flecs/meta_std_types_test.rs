@@ -0,0 +1,120 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use flecs_ecs::prelude::*;
+
+#[derive(Debug, Component, PartialEq, Default, Clone)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Component, PartialEq, Default)]
+#[meta]
+struct Inventory {
+    counts: BTreeMap<String, i32>,
+    spawns: HashMap<u32, Position>,
+    tags: BTreeSet<String>,
+    ids: HashSet<u64>,
+    queue: VecDeque<Position>,
+    nested: BTreeMap<String, BTreeSet<i32>>,
+    origin: Box<Position>,
+    label: Box<String>,
+    name: Arc<str>,
+}
+
+fn inventory() -> Inventory {
+    Inventory {
+        counts: BTreeMap::from([("apple".to_string(), 3), ("pear".to_string(), 1)]),
+        spawns: HashMap::from([(7, Position { x: 1.0, y: 2.0 })]),
+        tags: BTreeSet::from(["a".to_string(), "b".to_string()]),
+        ids: HashSet::from([42]),
+        queue: VecDeque::from([Position { x: 3.0, y: 4.0 }]),
+        nested: BTreeMap::from([("odd".to_string(), BTreeSet::from([1, 3]))]),
+        origin: Box::new(Position { x: 5.0, y: 6.0 }),
+        label: Box::new("boxed".to_string()),
+        name: Arc::from("bob"),
+    }
+}
+
+const INVENTORY_JSON: &str = concat!(
+    r#"{"counts":[{"key":"apple", "value":3}, {"key":"pear", "value":1}], "#,
+    r#""spawns":[{"key":7, "value":{"x":1, "y":2}}], "#,
+    r#""tags":["a", "b"], "#,
+    r#""ids":[42], "#,
+    r#""queue":[{"x":3, "y":4}], "#,
+    r#""nested":[{"key":"odd", "value":[1, 3]}], "#,
+    r#""origin":{"x":5, "y":6}, "#,
+    r#""label":"boxed", "#,
+    r#""name":"bob"}"#
+);
+
+#[test]
+fn meta_std_types_json() {
+    let world = World::new();
+    world.component::<Position>().meta();
+    world.component::<Inventory>().meta();
+
+    let value = inventory();
+    assert_eq!(world.to_json::<Inventory>(&value), INVENTORY_JSON);
+
+    let mut result = Inventory::default();
+    world.from_json::<Inventory>(&mut result, INVENTORY_JSON, None);
+    assert_eq!(result, value);
+
+    // deserializing replaces the contents of collections
+    world.from_json::<Inventory>(
+        &mut result,
+        r#"{"counts": [{"key": "kiwi", "value": 2}], "tags": [], "queue": []}"#,
+        None,
+    );
+    assert_eq!(result.counts, BTreeMap::from([("kiwi".to_string(), 2)]));
+    assert!(result.tags.is_empty());
+    assert!(result.queue.is_empty());
+    assert_eq!(result.ids, value.ids);
+}
+
+#[test]
+fn meta_std_types_expr() {
+    let world = World::new();
+    world.component::<Position>().meta();
+    world.component::<Inventory>().meta();
+
+    let value = inventory();
+    let expr = world.to_expr(&value);
+    let result = world.eval_expr::<Inventory>(&expr, None).unwrap();
+    assert_eq!(result, value);
+}
+
+#[test]
+fn meta_std_types_entity() {
+    let world = World::new();
+    world.component::<Position>().meta();
+    world.component::<Inventory>().meta();
+
+    let e = world.entity().set(inventory());
+    let json = e.to_json(None);
+
+    let other = world.entity().add::<Inventory>().from_json(&json);
+    other.get::<&Inventory>(|result| assert_eq!(*result, inventory()));
+}
+
+#[test]
+fn meta_std_types_id() {
+    let world = World::new();
+    world.component::<Position>().meta();
+
+    // standard library types are registered once, as opaque types
+    let id = id!(&world, HashMap<String, Position>).id();
+    assert_eq!(id, id!(&world, HashMap<String, Position>).id());
+    assert!(world.entity_from_id(id).has::<flecs::meta::EcsOpaque>());
+    assert!(world
+        .entity_from_id(id!(&world, Arc<str>).id())
+        .has::<flecs::meta::EcsOpaque>());
+
+    // types without reflection data are still reflected as vectors of values
+    let mut value = DynamicValue::new(&world, id!(&world, VecDeque<Position>).id());
+    value.from_json(r#"[{"x": 1}, {"y": 2}]"#, None).unwrap();
+    assert_eq!(value.to_json(), r#"[{"x":1, "y":0}, {"x":0, "y":2}]"#);
+}
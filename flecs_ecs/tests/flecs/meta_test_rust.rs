@@ -37,3 +37,54 @@ fn meta_struct_field_order() {
         assert_eq!(json, "{a: 10, b: 20, c: 30, d: 40, e: 50}"); //if this fails, field re-ordering is not working
     });
 }
+
+#[test]
+fn meta_field_attributes() {
+    use flecs_ecs::prelude::units::*;
+
+    let world = World::new();
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[meta]
+    struct Body {
+        #[meta(unit = length::Meters, range(0.0, 3.0))]
+        height: f32,
+        #[meta(warning_range(-10, 50), error_range(-20, 60))]
+        temperature: i32,
+        #[meta(name = "hp")]
+        health: i32,
+        #[meta(skip)]
+        cache: u64,
+    }
+
+    let c = world.component::<Body>().meta();
+    let height = c.lookup("height");
+    height.get::<&flecs::meta::Member>(|member| {
+        assert_eq!(member.unit, world.component_id::<length::Meters>());
+    });
+    height.get::<&flecs::meta::MemberRanges>(|ranges| {
+        assert_eq!(ranges.value.min, 0.0);
+        assert_eq!(ranges.value.max, 3.0);
+    });
+    c.lookup("temperature")
+        .get::<&flecs::meta::MemberRanges>(|ranges| {
+            assert_eq!((ranges.warning.min, ranges.warning.max), (-10.0, 50.0));
+            assert_eq!((ranges.error.min, ranges.error.max), (-20.0, 60.0));
+        });
+    assert!(c.try_lookup("hp").is_some());
+    assert!(c.try_lookup("health").is_none());
+    assert!(c.try_lookup("cache").is_none());
+
+    let body = Body {
+        height: 1.5,
+        temperature: 37,
+        health: 100,
+        cache: 1,
+    };
+    let json = world.to_json::<Body>(&body);
+    assert_eq!(json, r#"{"height":1.5, "temperature":37, "hp":100}"#);
+
+    let mut result = Body::default();
+    world.from_json::<Body>(&mut result, &json, None);
+    assert_eq!(result, Body { cache: 0, ..body });
+}
@@ -0,0 +1,119 @@
+#![allow(clippy::float_cmp)]
+use std::ffi::CStr;
+
+use flecs_ecs::prelude::metrics::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+#[meta]
+struct Mass {
+    value: f64,
+}
+
+#[derive(Component)]
+struct Tag;
+
+#[test]
+fn metrics_gauge_member() {
+    let world = World::new();
+    world.import::<Metrics>();
+
+    world.component::<Position>().meta();
+
+    let m = world
+        .metric_named("metrics::position_x")
+        .member::<Position>("x")
+        .kind(MetricKind::Gauge)
+        .brief("X position")
+        .build();
+
+    let e1 = world.entity().set(Position { x: 10.0, y: 20.0 });
+    let e2 = world.entity().set(Position { x: 30.0, y: 40.0 });
+
+    world.progress();
+
+    assert_eq!(m.instance_count(), 2);
+    assert_eq!(m.try_instance_for(e1).unwrap().value(), 10.0);
+    assert_eq!(m.try_instance_for(e2).unwrap().value(), 30.0);
+
+    e1.set(Position { x: 15.0, y: 20.0 });
+    world.progress();
+
+    let instance = m.try_instance_for(e1).unwrap();
+    assert_eq!(instance.value(), 15.0);
+    assert_eq!(instance.source(), e1);
+    assert_eq!(instance.metric().id(), m.id());
+
+    let brief =
+        unsafe { CStr::from_ptr(flecs_ecs::sys::ecs_doc_get_brief(world.ptr_mut(), *m.id())) };
+    assert_eq!(brief.to_str().unwrap(), "X position");
+}
+
+#[test]
+fn metrics_gauge_dotmember() {
+    let world = World::new();
+    world.import::<Metrics>();
+
+    world.component::<Position>().meta();
+
+    let m = world
+        .metric()
+        .dotmember::<Position>("y")
+        .kind(MetricKind::Gauge)
+        .build();
+
+    let e = world.entity().set(Position { x: 10.0, y: 20.0 });
+
+    world.progress();
+
+    assert_eq!(m.try_instance_for(e).unwrap().value(), 20.0);
+}
+
+#[test]
+fn metrics_counter_increment() {
+    let world = World::new();
+    world.import::<Metrics>();
+
+    world.component::<Mass>().meta();
+
+    let m = world
+        .metric()
+        .member::<Mass>("value")
+        .kind(MetricKind::CounterIncrement)
+        .build();
+
+    let e = world.entity().set(Mass { value: 10.0 });
+
+    world.progress_time(1.0);
+    world.progress_time(1.0);
+
+    assert_eq!(m.try_instance_for(e).unwrap().value(), 20.0);
+}
+
+#[test]
+fn metrics_counter_id() {
+    let world = World::new();
+    world.import::<Metrics>();
+
+    let m = world
+        .metric()
+        .id::<Tag>()
+        .kind(MetricKind::CounterId)
+        .build();
+
+    world.entity().add::<Tag>();
+    world.entity().add::<Tag>();
+    world.entity().add::<Tag>();
+
+    world.progress_time(1.0);
+
+    assert_eq!(m.value(), Some(3.0));
+    assert_eq!(m.instance_count(), 0);
+}
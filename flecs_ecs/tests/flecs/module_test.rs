@@ -0,0 +1,85 @@
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct InnerModule;
+
+impl Module for InnerModule {
+    fn module(world: &World) {
+        world.module::<InnerModule>("inner");
+    }
+}
+
+#[derive(Component)]
+struct OuterModule;
+
+impl Module for OuterModule {
+    fn module(world: &World) {
+        world.import::<InnerModule>();
+        world.module::<OuterModule>("outer");
+    }
+}
+
+fn assert_module(module: EntityView, path: &str) {
+    assert!(module.is_alive());
+    assert!(module.has::<flecs::Module>());
+    assert_eq!(module.path().unwrap(), path);
+}
+
+#[test]
+fn module_import_nested() {
+    let world = World::new();
+    let outer = world.import::<OuterModule>();
+    let inner = world.component::<InnerModule>().entity;
+
+    assert!(outer.is_alive());
+    assert!(outer.has::<flecs::Module>());
+    assert!(inner.is_alive());
+    assert!(inner.has::<flecs::Module>());
+    assert!(!inner.has_id((flecs::ChildOf::ID, outer)));
+}
+
+#[test]
+fn module_import_in_scope() {
+    let world = World::new();
+    let parent = world.entity_named("parent");
+    world.set_scope_id(parent);
+
+    // the module is registered at the root, and the scope is restored, both
+    // when the module is imported and when it was already imported
+    let outer = world.import::<OuterModule>();
+    assert_eq!(world.get_scope(), Some(parent));
+    assert_eq!(world.import::<OuterModule>(), outer);
+    assert_eq!(world.get_scope(), Some(parent));
+    world.set_scope_id(0);
+
+    let inner = world.component::<InnerModule>().entity;
+    assert!(outer.has::<flecs::Module>());
+    assert!(inner.has::<flecs::Module>());
+    assert!(!outer.has_id((flecs::ChildOf::ID, parent)));
+    assert!(!inner.has_id((flecs::ChildOf::ID, parent)));
+    assert!(parent.is_alive());
+}
+
+#[test]
+fn module_import_nested_stats() {
+    let world = World::new();
+    let stats = world.import::<stats::Stats>();
+
+    assert_module(stats, "::flecs::rust::stats");
+    assert_module(
+        world.component::<units::Units>().entity,
+        "::flecs::rust::units",
+    );
+}
+
+#[test]
+fn module_import_nested_alerts() {
+    let world = World::new();
+    let alerts = world.import::<alerts::Alerts>();
+
+    assert_module(alerts, "::flecs::rust::alerts");
+    assert_module(
+        world.component::<metrics::Metrics>().entity,
+        "::flecs::rust::metrics",
+    );
+}
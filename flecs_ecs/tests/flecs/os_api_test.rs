@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+fn run_threaded(task_threads: bool) -> HashSet<String> {
+    let world = World::new();
+    if task_threads {
+        world.set_task_threads(4);
+    } else {
+        world.set_threads(4);
+    }
+
+    for i in 0..100 {
+        world.entity().set(Position {
+            x: i as f32,
+            y: 0.0,
+        });
+    }
+
+    let names = Arc::new(Mutex::new(HashSet::new()));
+    let system_names = names.clone();
+    world
+        .system::<&mut Position>()
+        .multi_threaded()
+        .each(move |p| {
+            p.y += 1.0;
+            if let Some(name) = std::thread::current().name() {
+                system_names.lock().unwrap().insert(name.to_string());
+            }
+        });
+
+    for _ in 0..5 {
+        world.progress();
+    }
+
+    world
+        .new_query::<&Position>()
+        .each(|p| assert_eq!(p.y, 5.0));
+
+    let names = names.lock().unwrap().clone();
+    names
+}
+
+#[test]
+fn os_api_worker_threads() {
+    let names = run_threaded(false);
+    assert!(
+        names.iter().any(|name| name.starts_with("flecs-worker-")),
+        "{names:?}"
+    );
+}
+
+#[test]
+fn os_api_task_threads() {
+    let names = run_threaded(true);
+    assert!(
+        names.iter().any(|name| name.starts_with("flecs-task-")),
+        "{names:?}"
+    );
+}
+
+#[test]
+fn os_api_time() {
+    let world = World::new();
+    world.set_target_fps(100.0);
+
+    // the first frame has no previous frame to measure against
+    world.progress();
+
+    let start = std::time::Instant::now();
+    world.progress();
+    world.progress();
+
+    // sleeping to reach the target fps goes through the OS API
+    assert!(start.elapsed() >= std::time::Duration::from_millis(10));
+    assert!(world.info().delta_time > 0.0);
+}
@@ -119,3 +119,240 @@ fn query_each_sparse() {
         assert_eq!(p.y, 22);
     });
 }
+
+fn populate_for_paging(world: &World) {
+    for i in 0..5 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+    // a second table
+    for i in 5..10 {
+        world
+            .entity()
+            .set(Position { x: i, y: 0 })
+            .set(Velocity { x: 0, y: 0 });
+    }
+}
+
+#[test]
+fn query_page_each() {
+    let world = World::new();
+    populate_for_paging(&world);
+    let q = world.query::<&Position>().build();
+
+    let mut xs = vec![];
+    q.iterable().page(3, 4).each(|p| xs.push(p.x));
+    assert_eq!(xs, [3, 4, 5, 6]);
+
+    let mut xs = vec![];
+    q.iterable().page(8, 0).each(|p| xs.push(p.x));
+    assert_eq!(xs, [8, 9]);
+
+    let mut count = 0;
+    q.iterable().page(20, 5).each(|_| count += 1);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn query_page_each_entity_and_run() {
+    let world = World::new();
+    populate_for_paging(&world);
+    let q = world.query::<&Position>().build();
+
+    let mut entities = vec![];
+    q.iterable().page(4, 2).each_entity(|e, p| {
+        e.get::<&Position>(|ep| assert_eq!(ep.x, p.x));
+        entities.push(p.x);
+    });
+    assert_eq!(entities, [4, 5]);
+
+    let mut counts = vec![];
+    q.iterable().page(4, 2).run(|mut it| {
+        while it.next() {
+            counts.push(it.count());
+        }
+    });
+    assert_eq!(counts, [1, 1]);
+}
+
+#[test]
+fn query_worker_each() {
+    let world = World::new();
+    populate_for_paging(&world);
+    let q = world.query::<&Position>().build();
+
+    let mut results = vec![];
+    for index in 0..3 {
+        let mut xs = vec![];
+        q.iterable().worker(index, 3).each(|p| xs.push(p.x));
+        results.push(xs);
+    }
+
+    // each table is divided across the workers
+    assert_eq!(results, [vec![0, 1, 5, 6], vec![2, 3, 7, 8], vec![4, 9]]);
+}
+
+#[test]
+fn query_page_worker() {
+    let world = World::new();
+    populate_for_paging(&world);
+    let q = world.query::<&Position>().build();
+
+    // the page contains 1..=4 and 5..=8, of which worker 1 of 2 gets the second half
+    let mut xs = vec![];
+    q.iterable().page(1, 8).worker(1, 2).each(|p| xs.push(p.x));
+    assert_eq!(xs, [3, 4, 7, 8]);
+}
+
+#[test]
+fn query_page_to_json() {
+    #[derive(Component)]
+    #[meta]
+    struct Value {
+        v: i32,
+    }
+
+    let world = World::new();
+    world.component::<Value>().meta();
+    for v in 0..4 {
+        world.entity().set(Value { v });
+    }
+    let q = world.query::<&Value>().build();
+
+    let json = q.iterable().page(1, 2).to_json(None).unwrap();
+    assert!(!json.contains("\"v\":0"), "{json}");
+    assert!(json.contains("\"v\":1"), "{json}");
+    assert!(json.contains("\"v\":2"), "{json}");
+    assert!(!json.contains("\"v\":3"), "{json}");
+}
+
+#[test]
+fn query_par_each() {
+    let world = World::new();
+    for i in 0..1000 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+    for i in 0..1000 {
+        world
+            .entity()
+            .set(Position { x: i, y: 0 })
+            .set(Velocity { x: 1, y: 1 });
+    }
+
+    let threads = std::sync::Mutex::new(std::collections::HashSet::new());
+    let q = world.new_query::<&mut Position>();
+    q.par_each(4, |p| {
+        p.y = p.x * 2;
+        threads.lock().unwrap().insert(std::thread::current().id());
+    });
+
+    assert_eq!(threads.lock().unwrap().len(), 4);
+    q.each(|p| assert_eq!(p.y, p.x * 2));
+    assert_eq!(world.get_stage_count(), 1);
+    assert!(!world.is_readonly());
+}
+
+#[test]
+fn query_par_each_entity_deferred() {
+    let world = World::new();
+    // components can't be registered from the threads
+    world.component::<Velocity>();
+    for i in 0..100 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let q = world.new_query::<&Position>();
+    q.par_each_entity(3, |e, p| {
+        e.set(Velocity { x: p.x, y: 0 });
+        // commands are deferred until all threads are done
+        assert!(!e.has::<Velocity>());
+    });
+
+    let mut count = 0;
+    world.each::<(&Position, &Velocity)>(|(p, v)| {
+        assert_eq!(p.x, v.x);
+        count += 1;
+    });
+    assert_eq!(count, 100);
+}
+
+#[test]
+fn query_par_each_keeps_stages() {
+    let world = World::new();
+    world.set_threads(2);
+    for i in 0..10 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let q = world.new_query::<&mut Position>();
+    q.par_each(2, |p| p.y = 1);
+    q.par_each(4, |p| p.y += 1);
+
+    assert_eq!(world.get_stage_count(), 2);
+    q.each(|p| assert_eq!(p.y, 2));
+
+    world.system::<&mut Position>().each(|p| p.y += 1);
+    world.progress();
+    q.each(|p| assert_eq!(p.y, 3));
+}
+
+#[test]
+#[should_panic]
+fn query_par_each_while_readonly_panics() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+
+    let q = world.new_query::<&mut Position>();
+    world.readonly_begin(false);
+    q.par_each(2, |_| {});
+}
+
+#[test]
+#[should_panic(expected = "parallel iteration can't write to fields")]
+fn query_par_each_mut_singleton_panics() {
+    let world = World::new();
+    world.set(Velocity { x: 1, y: 1 });
+    world.entity().set(Position { x: 0, y: 0 });
+
+    let q = world
+        .query::<(&Position, &mut Velocity)>()
+        .term_at(1)
+        .singleton()
+        .build();
+    q.par_each(2, |(_, v)| v.x += 1);
+}
+
+#[test]
+#[should_panic(expected = "parallel iteration can't write to fields")]
+fn query_par_each_mut_up_panics() {
+    let world = World::new();
+    let parent = world.entity().set(Velocity { x: 1, y: 1 });
+    world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .child_of_id(parent);
+
+    let q = world
+        .query::<(&Position, &mut Velocity)>()
+        .term_at(1)
+        .parent()
+        .build();
+    q.par_each(2, |(_, v)| v.x += 1);
+}
+
+#[test]
+fn query_par_each_shared_readonly() {
+    let world = World::new();
+    world.set(Velocity { x: 2, y: 2 });
+    for i in 0..100 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let q = world
+        .query::<(&mut Position, &Velocity)>()
+        .term_at(1)
+        .singleton()
+        .build();
+    q.par_each(4, |(p, v)| p.y = p.x * v.y);
+
+    q.each(|(p, _)| assert_eq!(p.y, p.x * 2));
+}
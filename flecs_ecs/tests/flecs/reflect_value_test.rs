@@ -0,0 +1,336 @@
+#![allow(clippy::float_cmp)]
+use flecs_ecs::prelude::units::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Debug, Component, PartialEq)]
+#[repr(C)]
+#[meta]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Component)]
+#[meta]
+struct Character {
+    name: String,
+    health: i32,
+    alive: bool,
+    color: Color,
+    inventory: Vec<i32>,
+    target: Entity,
+}
+
+#[derive(Component)]
+#[repr(C)]
+struct Stats {
+    values: [i32; 3],
+    speed: f32,
+}
+
+/// Prints values in a compact text format, to test the order of visits.
+#[derive(Default)]
+struct Printer(String);
+
+impl ReflectVisitor for Printer {
+    fn visit_primitive(&mut self, value: PrimitiveValue) {
+        match value {
+            PrimitiveValue::String(s) => self.0 += &format!("{s:?}"),
+            PrimitiveValue::Bool(b) => self.0 += &b.to_string(),
+            PrimitiveValue::Entity(e) => self.0 += &format!("#{}", *e != 0),
+            value => self.0 += &value.as_f64().unwrap().to_string(),
+        }
+    }
+
+    fn visit_enum(&mut self, value: &EnumValue) {
+        self.0 += value.name().unwrap();
+    }
+
+    fn enter_struct(&mut self, _value: &StructValue) {
+        self.0 += "{";
+    }
+
+    fn exit_struct(&mut self, _value: &StructValue) {
+        self.0 += "}";
+    }
+
+    fn enter_member(&mut self, member: &ReflectMember) {
+        self.0 += member.name();
+        self.0 += ":";
+        if member.count() > 1 {
+            self.0 += "[";
+        }
+    }
+
+    fn exit_member(&mut self, member: &ReflectMember) {
+        if member.count() > 1 {
+            self.0 += "]";
+        }
+        self.0 += ";";
+    }
+
+    fn enter_opaque(&mut self, value: &OpaqueValue) {
+        if value.as_type().has::<flecs::meta::Vector>() {
+            self.0 += "[";
+        }
+    }
+
+    fn exit_opaque(&mut self, value: &OpaqueValue) {
+        if value.as_type().has::<flecs::meta::Vector>() {
+            self.0 += "]";
+        }
+    }
+
+    fn enter_element(&mut self, index: usize) {
+        if index > 0 {
+            self.0 += ",";
+        }
+    }
+}
+
+fn character(world: &World) -> Character {
+    Character {
+        name: "Bob".to_string(),
+        health: 100,
+        alive: true,
+        color: Color::Blue,
+        inventory: vec![1, 2],
+        target: world.entity().id(),
+    }
+}
+
+#[test]
+fn reflect_struct_nodes() {
+    let world = World::new();
+    world.component::<Color>().meta();
+    world.component::<Character>().meta();
+
+    let value = character(&world);
+    let reflected = ReflectValue::of(&world, &value);
+    assert_eq!(reflected.type_id(), world.component_id::<Character>());
+
+    let Some(ReflectKind::Struct(character)) = reflected.kind() else {
+        panic!("expected a struct");
+    };
+    assert_eq!(character.len(), 6);
+    let names: Vec<&str> = character.members().map(|m| m.name()).collect();
+    assert_eq!(
+        names,
+        ["name", "health", "alive", "color", "inventory", "target"]
+    );
+
+    let health = character.member("health").unwrap();
+    assert_eq!(health.type_entity().id(), world.component_id::<i32>());
+    match health.value().kind() {
+        Some(ReflectKind::Primitive(value)) => {
+            assert_eq!(value, PrimitiveValue::I32(100));
+            assert_eq!(value.as_i64(), Some(100));
+            assert_eq!(value.as_u64(), Some(100));
+            assert_eq!(value.as_f64(), Some(100.0));
+            assert_eq!(value.as_str(), None);
+        }
+        _ => panic!("expected a primitive"),
+    }
+
+    match character.member("color").unwrap().value().kind() {
+        Some(ReflectKind::Enum(color)) => {
+            assert_eq!(color.value(), 2);
+            assert_eq!(color.name(), Some("Blue"));
+            assert_eq!(color.constants(), [("Red", 0), ("Green", 1), ("Blue", 2)]);
+        }
+        _ => panic!("expected an enum"),
+    }
+
+    match character.member("target").unwrap().value().kind() {
+        Some(ReflectKind::Primitive(PrimitiveValue::Entity(e))) => assert_eq!(e, value.target),
+        _ => panic!("expected an entity"),
+    }
+
+    // Rust strings and vectors are opaque types
+    match character.member("name").unwrap().value().kind() {
+        Some(ReflectKind::Opaque(name)) => {
+            assert_eq!(
+                name.as_type().id(),
+                world.component_id::<flecs::meta::String>()
+            );
+            let mut strings = Vec::new();
+            name.for_each(|member, value| {
+                assert!(member.is_none());
+                if let Some(ReflectKind::Primitive(value)) = value.kind() {
+                    strings.push(value.as_str().unwrap().to_owned());
+                }
+            });
+            assert_eq!(strings, ["Bob"]);
+        }
+        _ => panic!("expected an opaque type"),
+    }
+
+    match character.member("inventory").unwrap().value().kind() {
+        Some(ReflectKind::Opaque(inventory)) => {
+            let mut elements = Vec::new();
+            inventory.for_each(|_, value| {
+                if let Some(ReflectKind::Primitive(value)) = value.kind() {
+                    elements.push(value.as_i64().unwrap());
+                }
+            });
+            assert_eq!(elements, [1, 2]);
+        }
+        _ => panic!("expected an opaque type"),
+    }
+}
+
+#[test]
+fn reflect_visitor() {
+    let world = World::new();
+    world.component::<Color>().meta();
+    world.component::<Character>().meta();
+
+    let value = character(&world);
+    let mut printer = Printer::default();
+    ReflectValue::of(&world, &value).visit(&mut printer);
+    assert_eq!(
+        printer.0,
+        r#"{name:Some("Bob");health:100;alive:true;color:Blue;inventory:[1,2];target:#true;}"#
+    );
+
+    world
+        .component::<Stats>()
+        .member::<i32>(("values", Count(3)))
+        .member::<f32>("speed");
+
+    let stats = Stats {
+        values: [1, 2, 3],
+        speed: 0.5,
+    };
+    let mut printer = Printer::default();
+    ReflectValue::of(&world, &stats).visit(&mut printer);
+    assert_eq!(printer.0, "{values:[1,2,3];speed:0.5;}");
+}
+
+#[test]
+fn reflect_member_unit_and_ranges() {
+    let world = World::new();
+    world.import::<Units>();
+
+    #[derive(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    world
+        .component::<Position>()
+        .member_unit::<f32>(world.component_id::<length::Meters>(), "x")
+        .range(-1.0, 1.0)
+        .warning_range(-2.0, 2.0)
+        .error_range(-3.0, 3.0)
+        .member::<f32>("y");
+
+    let value = Position { x: 0.5, y: 4.0 };
+    let Some(ReflectKind::Struct(position)) = ReflectValue::of(&world, &value).kind() else {
+        panic!("expected a struct");
+    };
+
+    let x = position.member("x").unwrap();
+    assert_eq!(
+        x.unit().unwrap().id(),
+        world.component_id::<length::Meters>()
+    );
+    assert_eq!(x.range(), Some(-1.0..=1.0));
+    assert_eq!(x.warning_range(), Some(-2.0..=2.0));
+    assert_eq!(x.error_range(), Some(-3.0..=3.0));
+
+    let y = position.member("y").unwrap();
+    assert!(y.unit().is_none());
+    assert_eq!(y.range(), None);
+    match y.value().kind() {
+        Some(ReflectKind::Primitive(value)) => assert_eq!(value.as_f64(), Some(4.0)),
+        _ => panic!("expected a primitive"),
+    }
+}
+
+#[test]
+fn reflect_bitmask() {
+    let world = World::new();
+
+    #[derive(Component)]
+    struct Toppings {
+        value: u32,
+    }
+
+    world
+        .component::<Toppings>()
+        .bit("bacon", 0x1)
+        .bit("lettuce", 0x2)
+        .bit("tomato", 0x4);
+
+    let toppings = Toppings { value: 0x1 | 0x4 };
+    match ReflectValue::of(&world, &toppings).kind() {
+        Some(ReflectKind::Bitmask(bitmask)) => {
+            assert_eq!(bitmask.value(), 0x5);
+            assert_eq!(bitmask.flags(), ["bacon", "tomato"]);
+            assert_eq!(
+                bitmask.constants(),
+                [("bacon", 0x1), ("lettuce", 0x2), ("tomato", 0x4)]
+            );
+        }
+        _ => panic!("expected a bitmask"),
+    }
+}
+
+#[test]
+fn reflect_each_reflected() {
+    let world = World::new();
+    world.component::<Color>().meta();
+
+    #[derive(Component)]
+    struct Unreflected {
+        value: i32,
+    }
+
+    #[derive(Component)]
+    struct Tag;
+
+    let e = world
+        .entity()
+        .set(Color::Green)
+        .set(Unreflected { value: 1 })
+        .add::<Tag>();
+
+    let mut visited = Vec::new();
+    e.each_reflected(|id, value| {
+        assert_eq!(value.type_id(), id.type_id().id());
+        if let Some(ReflectKind::Enum(color)) = value.kind() {
+            visited.push(color.name().unwrap().to_owned());
+        }
+    });
+    assert_eq!(visited, ["Green"]);
+}
+
+#[test]
+fn reflect_no_reflection_data() {
+    let world = World::new();
+
+    #[derive(Component)]
+    struct Unreflected {
+        value: i32,
+    }
+
+    let value = Unreflected { value: 1 };
+    let reflected = ReflectValue::of(&world, &value);
+    assert!(reflected.kind().is_none());
+
+    #[derive(Default)]
+    struct Unknown(usize);
+
+    impl ReflectVisitor for Unknown {
+        fn visit_unknown(&mut self, _value: &ReflectValue) {
+            self.0 += 1;
+        }
+    }
+
+    let mut unknown = Unknown::default();
+    reflected.visit(&mut unknown);
+    assert_eq!(unknown.0, 1);
+}
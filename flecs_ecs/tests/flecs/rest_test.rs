@@ -0,0 +1,122 @@
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn rest_get_entity() {
+    let world = World::new();
+    world.component::<Position>().meta();
+
+    let earth = world
+        .entity_named("Sun::Earth")
+        .set(Position { x: 10.0, y: 20.0 });
+
+    let reply = world.rest_request("GET", "/entity/Sun/Earth?values=true", None);
+    assert_eq!(reply.code, 200);
+    assert!(reply.is_ok());
+    assert_eq!(reply.status, "OK");
+    assert_eq!(reply.content_type, "application/json");
+    assert!(reply.body.contains("\"name\":\"Earth\""));
+    assert!(reply.body.contains("\"x\":10"));
+    assert!(reply.body.contains("\"y\":20"));
+    assert_eq!(earth.name(), "Earth");
+}
+
+#[test]
+fn rest_get_query() {
+    let world = World::new();
+    world.component::<Position>().meta();
+
+    world.entity_named("e1").set(Position { x: 1.0, y: 2.0 });
+    world.entity_named("e2").set(Position { x: 3.0, y: 4.0 });
+
+    let position = world.component::<Position>().path_w_sep(".", "").unwrap();
+    let reply = world.rest_request("GET", &format!("/query?expr={}", position), None);
+    assert_eq!(reply.code, 200);
+    assert!(reply.body.contains("\"e1\""));
+    assert!(reply.body.contains("\"e2\""));
+}
+
+#[test]
+fn rest_get_world() {
+    let world = World::new();
+    world.entity_named("MyEntity");
+
+    let reply = world.rest_request("GET", "/world", None);
+    assert_eq!(reply.code, 200);
+    assert!(reply.body.contains("MyEntity"));
+}
+
+#[test]
+fn rest_put_component() {
+    let world = World::new();
+    world.component::<Position>().meta();
+
+    let e = world.entity_named("e").set(Position { x: 1.0, y: 2.0 });
+
+    let position = world.component::<Position>().path_w_sep(".", "").unwrap();
+    let path = format!(
+        "/component/e?component={}&value=%7B%22x%22%3A5%2C%22y%22%3A6%7D",
+        position
+    );
+    let reply = world.rest_request("PUT", &path, None);
+    assert_eq!(reply.code, 200);
+
+    e.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 5.0);
+        assert_eq!(pos.y, 6.0);
+    });
+}
+
+#[test]
+fn rest_put_script() {
+    let world = World::new();
+    world.entity_named("main");
+
+    let reply = world.rest_request("PUT", "/script/main?code=my_entity%20%7B%7D", None);
+    assert_eq!(reply.code, 200);
+    assert!(world.try_lookup("my_entity").is_some());
+}
+
+#[test]
+fn rest_request_body() {
+    let world = World::new();
+
+    // the body is forwarded to the endpoint, even if it is not used by it
+    let reply = world.rest_request("GET", "/world", Some("{\"unused\": true}"));
+    assert_eq!(reply.code, 200);
+}
+
+#[test]
+fn rest_not_found() {
+    let world = World::new();
+
+    let reply = world.rest_request("GET", "/does_not_exist", None);
+    assert_eq!(reply.code, 404);
+    assert!(!reply.is_ok());
+
+    let reply = world.rest_request("GET", "/entity/DoesNotExist", None);
+    assert!(!reply.is_ok());
+}
+
+#[test]
+fn rest_request_line_break() {
+    let world = World::new();
+    world.entity_named("Sun");
+
+    let reply = world.rest_request(
+        "GET",
+        "/entity/Sun HTTP/1.1\r\n\r\nDELETE /entity/Sun",
+        None,
+    );
+    assert_eq!(reply.code, 400);
+    assert!(world.try_lookup("Sun").is_some());
+
+    let reply = world.rest_request("GET\n", "/entity/Sun", None);
+    assert_eq!(reply.code, 400);
+}
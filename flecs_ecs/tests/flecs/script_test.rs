@@ -0,0 +1,241 @@
+#![allow(clippy::float_cmp)]
+use flecs_ecs::prelude::script::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component, Default)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn script_vars_declare_get() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 10.0f32)
+        .declare("count", 5i32)
+        .declare("pos", Position { x: 1.0, y: 2.0 });
+
+    assert!(vars.has("x"));
+    assert!(!vars.has("y"));
+    assert_eq!(vars.get::<f32>("x"), Some(&10.0));
+    assert_eq!(vars.get::<i32>("count"), Some(&5));
+    assert!(vars.get::<i32>("y").is_none());
+
+    let pos = vars.get::<Position>("pos").unwrap();
+    assert_eq!(pos.x, 1.0);
+    assert_eq!(pos.y, 2.0);
+
+    *vars.get_mut::<i32>("count").unwrap() = 6;
+    assert_eq!(vars.get::<i32>("count"), Some(&6));
+}
+
+#[test]
+fn script_vars_push_pop() {
+    let world = World::new();
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 10i32);
+
+    vars.push().declare("x", 20i32).declare("y", 30i32);
+    assert_eq!(vars.get::<i32>("x"), Some(&20));
+    assert_eq!(vars.get::<i32>("y"), Some(&30));
+
+    vars.pop();
+    assert_eq!(vars.get::<i32>("x"), Some(&10));
+    assert!(!vars.has("y"));
+}
+
+#[test]
+fn script_eval_w_vars() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 10.0f32).declare("y", 20.0f32);
+
+    let script = Script::parse(&world, "main", "e { Position: {$x, $y * 2} }").unwrap();
+    assert!(script.eval_w_vars(&vars));
+
+    let e = world.lookup("e");
+    e.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 10.0);
+        assert_eq!(pos.y, 40.0);
+    });
+
+    // reevaluate with a different value
+    *vars.get_mut::<f32>("x").unwrap() = 30.0;
+    assert!(script.eval_w_vars(&vars));
+    e.get::<&Position>(|pos| assert_eq!(pos.x, 30.0));
+}
+
+#[test]
+fn script_eval_w_vars_struct() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let mut vars = world.script_vars();
+    vars.declare("pos", Position { x: 1.0, y: 2.0 });
+
+    let script = Script::parse(&world, "main", "e { Position: {$pos.x, $pos.y} }").unwrap();
+    assert!(script.eval_w_vars(&vars));
+
+    world.lookup("e").get::<&Position>(|pos| {
+        assert_eq!(pos.x, 1.0);
+        assert_eq!(pos.y, 2.0);
+    });
+}
+
+#[test]
+fn script_eval_w_vars_shadowed_by_script() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 10.0f32);
+
+    let script = Script::parse(&world, "main", "const x = 5.0\ne { Position: {$x, $x} }").unwrap();
+    assert!(script.eval_w_vars(&vars));
+
+    world
+        .lookup("e")
+        .get::<&Position>(|pos| assert_eq!(pos.x, 5.0));
+
+    // variables declared by the script do not leak into the vars
+    assert_eq!(vars.get::<f32>("x"), Some(&10.0));
+}
+
+#[test]
+fn script_eval_w_vars_missing() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let vars = world.script_vars();
+    let script = Script::parse(&world, "main", "e { Position: {$x, $y} }").unwrap();
+    assert!(!script.eval_w_vars(&vars));
+}
+
+#[test]
+fn script_update_w_vars() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let script = world.script_named("my_script").build_from_code("");
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 1.0f32);
+    assert!(script.update_w_vars(&world, None::<Entity>, "e { Position: {$x, $x} }", &vars));
+    world
+        .lookup("e")
+        .get::<&Position>(|pos| assert_eq!(pos.x, 1.0));
+
+    *vars.get_mut::<f32>("x").unwrap() = 2.0;
+    assert!(script.update_w_vars(&world, None::<Entity>, "e { Position: {$x, $x} }", &vars));
+    world
+        .lookup("e")
+        .get::<&Position>(|pos| assert_eq!(pos.x, 2.0));
+}
+
+#[test]
+fn script_update_w_vars_deferred() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let script = world.script_named("my_script").build_from_code("");
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 3.0f32);
+
+    world.defer_begin();
+    assert!(script.update_w_vars(&world, None::<Entity>, "e { Position: {$x, $x} }", &vars));
+    world.defer_end();
+
+    world
+        .lookup("e")
+        .get::<&Position>(|pos| assert_eq!(pos.x, 3.0));
+}
+
+#[test]
+fn script_eval_expr() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let pos: Position = world.eval_expr("{x: 10 + 5, y: 20}", None).unwrap();
+    assert_eq!(pos.x, 15.0);
+    assert_eq!(pos.y, 20.0);
+
+    let pos: Position = world.eval_expr("{y: 2}", None).unwrap();
+    assert_eq!(pos.x, 0.0);
+    assert_eq!(pos.y, 2.0);
+
+    let value: i32 = world.eval_expr("10 * 4 + 2", None).unwrap();
+    assert_eq!(value, 42);
+
+    let value: f64 = world.eval_expr(" 1.5 ", None).unwrap();
+    assert_eq!(value, 1.5);
+}
+
+#[test]
+fn script_eval_expr_w_vars() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let mut vars = world.script_vars();
+    vars.declare("speed", 2.5f32)
+        .declare("pos", Position { x: 1.0, y: 2.0 });
+
+    let pos: Position = world
+        .eval_expr("{x: $speed * 2, y: $pos.y}", Some(&vars))
+        .unwrap();
+    assert_eq!(pos.x, 5.0);
+    assert_eq!(pos.y, 2.0);
+
+    let pos: Position = world.eval_expr("$pos", Some(&vars)).unwrap();
+    assert_eq!(pos.x, 1.0);
+    assert_eq!(pos.y, 2.0);
+}
+
+#[test]
+fn script_eval_expr_error() {
+    let world = World::new();
+    world.component_named::<Position>("Position").meta();
+
+    let result = world.eval_expr::<Position>("{x: $missing}", None);
+    assert_eq!(
+        result.err(),
+        Some(ScriptError::Eval {
+            expr: "{x: $missing}".to_string()
+        })
+    );
+
+    let result = world.eval_expr::<i32>("10 20", None);
+    assert!(matches!(
+        result,
+        Err(ScriptError::TrailingInput { offset: 3, .. })
+    ));
+}
+
+#[test]
+fn script_interpolate() {
+    let world = World::new();
+
+    let mut vars = world.script_vars();
+    vars.declare("count", 3i32);
+
+    assert_eq!(
+        world
+            .interpolate("{$count * 2} apples", Some(&vars))
+            .unwrap(),
+        "6 apples"
+    );
+    assert_eq!(world.interpolate("$count", Some(&vars)).unwrap(), "3");
+    assert_eq!(
+        world.interpolate("\\$count", Some(&vars)).unwrap(),
+        "$count"
+    );
+    assert_eq!(world.interpolate("{1 + 1}", None).unwrap(), "2");
+    assert!(world.interpolate("$missing", None).is_err());
+}
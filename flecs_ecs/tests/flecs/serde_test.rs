@@ -0,0 +1,196 @@
+use bincode::Options;
+use flecs_ecs::prelude::*;
+use serde::de::DeserializeSeed;
+
+#[derive(Debug, Component, PartialEq)]
+#[repr(C)]
+#[meta]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Component, PartialEq)]
+#[meta]
+struct Character {
+    name: String,
+    health: i32,
+    speed: f32,
+    alive: bool,
+    color: Color,
+    inventory: Vec<i32>,
+    target: Entity,
+}
+
+impl Default for Character {
+    fn default() -> Self {
+        Character {
+            name: String::new(),
+            health: 0,
+            speed: 0.0,
+            alive: false,
+            color: Color::Red,
+            inventory: Vec::new(),
+            target: Entity::null(),
+        }
+    }
+}
+
+fn register(world: &World) {
+    world.component::<Color>().meta();
+    world.component::<Character>().meta();
+}
+
+fn from_json<T: ComponentId>(world: &World, value: &mut T, json: &str) -> serde_json::Result<()> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    ReflectDeserializer::of(world, value).deserialize(&mut deserializer)
+}
+
+#[test]
+fn serde_struct_round_trip() {
+    let world = World::new();
+    register(&world);
+
+    let enemy = world.entity_named("enemy");
+    let character = Character {
+        name: "Bob".to_string(),
+        health: 100,
+        speed: 2.5,
+        alive: true,
+        color: Color::Blue,
+        inventory: vec![1, 2, 3],
+        target: enemy.id(),
+    };
+
+    let json = serde_json::to_string(&ReflectSerializer::of(&world, &character)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"Bob","health":100,"speed":2.5,"alive":true,"color":"Blue","inventory":[1,2,3],"target":"enemy"}"#
+    );
+
+    let mut result = Character::default();
+    from_json(&world, &mut result, &json).unwrap();
+    assert_eq!(result, character);
+}
+
+#[test]
+fn serde_deserialize_partial() {
+    let world = World::new();
+    register(&world);
+
+    let mut character = Character {
+        name: "Bob".to_string(),
+        health: 100,
+        inventory: vec![1, 2, 3],
+        ..Default::default()
+    };
+
+    from_json(&world, &mut character, r#"{"health":50,"inventory":[4]}"#).unwrap();
+    assert_eq!(character.name, "Bob");
+    assert_eq!(character.health, 50);
+    assert_eq!(character.inventory, vec![4]);
+}
+
+#[test]
+fn serde_deserialize_errors() {
+    let world = World::new();
+    register(&world);
+
+    let mut character = Character::default();
+    let err = from_json(&world, &mut character, r#"{"mana":10}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown field `mana`"));
+
+    let err = from_json(&world, &mut character, r#"{"color":"Purple"}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown variant `Purple`"));
+
+    let err = from_json(&world, &mut character, r#"{"target":"missing"}"#).unwrap_err();
+    assert!(err.to_string().contains("unresolved entity 'missing'"));
+
+    let err = from_json(&world, &mut character, r#"{"health":3000000000}"#).unwrap_err();
+    assert!(err.to_string().contains("cannot assign"));
+}
+
+#[test]
+fn serde_bitmask() {
+    let world = World::new();
+
+    #[derive(Component, Default)]
+    struct Toppings {
+        value: u32,
+    }
+
+    world
+        .component::<Toppings>()
+        .bit("bacon", 0x1)
+        .bit("lettuce", 0x2)
+        .bit("tomato", 0x4);
+
+    let toppings = Toppings { value: 0x1 | 0x4 };
+    let json = serde_json::to_string(&ReflectSerializer::of(&world, &toppings)).unwrap();
+    assert_eq!(json, r#""bacon|tomato""#);
+
+    let mut result = Toppings::default();
+    from_json(&world, &mut result, r#""lettuce | tomato""#).unwrap();
+    assert_eq!(result.value, 0x2 | 0x4);
+}
+
+#[test]
+fn serde_entity_serialize_with() {
+    let world = World::new();
+    register(&world);
+
+    #[derive(Component)]
+    struct Npc;
+
+    #[derive(Component)]
+    struct Unreflected {
+        value: i32,
+    }
+
+    let e = world
+        .entity()
+        .set(Character {
+            name: "Alice".to_string(),
+            ..Default::default()
+        })
+        .set(Unreflected { value: 10 })
+        .add::<Npc>();
+
+    let mut json = Vec::new();
+    e.serialize_with(&mut serde_json::Serializer::new(&mut json))
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(json).unwrap(),
+        r##"{"flecs.serde_test.Character":{"name":"Alice","health":0,"speed":0.0,"alive":false,"color":"Red","inventory":[],"target":"#0"},"flecs.serde_test.serde_entity_serialize_with.Npc":null}"##
+    );
+}
+
+#[test]
+fn serde_binary_round_trip() {
+    let world = World::new();
+    register(&world);
+
+    let enemy = world.entity_named("enemy");
+    let character = Character {
+        name: "Bob".to_string(),
+        health: -5,
+        speed: 1.5,
+        alive: true,
+        color: Color::Green,
+        inventory: vec![7, 8],
+        target: enemy.id(),
+    };
+
+    let options = bincode::DefaultOptions::new();
+    let bytes = options
+        .serialize(&ReflectSerializer::of(&world, &character))
+        .unwrap();
+
+    let mut result = Character::default();
+    let mut deserializer = bincode::Deserializer::from_slice(&bytes, options);
+    ReflectDeserializer::of(&world, &mut result)
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(result, character);
+}
@@ -0,0 +1,450 @@
+#![allow(clippy::float_cmp)]
+use flecs_ecs::prelude::*;
+
+use crate::common_test::create_world_with_meta;
+
+#[derive(Component)]
+#[meta]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Clone)]
+#[meta]
+struct Label {
+    text: String,
+}
+
+#[derive(Component)]
+struct Unreflected {
+    value: i32,
+}
+
+#[derive(Component)]
+struct NotClone {
+    _text: String,
+}
+
+#[derive(Component)]
+#[meta]
+struct Notes {
+    text: String,
+    lines: Vec<i32>,
+    author: Entity,
+}
+
+#[derive(Component, Clone)]
+struct Precise {
+    value: f64,
+    nan: f64,
+    inf: f32,
+}
+
+#[derive(Component)]
+struct Likes;
+
+#[derive(Component)]
+struct Start;
+
+#[derive(Component)]
+struct Tag;
+
+#[test]
+fn snapshot_restore_values() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e = world
+        .entity_named("e")
+        .set(Position { x: 10.0, y: 20.0 })
+        .set(Label {
+            text: "hello".to_string(),
+        })
+        .add::<Tag>();
+
+    let snapshot = world.snapshot().unwrap();
+    assert!(snapshot.contains(e));
+    assert!(!snapshot.is_filtered());
+
+    e.set(Position { x: 30.0, y: 40.0 })
+        .set(Label {
+            text: "world".to_string(),
+        })
+        .remove::<Tag>();
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(e.has::<Tag>());
+    e.get::<(&Position, &Label)>(|(pos, label)| {
+        assert_eq!(pos.x, 10.0);
+        assert_eq!(pos.y, 20.0);
+        assert_eq!(label.text, "hello");
+    });
+}
+
+#[test]
+fn snapshot_restore_added_removed_components() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+
+    let snapshot = world.snapshot().unwrap();
+
+    e.remove::<Position>().add::<Tag>();
+    assert!(!e.has::<Position>());
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(!e.has::<Tag>());
+    e.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 1.0);
+        assert_eq!(pos.y, 2.0);
+    });
+}
+
+#[test]
+fn snapshot_restore_created_deleted_entities() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e1 = world.entity_named("e1").set(Position { x: 1.0, y: 2.0 });
+    let e2 = world.entity();
+
+    let snapshot = world.snapshot().unwrap();
+
+    e1.destruct();
+    e2.destruct();
+    let e3 = world.entity_named("e3").add::<Tag>();
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(e1.is_alive());
+    assert!(e2.is_alive());
+    assert!(!e3.is_alive());
+    assert_eq!(world.lookup("e1"), e1);
+    assert!(world.try_lookup("e3").is_none());
+    e1.get::<&Position>(|pos| assert_eq!(pos.x, 1.0));
+}
+
+#[test]
+fn snapshot_restore_names() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e = world.entity_named("foo");
+    let unnamed = world.entity();
+
+    let snapshot = world.snapshot().unwrap();
+
+    e.set_name("bar");
+    unnamed.set_name("foo");
+
+    world.restore(&snapshot).unwrap();
+
+    assert_eq!(e.name(), "foo");
+    assert!(unnamed.get_name().is_none());
+    assert_eq!(world.lookup("foo"), e);
+}
+
+#[test]
+fn snapshot_restore_hierarchy() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let parent = world.entity_named("parent");
+    let child = world
+        .entity_named("child")
+        .child_of_id(parent)
+        .set(Position { x: 1.0, y: 2.0 });
+    let grandchild = world.entity_named("grandchild").child_of_id(child);
+
+    let snapshot = world.snapshot().unwrap();
+
+    // deleting the parent deletes the children
+    parent.destruct();
+    assert!(!child.is_alive());
+    assert!(!grandchild.is_alive());
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(parent.is_alive());
+    assert!(child.is_alive());
+    assert!(grandchild.is_alive());
+    assert_eq!(world.lookup("parent::child"), child);
+    assert_eq!(world.lookup("parent::child::grandchild"), grandchild);
+    assert!(child.has_id((flecs::ChildOf::ID, parent)));
+    child.get::<&Position>(|pos| assert_eq!(pos.x, 1.0));
+}
+
+#[test]
+fn snapshot_restore_reparented() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let parent_a = world.entity_named("a");
+    let parent_b = world.entity_named("b");
+    let child = world.entity_named("child").child_of_id(parent_a);
+
+    let snapshot = world.snapshot().unwrap();
+
+    child.child_of_id(parent_b);
+    let new_child = world.entity_named("child").child_of_id(parent_a);
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(!new_child.is_alive());
+    assert!(child.has_id((flecs::ChildOf::ID, parent_a)));
+    assert!(!child.has_id((flecs::ChildOf::ID, parent_b)));
+    assert_eq!(world.lookup("a::child"), child);
+    assert!(world.try_lookup("b::child").is_none());
+}
+
+#[test]
+fn snapshot_restore_pairs() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let bob = world.entity_named("Bob");
+    let alice = world.entity_named("Alice");
+    let e = world
+        .entity()
+        .add_first::<Likes>(bob)
+        .set_pair::<Position, Start>(Position { x: 5.0, y: 6.0 });
+
+    let snapshot = world.snapshot().unwrap();
+
+    e.remove_first::<Likes>(bob)
+        .add_first::<Likes>(alice)
+        .set_pair::<Position, Start>(Position { x: 7.0, y: 8.0 });
+    bob.destruct();
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(bob.is_alive());
+    assert!(e.has_first::<Likes>(bob));
+    assert!(!e.has_first::<Likes>(alice));
+    e.get::<&(Position, Start)>(|pos| {
+        assert_eq!(pos.x, 5.0);
+        assert_eq!(pos.y, 6.0);
+    });
+}
+
+#[test]
+fn snapshot_restore_prefab() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let prefab = world
+        .prefab_named("prefab")
+        .set(Position { x: 1.0, y: 2.0 });
+    world.prefab_named("child").child_of_id(prefab).add::<Tag>();
+
+    let instance = world.entity_named("instance").is_a_id(prefab);
+    instance.set(Position { x: 3.0, y: 4.0 });
+    let instance_child = world.lookup("instance::child");
+
+    let snapshot = world.snapshot().unwrap();
+    assert!(snapshot.contains(prefab));
+
+    instance.destruct();
+    prefab.set(Position { x: 10.0, y: 20.0 });
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(prefab.has::<flecs::Prefab>());
+    prefab.get::<&Position>(|pos| assert_eq!(pos.x, 1.0));
+
+    assert!(instance.is_alive());
+    assert!(instance.has_id((flecs::IsA::ID, prefab)));
+    instance.get::<&Position>(|pos| assert_eq!(pos.x, 3.0));
+
+    // the instance children are restored without creating duplicates
+    assert!(instance_child.is_alive());
+    assert_eq!(world.lookup("instance::child"), instance_child);
+    let mut count = 0;
+    instance.each_child(|_| count += 1);
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn snapshot_query() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e1 = world.entity().set(Position { x: 1.0, y: 2.0 });
+    let e2 = world.entity().set(Position { x: 3.0, y: 4.0 }).add::<Tag>();
+    let e3 = world.entity().add::<Tag>();
+
+    let query = world.query::<&Position>().with::<Tag>().build();
+    let snapshot = world.snapshot_query(&query).unwrap();
+    assert!(snapshot.is_filtered());
+    assert_eq!(snapshot.count(), 1);
+    assert!(snapshot.contains(e2));
+
+    e1.set(Position { x: 10.0, y: 20.0 });
+    e2.set(Position { x: 30.0, y: 40.0 });
+    e3.remove::<Tag>();
+    let e4 = world.entity();
+
+    world.restore(&snapshot).unwrap();
+
+    // entities that are not in a filtered snapshot are not touched
+    assert!(e4.is_alive());
+    assert!(!e3.has::<Tag>());
+    e1.get::<&Position>(|pos| assert_eq!(pos.x, 10.0));
+    e2.get::<&Position>(|pos| assert_eq!(pos.x, 3.0));
+}
+
+#[test]
+fn snapshot_unreflected_component() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e = world.entity().set(Unreflected { value: 10 });
+
+    let snapshot = world.snapshot().unwrap();
+
+    e.set(Unreflected { value: 20 });
+
+    world.restore(&snapshot).unwrap();
+
+    // values are stored without reflection data
+    e.get::<&Unreflected>(|u| assert_eq!(u.value, 10));
+}
+
+#[test]
+fn snapshot_exact_values() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let value = 0.1 + 0.2;
+    let e = world.entity().set(Precise {
+        value,
+        nan: f64::NAN,
+        inf: f32::INFINITY,
+    });
+
+    let snapshot = world.snapshot().unwrap();
+
+    e.set(Precise {
+        value: 0.0,
+        nan: 0.0,
+        inf: 0.0,
+    });
+
+    world.restore(&snapshot).unwrap();
+
+    e.get::<&Precise>(|p| {
+        assert_eq!(p.value.to_bits(), value.to_bits());
+        assert!(p.nan.is_nan());
+        assert_eq!(p.inf, f32::INFINITY);
+    });
+}
+
+#[test]
+fn snapshot_not_clone_component() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e = world.entity().set(NotClone {
+        _text: "hello".to_string(),
+    });
+
+    let err = world.snapshot().err().unwrap();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidOperation);
+    assert_eq!(err.entity(), Some(e.id()));
+    assert_eq!(err.id(), Some(world.component_id::<NotClone>().into()));
+
+    // entities without the component can still be stored
+    let query = world.query::<&Position>().build();
+    assert!(world.snapshot_query(&query).is_ok());
+}
+
+#[test]
+fn snapshot_reflected_not_clone_component() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let author = world.entity_named("author");
+    let e1 = world.entity().set(Notes {
+        text: "hello".to_string(),
+        lines: vec![1, 2, 3],
+        author: author.id(),
+    });
+    let e2 = world.entity().set(Notes {
+        text: "world".to_string(),
+        lines: vec![4],
+        author: Entity::null(),
+    });
+
+    let snapshot = world.snapshot().unwrap();
+
+    e1.set(Notes {
+        text: "changed".to_string(),
+        lines: vec![],
+        author: Entity::null(),
+    });
+    e2.destruct();
+    author.destruct();
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(author.is_alive());
+    e1.get::<&Notes>(|notes| {
+        assert_eq!(notes.text, "hello");
+        assert_eq!(notes.lines, [1, 2, 3]);
+        assert_eq!(notes.author, author.id());
+    });
+    assert!(e2.is_alive());
+    e2.get::<&Notes>(|notes| {
+        assert_eq!(notes.text, "world");
+        assert_eq!(notes.lines, [4]);
+        assert_eq!(notes.author, Entity::null());
+    });
+}
+
+#[test]
+fn snapshot_builtin_entities() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let snapshot = world.snapshot().unwrap();
+
+    // registering a component after taking a snapshot doesn't cause it to be
+    // deleted on restore
+    world.component::<Likes>();
+    let system = world.system::<&Position>().each(|_| {});
+
+    world.restore(&snapshot).unwrap();
+
+    assert!(world.component::<Likes>().is_alive());
+    assert!(system.is_alive());
+    assert!(!snapshot.contains(world.component::<Position>()));
+}
+
+#[test]
+fn snapshot_restore_errors() {
+    let world = create_world_with_meta::<(Position, Label, Notes)>();
+    let other = create_world_with_meta::<(Position, Label, Notes)>();
+
+    let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    let snapshot = world.snapshot().unwrap();
+
+    let err = other.restore(&snapshot).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+
+    e.set(Position { x: 3.0, y: 4.0 });
+    world.defer_begin();
+    let err = world.restore(&snapshot).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidOperation);
+    world.defer_end();
+    e.get::<&Position>(|pos| assert_eq!(pos.x, 3.0));
+
+    // a full restore deletes the entity that recycled the id
+    e.destruct();
+    let recycled = world.entity();
+    world.restore(&snapshot).unwrap();
+    assert!(e.is_alive());
+    assert!(!recycled.is_alive());
+
+    // the recycled id is used by an entity that a filtered restore keeps
+    let query = world.query::<&Position>().build();
+    let snapshot = world.snapshot_query(&query).unwrap();
+    e.destruct();
+    let recycled = world.entity().add::<Tag>();
+    assert_eq!(*recycled.id() as u32, *e.id() as u32);
+
+    let err = world.restore(&snapshot).unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidOperation);
+    assert_eq!(err.entity(), Some(e.id()));
+    assert!(recycled.is_alive());
+    assert!(!e.is_alive());
+}
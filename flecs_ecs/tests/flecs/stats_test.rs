@@ -0,0 +1,195 @@
+#![allow(clippy::float_cmp)]
+
+use flecs_ecs::prelude::stats::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+struct Velocity {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn stats_world_entities() {
+    let world = World::new();
+
+    let before = world.stats().entities.count.latest();
+
+    for _ in 0..10 {
+        world.entity();
+    }
+
+    let after = world.stats().entities.count.latest();
+    assert_eq!(after - before, 10.0);
+}
+
+#[test]
+fn stats_world_update_history() {
+    let world = World::new();
+    world.system::<&Position>().each(|_| {});
+
+    let mut stats = WorldStatistics::new();
+    stats.update(&world);
+
+    world.progress();
+    stats.update(&world);
+
+    world.progress();
+    world.progress();
+    stats.update(&world);
+
+    let frames = &stats.frame.frame_count;
+    assert_eq!(frames.latest(), 3.0);
+    assert_eq!(frames.delta(), 2.0);
+
+    let history: Vec<f64> = frames.history().collect();
+    assert_eq!(history, vec![0.0, 1.0, 3.0]);
+
+    assert_eq!(frames.rate().max(), 2.0);
+    assert_eq!(frames.sum(), 3.0);
+}
+
+#[test]
+fn stats_gauge_aggregates() {
+    let world = World::new();
+    let mut stats = WorldStatistics::new();
+
+    let base = world.stats().entities.count.latest();
+    stats.update(&world);
+    for _ in 0..4 {
+        world.entity();
+    }
+    stats.update(&world);
+
+    // the aggregates only include recorded samples
+    let count = &stats.entities.count;
+    assert_eq!(count.sample_count(), 2);
+    assert_eq!(count.latest(), base + 4.0);
+    assert_eq!(count.max(), base + 4.0);
+    assert_eq!(count.min(), base);
+    assert_eq!(count.mean(), (base + base + 4.0) / 2.0);
+    assert_eq!(count.history().collect::<Vec<_>>(), vec![base, base + 4.0]);
+
+    let empty = WorldStatistics::new();
+    assert_eq!(empty.entities.count.sample_count(), 0);
+    assert_eq!(empty.entities.count.mean(), 0.0);
+}
+
+#[test]
+fn stats_query_samples() {
+    let world = World::new();
+    let query = world.new_query::<&Position>();
+
+    let mut stats = QueryStatistics::new();
+    world.entity().set(Position { x: 1.0, y: 2.0 });
+    stats.update(&query);
+    world.entity().set(Position { x: 3.0, y: 4.0 });
+    stats.update(&query);
+
+    let count = &stats.matched_entity_count;
+    assert_eq!(count.avg_samples(), &[1.0, 2.0]);
+    assert_eq!(count.min(), 1.0);
+    assert_eq!(count.mean(), 1.5);
+}
+
+#[test]
+fn stats_reduce() {
+    let world = World::new();
+
+    let mut src = WorldStatistics::new();
+    src.update(&world);
+    world.entity();
+    src.update(&world);
+
+    let mut dst = WorldStatistics::new();
+    dst.reduce(&src);
+
+    let count = &dst.entities.count;
+    assert_eq!(count.latest_max(), src.entities.count.latest());
+    assert_eq!(count.latest_min(), 0.0);
+}
+
+#[test]
+fn stats_query() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1.0, y: 2.0 });
+    world.entity().set(Position { x: 3.0, y: 4.0 });
+    world
+        .entity()
+        .set(Position { x: 5.0, y: 6.0 })
+        .set(Velocity { x: 1.0, y: 1.0 });
+
+    let query = world.new_query::<&Position>();
+    let stats = query.stats();
+
+    assert_eq!(stats.matched_entity_count.latest(), 3.0);
+    assert_eq!(stats.result_count.latest(), 2.0);
+}
+
+#[test]
+fn stats_system() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1.0, y: 2.0 });
+    world.entity().set(Position { x: 3.0, y: 4.0 });
+
+    let system = world.system::<&Position>().each(|_| {});
+    world.progress();
+
+    let stats = world.system_stats(system).unwrap();
+    assert!(!stats.task);
+    assert_eq!(stats.matched_entity_count.latest(), 2.0);
+    assert!(stats.time_spent.latest() >= 0.0);
+
+    let entity = world.entity();
+    assert!(world.system_stats(entity).is_none());
+}
+
+#[test]
+fn stats_pipeline() {
+    let world = World::new();
+
+    let s1 = world.system::<&Position>().each(|_| {});
+    let s2 = world.system::<&Velocity>().each(|_| {});
+    world.system::<&Velocity>().with::<Position>().each(|_| {});
+
+    world.entity().set(Position { x: 1.0, y: 2.0 });
+    world.entity().set(Velocity { x: 1.0, y: 1.0 });
+    world.progress();
+
+    let stats = world.pipeline_stats(world.get_pipeline()).unwrap();
+    assert_eq!(stats.systems, vec![s1.id(), s2.id()]);
+
+    let sync_points = &stats.sync_points;
+    assert_eq!(sync_points.len(), 1);
+    assert_eq!(sync_points[0].system_count, 2);
+}
+
+#[test]
+fn stats_history() {
+    let world = World::new();
+    world.import::<Stats>();
+
+    let system = world.system::<&Position>().each(|_| {});
+    world.entity().set(Position { x: 1.0, y: 2.0 });
+
+    for _ in 0..3 {
+        world.progress_time(1.0);
+    }
+
+    let stats = world.stats_history(StatsPeriod::OneSecond).unwrap();
+    assert!(stats.entities.count.latest() > 0.0);
+    assert!(stats.frame.frame_count.latest() > 0.0);
+
+    let system_stats = world
+        .system_stats_history(system, StatsPeriod::OneSecond)
+        .unwrap();
+    assert_eq!(system_stats.matched_entity_count.latest(), 1.0);
+}
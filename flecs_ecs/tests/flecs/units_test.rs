@@ -0,0 +1,151 @@
+use flecs_ecs::prelude::json::*;
+use flecs_ecs::prelude::units::*;
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+#[meta]
+struct Body {
+    #[meta(unit = length::Meters)]
+    height: f32,
+    #[meta(unit = mass::KiloGrams)]
+    weight: f32,
+    age: i32,
+}
+
+#[derive(Component)]
+struct Speed {
+    value: f32,
+}
+
+fn member_unit(world: &World, component: impl Into<Entity>, member: &str) -> Entity {
+    let member = world
+        .entity_from_id(component)
+        .lookup(member)
+        .cloned::<&flecs::meta::Member>();
+    Entity::new(member.unit)
+}
+
+#[test]
+fn units_import() {
+    let world = World::new();
+    world.import::<Units>();
+
+    let meters = world.lookup("flecs::units::Length::Meters");
+    assert_eq!(world.component_id::<length::Meters>(), meters.id());
+    assert!(meters.has::<flecs::meta::Unit>());
+
+    let seconds = world.lookup("flecs::units::Duration::Seconds");
+    assert_eq!(world.component_id::<duration::Seconds>(), seconds.id());
+
+    let percentage = world.lookup("flecs::units::Percentage");
+    assert_eq!(world.component_id::<Percentage>(), percentage.id());
+
+    let length = world.lookup("flecs::units::Length");
+    assert_eq!(world.component_id::<Length>(), length.id());
+    assert!(length.has::<flecs::meta::Quantity>());
+
+    let kilo = world.lookup("flecs::units::prefixes::Kilo");
+    assert_eq!(world.component_id::<Kilo>(), kilo.id());
+}
+
+#[test]
+fn units_import_on_first_use() {
+    let world = World::new();
+
+    let bytes = world.component_id::<data::Bytes>();
+    assert_eq!(world.lookup("flecs::units::Data::Bytes").id(), bytes);
+}
+
+#[test]
+fn units_multiple_worlds() {
+    let world_a = World::new();
+    world_a.component::<Speed>();
+    let a = world_a.component_id::<length::Meters>();
+    assert_eq!(world_a.lookup("flecs::units::Length::Meters").id(), a);
+
+    let world_b = World::new();
+    let b = world_b.component_id::<length::Meters>();
+    assert_eq!(world_b.lookup("flecs::units::Length::Meters").id(), b);
+}
+
+#[test]
+fn units_member_unit_type() {
+    let world = World::new();
+    world.import::<Units>();
+
+    let c = world
+        .component::<Speed>()
+        .member_unit_type::<f32, speed::MetersPerSecond>(("value", Count(1), 0));
+
+    assert_eq!(
+        member_unit(&world, c, "value"),
+        world.component_id::<speed::MetersPerSecond>()
+    );
+}
+
+#[test]
+fn units_member_unit() {
+    let world = World::new();
+    world.import::<Units>();
+
+    let c = world.component::<Speed>().member_unit::<f32>(
+        world.component_id::<speed::KiloMetersPerHour>(),
+        ("value", Count(1), 0),
+    );
+
+    assert_eq!(
+        member_unit(&world, c, "value"),
+        world.component_id::<speed::KiloMetersPerHour>()
+    );
+}
+
+#[test]
+fn units_meta_attribute() {
+    let world = World::new();
+    world.import::<Units>();
+
+    let c = world.component::<Body>().meta();
+
+    assert_eq!(
+        member_unit(&world, c, "height"),
+        world.component_id::<length::Meters>()
+    );
+    assert_eq!(
+        member_unit(&world, c, "weight"),
+        world.component_id::<mass::KiloGrams>()
+    );
+    assert_eq!(member_unit(&world, c, "age"), Entity::new(0));
+}
+
+#[test]
+fn units_to_json_type_info() {
+    let world = World::new();
+    world.component::<Body>().meta();
+
+    let e = world.entity_named("e").set(Body {
+        height: 1.8,
+        weight: 75.0,
+        age: 30,
+    });
+
+    let desc = EntityToJsonDesc {
+        serialize_entity_id: false,
+        serialize_doc: false,
+        serialize_full_paths: true,
+        serialize_inherited: false,
+        serialize_values: true,
+        serialize_builtin: false,
+        serialize_type_info: true,
+        serialize_alerts: false,
+        serialize_refs: 0,
+        serialize_matches: false,
+    };
+    let json = e.to_json(Some(&desc));
+
+    assert!(
+        json.contains("\"unit\":\"flecs.units.Length.Meters\""),
+        "{json}"
+    );
+    assert!(json.contains("\"symbol\":\"m\""), "{json}");
+    assert!(json.contains("\"symbol\":\"kg\""), "{json}");
+}
@@ -65,3 +65,17 @@ fn world_finis_reentrancy() {
 
     // world will be destroyed here, and hook above will be called.
 }
+
+#[test]
+fn world_try_lookup_path() {
+    let world = World::new();
+
+    let parent = world.entity_named("parent");
+    let child = world.entity_named("child").child_of_id(parent);
+
+    assert_eq!(world.try_lookup_path("parent::child").unwrap(), child);
+
+    let err = world.try_lookup_path("parent::other").unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+    assert!(err.to_string().contains("parent::other"));
+}
@@ -0,0 +1,113 @@
+//! The allocator has to be set before the first world is created, so these
+//! tests run in their own process.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use flecs_ecs::prelude::*;
+
+#[derive(Component)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+static SYSTEM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        SYSTEM_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[test]
+fn os_allocator() {
+    assert!(os_memory_usage().is_none());
+    assert!(set_os_allocator(CountingAllocator));
+    assert!(!set_os_allocator(RustAllocator));
+
+    let world = World::new();
+    let usage = os_memory_usage().unwrap();
+    assert!(usage.live_bytes > 0);
+    assert!(usage.peak_bytes >= usage.live_bytes);
+    assert!(usage.live_allocations() > 0);
+    assert!(SYSTEM_ALLOCATIONS.load(Ordering::Relaxed) as u64 >= usage.allocation_count);
+
+    let entities: Vec<_> = (0..10_000)
+        .map(|i| {
+            world.entity().set(Position {
+                x: i as f32,
+                y: 0.0,
+            })
+        })
+        .collect();
+
+    let grown = os_memory_usage().unwrap();
+    assert!(grown.live_bytes > usage.live_bytes);
+    assert!(grown.allocation_count > usage.allocation_count);
+
+    for entity in entities {
+        entity.destruct();
+    }
+    world.query::<&Position>().build().each(|_| panic!());
+
+    // the memory statistics of the world are based on the same counters
+    let stats = world.stats();
+    assert!(stats.memory.alloc_count.latest() > 0.0);
+
+    let peak = os_memory_usage().unwrap().peak_bytes;
+    assert!(peak >= grown.live_bytes);
+
+    // a resize is counted as a resize, not as an allocation and a free
+    unsafe {
+        let api = flecs_ecs::sys::ecs_os_api;
+        let ptr = api.malloc_.unwrap()(16);
+        let before = os_memory_usage().unwrap();
+        let ptr = api.realloc_.unwrap()(ptr, 64);
+        let after = os_memory_usage().unwrap();
+        assert_eq!(after.realloc_count, before.realloc_count + 1);
+        assert_eq!(after.allocation_count, before.allocation_count);
+        assert_eq!(after.free_count, before.free_count);
+        assert_eq!(after.live_bytes, before.live_bytes + 48);
+        api.free_.unwrap()(ptr);
+    }
+
+    // the usage is process-wide, so it includes a second world
+    let before = os_memory_usage().unwrap().live_bytes;
+    let second = World::new();
+    assert!(os_memory_usage().unwrap().live_bytes > before);
+
+    drop(second);
+    drop(world);
+
+    let usage = os_memory_usage().unwrap();
+    #[cfg(not(feature = "flecs_use_os_alloc"))]
+    {
+        assert_eq!(usage.live_allocations(), 0);
+        assert_eq!(usage.live_bytes, 0);
+    }
+
+    // With `flecs_use_os_alloc`, the C library leaves the buckets of its maps to
+    // its block allocators, which don't own them in that mode, so they are not
+    // freed when a world is destroyed. This is the same amount of memory for
+    // every world, no matter how many entities it had.
+    let world = World::new();
+    for _ in 0..1_000 {
+        world.entity().set(Position { x: 0.0, y: 0.0 }).destruct();
+    }
+    drop(world);
+
+    let after = os_memory_usage().unwrap();
+    assert_eq!(
+        (after.live_allocations() - usage.live_allocations()) * 2,
+        usage.live_allocations()
+    );
+    assert_eq!((after.live_bytes - usage.live_bytes) * 2, usage.live_bytes);
+}
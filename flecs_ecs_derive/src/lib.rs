@@ -10,7 +10,8 @@ use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
     token::{Bracket, Comma},
-    Data, DeriveInput, Expr, Fields, Ident, LitInt, LitStr, Result, Token, Type,
+    Data, DeriveInput, Expr, Fields, GenericArgument, Ident, LitInt, LitStr, PathArguments, Result,
+    Token, Type,
 };
 
 /// `Component` macro for defining Flecs ECS components.
@@ -23,10 +24,29 @@ use syn::{
 /// - The `ComponentId` trait is implemented, providing storage mechanisms for the component.
 ///
 /// # Generic types
-/// - Generic types are supported, but they don't have first-class support for the `ComponentId` trait where it automatically registers the
-///   ctor and copy hooks (Default & Clone) which are used for either `EntityView::add` or `EntityView::duplicate` and some other operations.
-///   In that case, the user has to manually register the hooks for each variant of T of the generic component
-///   by using `T::register_ctor_hook` and `T::register_clone_hook`.
+/// - Generic types are supported. The ctor and copy hooks (Default & Clone), which are used by `EntityView::add`,
+///   `EntityView::duplicate` and prefab instancing, are registered for every instantiation of the component, as long as
+///   the `Default` and `Clone` implementations follow from the bounds on the type, such as `struct Generic<T: Default + Clone>`
+///   or a `where` clause.
+/// - Without such bounds the hooks can't be detected. Whether `Generic<T>` implements `Default` or `Clone` depends
+///   on `T`, and the derived impl is shared by all instantiations, which can't select an implementation per `T`
+///   on stable Rust. An unbounded `struct Generic<T>` therefore gets no ctor and copy hooks: it can't be added
+///   with `EntityView::add`, only with `EntityView::set`, and the user has to register the hooks once per world
+///   for each instantiation by using `T::register_ctor_hook` and `T::register_clone_hook` before it is added by
+///   id, duplicated or instantiated from a prefab.
+///
+/// # Reflection
+/// - With `#[meta]` on the type, reflection data is registered when `Component::meta` is called.
+///   Fields are annotated with `#[meta(...)]`, which accepts:
+///   - `unit = length::Meters`: the unit of the field, which is any component type, such as the marker
+///     types in `flecs_ecs::addons::units`.
+///   - `range(min, max)`, `warning_range(min, max)` and `error_range(min, max)`: the value ranges of a
+///     numeric field.
+///   - `name = "name"`: the name of the member, which defaults to the name of the field.
+///   - `skip`: excludes the field, the same as `#[skip]`.
+/// - Rust enums with data-carrying variants are reflected as tagged unions, which serialize the active
+///   variant as `{"Circle": {"r": 1}}`. Variants need named fields that implement `Default`, unit variants
+///   are booleans.
 ///
 /// # Enums:
 ///
@@ -42,11 +62,21 @@ use syn::{
 /// }
 ///
 /// #[derive(Component)]
-/// struct Generic<T>
+/// struct Generic<T: Default + Clone>
+/// {
+///     value: T,
+/// }
+///
+/// // without bounds, the hooks are registered for each instantiation
+/// #[derive(Component, Default, Clone)]
+/// struct Unbounded<T>
 /// {
 ///     value: T,
 /// }
 ///
+/// Unbounded::<f32>::register_ctor_hook(&world);
+/// Unbounded::<f32>::register_clone_hook(&world);
+///
 /// #[derive(Component, Default)]
 /// #[repr(C)]
 /// enum State {
@@ -107,14 +137,21 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
     }
 
     let mut meta_fields_impl = Vec::new();
+    let mut type_args = Vec::new();
 
     match input.data.clone() {
         Data::Struct(data_struct) => {
             if let Fields::Named(fields_named) = &data_struct.fields {
                 for field in &fields_named.named {
-                    let is_ignored = field.attrs.iter().any(|attr| attr.path().is_ident("skip"));
+                    let field_meta = match parse_field_meta(field) {
+                        Ok(field_meta) => field_meta,
+                        Err(err) => {
+                            meta_fields_impl.push(err.to_compile_error());
+                            continue;
+                        }
+                    };
 
-                    if is_ignored {
+                    if field_meta.skip {
                         continue;
                     }
 
@@ -122,9 +159,13 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
                     let field_type = &field.ty;
 
                     if let Some(field_name) = field_name {
-                        meta_fields_impl.push(quote! {
-                            .member_id(id!(world, #field_type), (stringify!(#field_name), flecs_ecs::addons::meta::Count(1), core::mem::offset_of!(#struct_name, #field_name)))
-                        });
+                        collect_type_args(field_type, &mut type_args);
+
+                        let member_name = field_meta.member_name(field_name);
+                        meta_fields_impl.push(field_meta.member_impl(
+                            field_type,
+                            quote! { (#member_name, flecs_ecs::addons::meta::Count(1), core::mem::offset_of!(#struct_name, #field_name)) },
+                        ));
                     } else {
                         meta_fields_impl.push( quote! {
                             compile_error!("Meta expects named fields, unnamed fields are not supported");
@@ -136,10 +177,8 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
         }
         Data::Enum(data_enum) => {
             if !has_repr_c {
-                meta_fields_impl.push( quote! {
-                    compile_error!("Meta currently does not support Rust Algebraic enums, please use #[repr(C)] if it's a C compatible enum");
-                }
-                );
+                let meta_fn_impl = impl_meta_tagged_union(&data_enum, &struct_name);
+                return meta_impl_return(meta_fn_impl, struct_name);
             } else {
                 for variant in &data_enum.variants {
                     let is_ignored = variant
@@ -166,6 +205,7 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
         use flecs_ecs::addons::meta::*;
         use flecs_ecs::core::WorldProvider;
         let world = component.world();
+        #( id!(world, #type_args); )*
         component
         #( #meta_fields_impl )*;
     };
@@ -173,6 +213,264 @@ fn impl_meta(input: &DeriveInput, has_repr_c: bool, struct_name: Ident) -> Token
     meta_impl_return(meta_fn_impl, struct_name)
 }
 
+/// Describes a Rust enum with data-carrying variants as a tagged union: an
+/// opaque type with a member for every variant, of which only the active
+/// variant is serialized. Variants with fields have an opaque type over the
+/// enum that hands out pointers to the fields, unit variants are booleans.
+fn impl_meta_tagged_union(data_enum: &syn::DataEnum, enum_name: &Ident) -> TokenStream {
+    let mut type_args = Vec::new();
+    let mut variants_impl = Vec::new();
+    let mut serialize_arms = Vec::new();
+    let mut ensure_arms = Vec::new();
+
+    for variant in &data_enum.variants {
+        let variant_name = &variant.ident;
+        let is_ignored = variant
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("skip"));
+
+        if is_ignored {
+            serialize_arms.push(quote! {
+                #enum_name::#variant_name { .. } => 0
+            });
+            continue;
+        }
+
+        let fields: Vec<&syn::Field> = match &variant.fields {
+            Fields::Named(fields_named) => fields_named.named.iter().collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => {
+                variants_impl.push(quote! {
+                    compile_error!("Meta expects named fields, unnamed fields are not supported");
+                });
+                continue;
+            }
+        };
+
+        let mut members = Vec::new();
+        let mut serialize_fields = Vec::new();
+        let mut ensure_fields = Vec::new();
+        let mut field_names = Vec::new();
+        let mut defaults = Vec::new();
+
+        for field in fields {
+            let field_name = &field.ident;
+            let field_type = &field.ty;
+            defaults.push(quote! { #field_name: ::core::default::Default::default() });
+
+            let field_meta = match parse_field_meta(field) {
+                Ok(field_meta) => field_meta,
+                Err(err) => {
+                    members.push(err.to_compile_error());
+                    continue;
+                }
+            };
+
+            if field_meta.skip {
+                continue;
+            }
+
+            collect_type_args(field_type, &mut type_args);
+            field_names.push(field_name);
+
+            let Some(field_ident) = field_name else {
+                continue;
+            };
+            let member_name = field_meta.member_name(field_ident);
+            members.push(field_meta.member_impl(field_type, quote! { #member_name }));
+
+            serialize_fields.push(quote! {
+                s.member(#member_name);
+                s.value_id(id!(world, #field_type), #field_name as *const #field_type as *const std::ffi::c_void);
+            });
+            ensure_fields.push(quote! {
+                if member == #member_name.as_bytes() {
+                    return #field_name as *mut #field_type as *mut std::ffi::c_void;
+                }
+            });
+        }
+
+        // variants without reflected fields are unit variants
+        if members.is_empty() {
+            variants_impl.push(quote! {
+                let layout = layout.member_id(id!(world, bool), stringify!(#variant_name));
+            });
+            serialize_arms.push(quote! {
+                #enum_name::#variant_name { .. } => serialize_unit_variant(s, stringify!(#variant_name))
+            });
+            ensure_arms.push(quote! {
+                if member == stringify!(#variant_name).as_bytes() {
+                    if !matches!(data, #enum_name::#variant_name { .. }) {
+                        *data = #enum_name::#variant_name { #( #defaults, )* };
+                    }
+                    return unit_variant_ptr();
+                }
+            });
+            continue;
+        }
+
+        variants_impl.push(quote! {
+            let fields = world.component_untyped() #( #members )*;
+            let variant = {
+                let mut ts = component.variant(stringify!(#variant_name), fields);
+                ts.serialize(|s: &Serializer, data: &#enum_name| {
+                    let world = unsafe { WorldRef::from_ptr(s.world as *mut flecs_ecs::sys::ecs_world_t) };
+                    if let #enum_name::#variant_name { #( #field_names, )* .. } = data {
+                        #( #serialize_fields )*
+                    }
+                    0
+                });
+                ts.ensure_member(|data: &mut #enum_name, member: *const i8| {
+                    let member = unsafe { std::ffi::CStr::from_ptr(member) }.to_bytes();
+                    if let #enum_name::#variant_name { #( #field_names, )* .. } = data {
+                        #( #ensure_fields )*
+                    }
+                    std::ptr::null_mut()
+                });
+                ts.desc.entity
+            };
+            let layout = layout.member_id(variant, stringify!(#variant_name));
+        });
+        serialize_arms.push(quote! {
+            #enum_name::#variant_name { .. } => serialize_variant(s, stringify!(#variant_name), data)
+        });
+        ensure_arms.push(quote! {
+            if member == stringify!(#variant_name).as_bytes() {
+                if !matches!(data, #enum_name::#variant_name { .. }) {
+                    *data = #enum_name::#variant_name { #( #defaults, )* };
+                }
+                return data as *mut #enum_name as *mut std::ffi::c_void;
+            }
+        });
+    }
+
+    quote! {
+        use flecs_ecs::addons::meta::*;
+        use flecs_ecs::core::WorldProvider;
+        let world = component.world();
+        #( id!(world, #type_args); )*
+        let layout = world.component_untyped();
+        #( #variants_impl )*
+        let mut ts = Opaque::<#enum_name>::new_id(world, component.id());
+        ts.as_type(layout.id());
+        ts.serialize(|s: &Serializer, data: &#enum_name| match data {
+            #( #serialize_arms, )*
+        });
+        ts.ensure_member(|data: &mut #enum_name, member: *const i8| {
+            let member = unsafe { std::ffi::CStr::from_ptr(member) }.to_bytes();
+            #( #ensure_arms )*
+            std::ptr::null_mut()
+        });
+    }
+}
+
+/// Collects the type arguments of a field type, innermost first. Collections
+/// look up the ids of their type arguments by type, so these are registered
+/// before the field types.
+fn collect_type_args(ty: &Type, type_args: &mut Vec<Type>) {
+    let Type::Path(type_path) = ty else {
+        return;
+    };
+    for segment in &type_path.path.segments {
+        if let PathArguments::AngleBracketed(args) = &segment.arguments {
+            for arg in &args.args {
+                match arg {
+                    // unsized types have no id
+                    GenericArgument::Type(Type::Path(path)) if path.path.is_ident("str") => {}
+                    GenericArgument::Type(arg) => {
+                        collect_type_args(arg, type_args);
+                        type_args.push(arg.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// The reflection attributes of a field, which are either `#[skip]` or
+/// `#[meta(unit = Type, range(min, max), warning_range(min, max),
+/// error_range(min, max), name = "name", skip)]`.
+#[derive(Default)]
+struct FieldMeta {
+    unit: Option<syn::Path>,
+    name: Option<LitStr>,
+    ranges: Vec<TokenStream>,
+    skip: bool,
+}
+
+impl FieldMeta {
+    /// The name of the member, which is the name of the field unless renamed.
+    fn member_name(&self, field_name: &Ident) -> TokenStream {
+        match &self.name {
+            Some(name) => quote! { #name },
+            None => quote! { stringify!(#field_name) },
+        }
+    }
+
+    /// Adds the member to the component, followed by its ranges. `data` are
+    /// the `MetaMember` arguments of the member.
+    fn member_impl(&self, field_type: &Type, data: TokenStream) -> TokenStream {
+        let ranges = &self.ranges;
+        match &self.unit {
+            Some(unit) => quote! {
+                .member_id_unit(id!(world, #field_type), id!(world, #unit), #data)
+                #( #ranges )*
+            },
+            None => quote! {
+                .member_id(id!(world, #field_type), #data)
+                #( #ranges )*
+            },
+        }
+    }
+}
+
+/// Parses the reflection attributes of a field.
+fn parse_field_meta(field: &syn::Field) -> Result<FieldMeta> {
+    let mut field_meta = FieldMeta {
+        skip: field.attrs.iter().any(|attr| attr.path().is_ident("skip")),
+        ..Default::default()
+    };
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("meta"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("unit") {
+                field_meta.unit = Some(meta.value()?.parse::<syn::Path>()?);
+            } else if meta.path.is_ident("name") {
+                field_meta.name = Some(meta.value()?.parse::<LitStr>()?);
+            } else if meta.path.is_ident("skip") {
+                field_meta.skip = true;
+            } else if meta.path.is_ident("range")
+                || meta.path.is_ident("warning_range")
+                || meta.path.is_ident("error_range")
+            {
+                let method = meta.path.get_ident();
+                let content;
+                parenthesized!(content in meta.input);
+                let min = content.parse::<Expr>()?;
+                content.parse::<Token![,]>()?;
+                let max = content.parse::<Expr>()?;
+                content.parse::<Option<Token![,]>>()?;
+                field_meta
+                    .ranges
+                    .push(quote! { .#method((#min) as f64, (#max) as f64) });
+            } else {
+                return Err(meta.error(
+                    "unsupported meta attribute, expected `unit`, `range`, `warning_range`, \
+                     `error_range`, `name` or `skip`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(field_meta)
+}
+
 #[cfg(feature = "flecs_meta")]
 fn meta_impl_return(meta_fn_impl: TokenStream, struct_name: Ident) -> TokenStream {
     quote! {
@@ -214,8 +512,6 @@ fn generate_tag_trait(has_fields: bool) -> proc_macro2::TokenStream {
 struct GenericTypeInfo {
     contains_type_bound: bool,
     contains_generic_type: bool,
-    is_bound_default: bool,
-    is_bound_clone: bool,
 }
 
 impl GenericTypeInfo {
@@ -226,14 +522,6 @@ impl GenericTypeInfo {
     pub fn set_contains_generic_type(&mut self) {
         self.contains_generic_type = true;
     }
-
-    pub fn set_is_bound_default(&mut self) {
-        self.is_bound_default = true;
-    }
-
-    pub fn set_is_bound_clone(&mut self) {
-        self.is_bound_clone = true;
-    }
 }
 
 // This function generates a series of trait implementations for structs.
@@ -251,7 +539,6 @@ fn impl_cached_component_data_struct(
 
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
     let iter = &ast.generics.params.iter();
-    let iter_where = where_clause.iter();
     let mut contains_lifetime_bound = false;
 
     let mut type_info_map: HashMap<Ident, GenericTypeInfo> = HashMap::new();
@@ -274,57 +561,14 @@ fn impl_cached_component_data_struct(
                     .get_mut(&type_param.ident)
                     .unwrap()
                     .set_contains_type_bound();
-                type_param.bounds.iter().for_each(|bound| {
-                    if let syn::TypeParamBound::Trait(trait_bound) = bound {
-                        if trait_bound.path.is_ident("Default") {
-                            type_info_map
-                                .get_mut(&type_param.ident)
-                                .unwrap()
-                                .set_is_bound_default();
-                        } else if trait_bound.path.is_ident("Clone") {
-                            type_info_map
-                                .get_mut(&type_param.ident)
-                                .unwrap()
-                                .set_is_bound_clone();
-                        }
-                    }
-                });
             }
         } else if let syn::GenericParam::Lifetime(_) = param {
             contains_lifetime_bound = true;
         }
     });
 
-    iter_where.for_each(|where_clause| {
-        for predicate in where_clause.predicates.iter() {
-            if let syn::WherePredicate::Type(predicate_type) = predicate {
-                // Extract the Ident from the bounded type
-                if let Type::Path(type_path) = &predicate_type.bounded_ty {
-                    if let Some(segment) = type_path.path.segments.first() {
-                        let type_ident = &segment.ident;
-                        for bound in predicate_type.bounds.iter() {
-                            if let syn::TypeParamBound::Trait(trait_bound) = bound {
-                                if trait_bound.path.is_ident("Default") {
-                                    if let Some(gtype_info) = type_info_map.get_mut(type_ident) {
-                                        gtype_info.set_is_bound_default();
-                                    }
-                                } else if trait_bound.path.is_ident("Clone") {
-                                    if let Some(gtype_info) = type_info_map.get_mut(type_ident) {
-                                        gtype_info.set_is_bound_clone();
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    });
-
     let mut contains_any_type_bound = false;
     let mut contains_any_generic_type = false;
-    let mut contains_all_default_bound = true;
-    let mut contains_all_clone_bound = true;
 
     type_info_map.iter().for_each(|(_, type_info)| {
         if type_info.contains_type_bound {
@@ -333,8 +577,6 @@ fn impl_cached_component_data_struct(
         if type_info.contains_generic_type {
             contains_any_generic_type = true;
         }
-        contains_all_default_bound = contains_all_default_bound && type_info.is_bound_default;
-        contains_all_clone_bound = contains_all_clone_bound && type_info.is_bound_clone;
     });
 
     let mut contains_where_bound = false;
@@ -392,62 +634,26 @@ fn impl_cached_component_data_struct(
                 }
             }
         }
-    // } else if contains_any_generic_type && contains_all_default_bound && contains_all_clone_bound {
-    //     quote! {
-    //     fn __register_default_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
-    //         use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
-    //         const IMPLS_DEFAULT: bool =  #name::#type_generics::IMPLS_DEFAULT;
-
-    //         if IMPLS_DEFAULT {
-    //             flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions::<<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,#name #type_generics>as flecs_ecs::core::component_registration::registration_traits::FlecsDefaultType> ::Type, >(type_hooks);
-    //         }
-    //     }
-
-    //     fn __register_clone_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
-    //         use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
-    //         const IMPLS_CLONE: bool = #name::#type_generics::IMPLS_CLONE;
-
-    //         if IMPLS_CLONE {
-    //             flecs_ecs::core::lifecycle_traits::register_copy_lifecycle_action:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_CLONE,#name #type_generics>as flecs_ecs::core::component_registration::registration_traits::FlecsCloneType> ::Type, >(type_hooks);
-    //         } else {
-    //             flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<#name #type_generics>(
-    //                 type_hooks,
-    //             );
-    //         }
-    //     }
-    //     }
-    // } else if contains_any_generic_type && contains_all_default_bound {
-    //     quote! {
-    //         fn __register_default_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
-    //             use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
-    //             const IMPLS_DEFAULT: bool =  #name::#type_generics::IMPLS_DEFAULT;
-
-    //             if IMPLS_DEFAULT {
-    //                 flecs_ecs::core::lifecycle_traits::register_ctor_lifecycle_actions::<<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_DEFAULT,#name #type_generics>as flecs_ecs::core::component_registration::registration_traits::FlecsDefaultType> ::Type, >(type_hooks);
-    //             }
-    //         }
-    //     }
-    // } else if contains_any_generic_type && contains_all_clone_bound {
-    //     quote! {
-    //         fn __register_clone_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
-    //             use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
-    //             const IMPLS_CLONE: bool = #name::#type_generics::IMPLS_CLONE;
-
-    //             if IMPLS_CLONE {
-    //                 flecs_ecs::core::lifecycle_traits::register_copy_lifecycle_action:: <<flecs_ecs::core::component_registration::registration_types::ConditionalTypeSelector<IMPLS_CLONE,#name #type_generics>as flecs_ecs::core::component_registration::registration_traits::FlecsCloneType> ::Type, >(type_hooks);
-    //             } else {
-    //                 flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<#name #type_generics>(
-    //                     type_hooks,
-    //                 );
-    //             }
-    //         }
-    //     }
     } else {
+        // The hooks of generic components are registered per instantiation, for the instantiations
+        // of which the impl can prove that they implement `Default` or `Clone`.
         quote! {
+            fn __register_default_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
+                use flecs_ecs::core::utility::traits::DoesNotImpl;
+                flecs_ecs::core::utility::types::ImplementsDefault::<Self>::register_hooks(type_hooks);
+            }
+
             fn __register_clone_hooks(type_hooks: &mut flecs_ecs::sys::ecs_type_hooks_t) {
-                    flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<#name #type_generics>(
+                use flecs_ecs::core::component_registration::registration_traits::ComponentInfo;
+                use flecs_ecs::core::utility::traits::DoesNotImpl;
+
+                if <Self as ComponentInfo>::IMPLS_CLONE {
+                    flecs_ecs::core::utility::types::ImplementsClone::<Self>::register_hooks(type_hooks);
+                } else {
+                    flecs_ecs::core::lifecycle_traits::register_copy_panic_lifecycle_action::<Self>(
                         type_hooks,
                     );
+                }
             }
         }
     };
@@ -475,41 +681,15 @@ fn impl_cached_component_data_struct(
             const IS_GENERIC: bool = true;
         }
     };
-    let clone_default = if !is_generic
-        || (contains_lifetime_bound && !contains_any_generic_type)
-        || (contains_any_generic_type && contains_all_default_bound && contains_all_clone_bound)
-    {
-        quote! {
-            const IMPLS_CLONE: bool = {
-                use flecs_ecs::core::utility::traits::DoesNotImpl;
-                flecs_ecs::core::utility::types::ImplementsClone::<#name #type_generics>::IMPLS
-            };
-            const IMPLS_DEFAULT: bool = {
-                use flecs_ecs::core::utility::traits::DoesNotImpl;
-                flecs_ecs::core::utility::types::ImplementsDefault::<#name #type_generics>::IMPLS
-            };
-        }
-    } else if contains_any_generic_type && contains_all_default_bound {
-        quote! {
-            const IMPLS_CLONE: bool = false;
-            const IMPLS_DEFAULT: bool = {
-                use flecs_ecs::core::utility::traits::DoesNotImpl;
-                flecs_ecs::core::utility::types::ImplementsDefault::<#name #type_generics>::IMPLS
-            };
-        }
-    } else if contains_any_generic_type && contains_all_clone_bound {
-        quote! {
-            const IMPLS_CLONE: bool = {
-                use flecs_ecs::core::utility::traits::DoesNotImpl;
-                flecs_ecs::core::utility::types::ImplementsClone::<#name #type_generics>::IMPLS
-            };
-            const IMPLS_DEFAULT: bool = false;
-        }
-    } else {
-        quote! {
-            const IMPLS_CLONE: bool = false;
-            const IMPLS_DEFAULT: bool = false;
-        }
+    let clone_default = quote! {
+        const IMPLS_CLONE: bool = {
+            use flecs_ecs::core::utility::traits::DoesNotImpl;
+            flecs_ecs::core::utility::types::ImplementsClone::<#name #type_generics>::IMPLS
+        };
+        const IMPLS_DEFAULT: bool = {
+            use flecs_ecs::core::utility::traits::DoesNotImpl;
+            flecs_ecs::core::utility::types::ImplementsDefault::<#name #type_generics>::IMPLS
+        };
     };
     // Common trait implementation for ComponentType and ComponentId
     let common_traits = {
@@ -62,6 +62,10 @@ fn generate_bindings() {
         .blocklist_function("ecs_strbuf_vappend")
         .blocklist_function("flecs_vasprintf")
         .layout_tests(false)
+        // The abort hook may panic with the `abort_panic` feature.
+        .override_abi(bindgen::Abi::CUnwind, "ecs_os_api_abort_t")
+        // Module actions are declared in `mbindings.rs`, with the ABI of `extern_flecs!`.
+        .blocklist_type("ecs_module_action_t")
         .raw_line("#![allow(clippy::all)]")
         .raw_line("#![allow(warnings)]")
         .raw_line("use super::*;")
@@ -184,9 +188,12 @@ fn generate_bindings() {
     let bindings = bindings.generate().expect("Unable to generate bindings");
 
     let crate_root: PathBuf = env::var("CARGO_MANIFEST_DIR").unwrap().into();
-    bindings
-        .write_to_file(crate_root.join("src/bindings.rs"))
-        .unwrap();
+    // Function declarations go through `extern_flecs!`, which picks the ABI
+    // depending on the `abort_panic` feature.
+    let bindings = bindings
+        .to_string()
+        .replace("\nextern \"C\" {\n", "\nextern_flecs! {\n");
+    std::fs::write(crate_root.join("src/bindings.rs"), bindings).unwrap();
 }
 
 fn main() {
@@ -208,6 +215,11 @@ fn main() {
             .define("FLECS_CUSTOM_BUILD", None)
             .define("FLECS_CPP", None);
 
+        // Allow Rust panics raised by the abort hook of the OS API to unwind
+        // through the C library.
+        #[cfg(feature = "abort_panic")]
+        build.flag_if_supported("-fexceptions");
+
         #[cfg(feature = "flecs_module")]
         build.define("FLECS_MODULE", None);
 
@@ -259,6 +271,10 @@ fn main() {
         #[cfg(feature = "flecs_os_api_impl")]
         build.define("FLECS_OS_API_IMPL", None);
 
+        // addons such as http enable the C implementation unless told otherwise
+        #[cfg(all(feature = "flecs_os_api_rust", not(feature = "flecs_os_api_impl")))]
+        build.define("FLECS_NO_OS_API_IMPL", None);
+
         #[cfg(feature = "flecs_http")]
         build.define("FLECS_HTTP", None);
 
@@ -11,6 +11,18 @@ use crate::{ecs_system_desc_t, EcsTickSource};
 #[cfg(feature = "flecs_pipeline")]
 use crate::ecs_pipeline_desc_t;
 
+#[cfg(feature = "flecs_metrics")]
+use crate::ecs_metric_desc_t;
+
+#[cfg(feature = "flecs_alerts")]
+use crate::{ecs_alert_desc_t, ecs_alert_severity_filter_t};
+
+#[cfg(feature = "flecs_script")]
+use crate::ecs_script_expr_run_desc_t;
+
+#[cfg(feature = "flecs_http")]
+use crate::{ecs_http_reply_t, ecs_http_server_desc_t, ecs_strbuf_list_elem, ecs_strbuf_t};
+
 impl Default for ecs_type_t {
     fn default() -> Self {
         Self {
@@ -181,6 +193,120 @@ impl Default for ecs_pipeline_desc_t {
     }
 }
 
+#[cfg(feature = "flecs_metrics")]
+impl Default for ecs_metric_desc_t {
+    fn default() -> Self {
+        Self {
+            _canary: Default::default(),
+            entity: Default::default(),
+            member: Default::default(),
+            dotmember: core::ptr::null(),
+            id: Default::default(),
+            targets: Default::default(),
+            kind: Default::default(),
+            brief: core::ptr::null(),
+        }
+    }
+}
+
+#[cfg(feature = "flecs_alerts")]
+impl Default for ecs_alert_severity_filter_t {
+    fn default() -> Self {
+        Self {
+            severity: Default::default(),
+            with: Default::default(),
+            var: core::ptr::null(),
+            _var_index: Default::default(),
+        }
+    }
+}
+
+#[cfg(feature = "flecs_alerts")]
+impl Default for ecs_alert_desc_t {
+    fn default() -> Self {
+        Self {
+            _canary: Default::default(),
+            entity: Default::default(),
+            filter: Default::default(),
+            message: core::ptr::null(),
+            doc_name: core::ptr::null(),
+            brief: core::ptr::null(),
+            severity: Default::default(),
+            severity_filters: Default::default(),
+            retain_period: Default::default(),
+            member: Default::default(),
+            id: Default::default(),
+            var: core::ptr::null(),
+        }
+    }
+}
+
+#[cfg(feature = "flecs_http")]
+impl Default for ecs_strbuf_list_elem {
+    fn default() -> Self {
+        Self {
+            count: Default::default(),
+            separator: core::ptr::null(),
+        }
+    }
+}
+
+#[cfg(feature = "flecs_http")]
+impl Default for ecs_strbuf_t {
+    fn default() -> Self {
+        Self {
+            content: core::ptr::null_mut(),
+            length: Default::default(),
+            size: Default::default(),
+            list_stack: Default::default(),
+            list_sp: Default::default(),
+            small_string: [0; 512],
+        }
+    }
+}
+
+#[cfg(feature = "flecs_http")]
+impl Default for ecs_http_reply_t {
+    fn default() -> Self {
+        // same as ECS_HTTP_REPLY_INIT
+        Self {
+            code: 200,
+            body: Default::default(),
+            status: c"OK".as_ptr(),
+            content_type: c"application/json".as_ptr(),
+            headers: Default::default(),
+        }
+    }
+}
+
+#[cfg(feature = "flecs_http")]
+impl Default for ecs_http_server_desc_t {
+    fn default() -> Self {
+        Self {
+            callback: None,
+            ctx: core::ptr::null_mut(),
+            port: Default::default(),
+            ipaddr: core::ptr::null(),
+            send_queue_wait_ms: Default::default(),
+            cache_timeout: Default::default(),
+            cache_purge_timeout: Default::default(),
+        }
+    }
+}
+
+#[cfg(feature = "flecs_script")]
+impl Default for ecs_script_expr_run_desc_t {
+    fn default() -> Self {
+        Self {
+            name: core::ptr::null(),
+            expr: core::ptr::null(),
+            lookup_action: None,
+            lookup_ctx: core::ptr::null_mut(),
+            vars: core::ptr::null_mut(),
+        }
+    }
+}
+
 #[cfg(feature = "flecs_app")]
 impl Default for ecs_app_desc_t {
     fn default() -> Self {
@@ -301,3 +427,12 @@ unsafe impl Sync for crate::EcsIdentifier {}
 unsafe impl Send for crate::EcsIdentifier {}
 unsafe impl Send for crate::EcsPoly {}
 unsafe impl Sync for crate::EcsPoly {}
+
+#[cfg(feature = "flecs_alerts")]
+unsafe impl Send for crate::EcsAlertInstance {}
+#[cfg(feature = "flecs_alerts")]
+unsafe impl Sync for crate::EcsAlertInstance {}
+#[cfg(feature = "flecs_alerts")]
+unsafe impl Send for crate::EcsAlertsActive {}
+#[cfg(feature = "flecs_alerts")]
+unsafe impl Sync for crate::EcsAlertsActive {}
@@ -114,3 +114,358 @@ error:
     return -1;
 }
 
+#ifdef FLECS_SCRIPT
+/* The expression serializer of flecs serializes opaque types as primitives,
+ * which fails. The functions below walk the serializer ops of a type like
+ * ecs_ptr_to_expr, forward opaque types to their serialize callback and leave
+ * all other values to ecs_ptr_to_expr_buf. */
+
+static
+int flecs_rust_expr_ser_ops(
+    const ecs_world_t *world,
+    ecs_meta_type_op_t *ops,
+    int32_t op_count,
+    const void *base,
+    ecs_strbuf_t *str,
+    int32_t in_array);
+
+/* Serialize elements of a contiguous array */
+static
+int flecs_rust_expr_ser_elements(
+    const ecs_world_t *world,
+    ecs_meta_type_op_t *ops,
+    int32_t op_count,
+    const void *base,
+    int32_t elem_count,
+    int32_t elem_size,
+    ecs_strbuf_t *str)
+{
+    ecs_strbuf_list_push(str, "[", ", ");
+
+    const void *ptr = base;
+    int32_t i;
+    for (i = 0; i < elem_count; i ++) {
+        ecs_strbuf_list_next(str);
+        if (flecs_rust_expr_ser_ops(world, ops, op_count, ptr, str, 1)) {
+            return -1;
+        }
+        ptr = ECS_OFFSET(ptr, elem_size);
+    }
+
+    ecs_strbuf_list_pop(str, "]");
+    return 0;
+}
+
+static
+int flecs_rust_expr_ser_type_elements(
+    const ecs_world_t *world,
+    ecs_entity_t type,
+    const void *base,
+    int32_t elem_count,
+    ecs_strbuf_t *str)
+{
+    const EcsTypeSerializer *ser = ecs_get(world, type, EcsTypeSerializer);
+    ecs_assert(ser != NULL, ECS_INTERNAL_ERROR, NULL);
+
+    const EcsComponent *comp = ecs_get(world, type, EcsComponent);
+    ecs_assert(comp != NULL, ECS_INTERNAL_ERROR, NULL);
+
+    ecs_meta_type_op_t *ops = ecs_vec_first_t(&ser->ops, ecs_meta_type_op_t);
+    int32_t op_count = ecs_vec_count(&ser->ops);
+    return flecs_rust_expr_ser_elements(
+        world, ops, op_count, base, elem_count, comp->size, str);
+}
+
+typedef struct flecs_rust_expr_opaque_ctx_t {
+    ecs_strbuf_t *str;
+    bool is_collection;
+    bool is_struct;
+} flecs_rust_expr_opaque_ctx_t;
+
+static
+int flecs_rust_expr_ser_opaque_value(
+    const ecs_serializer_t *ser,
+    ecs_entity_t type,
+    const void *value)
+{
+    flecs_rust_expr_opaque_ctx_t *ctx = ser->ctx;
+    if (ctx->is_collection) {
+        ecs_strbuf_list_next(ctx->str);
+    }
+
+    return ecs_rust_ptr_to_expr_buf(ser->world, type, value, ctx->str);
+}
+
+static
+int flecs_rust_expr_ser_opaque_member(
+    const ecs_serializer_t *ser,
+    const char *name)
+{
+    flecs_rust_expr_opaque_ctx_t *ctx = ser->ctx;
+    if (!ctx->is_struct) {
+        ecs_err("serializer::member can only be called for structs");
+        return -1;
+    }
+    ecs_strbuf_list_next(ctx->str);
+    ecs_strbuf_append(ctx->str, "%s: ", name);
+    return 0;
+}
+
+/* Serialize opaque type */
+static
+int flecs_rust_expr_ser_opaque(
+    const ecs_world_t *world,
+    ecs_meta_type_op_t *op,
+    const void *base,
+    ecs_strbuf_t *str)
+{
+    const EcsOpaque *ct = ecs_get(world, op->type, EcsOpaque);
+    ecs_assert(ct != NULL, ECS_INVALID_OPERATION,
+        "entity %s in opaque type serializer instruction is not an opaque type",
+            ecs_get_name(world, op->type));
+    ecs_assert(ct->serialize != NULL, ECS_INVALID_OPERATION,
+        "opaque type %s does not have serialize interface",
+            ecs_get_name(world, op->type));
+
+    const EcsType *pt = ecs_get(world, ct->as_type, EcsType);
+    ecs_assert(pt != NULL, ECS_INVALID_OPERATION,
+        "opaque type %s is missing flecs.meta.Type component",
+            ecs_get_name(world, op->type));
+
+    ecs_type_kind_t kind = pt->kind;
+    bool is_collection = kind == EcsArrayType || kind == EcsVectorType;
+    bool is_struct = kind == EcsStructType;
+
+    if (is_struct) {
+        ecs_strbuf_list_push(str, "{", ", ");
+    } else if (is_collection) {
+        ecs_strbuf_list_push(str, "[", ", ");
+    }
+
+    flecs_rust_expr_opaque_ctx_t ctx = {
+        .str = str,
+        .is_collection = is_collection,
+        .is_struct = is_struct
+    };
+
+    ecs_serializer_t ser = {
+        .world = world,
+        .value = flecs_rust_expr_ser_opaque_value,
+        .member = flecs_rust_expr_ser_opaque_member,
+        .ctx = &ctx
+    };
+
+    if (ct->serialize(&ser, base)) {
+        return -1;
+    }
+
+    if (is_struct) {
+        ecs_strbuf_list_pop(str, "}");
+    } else if (is_collection) {
+        ecs_strbuf_list_pop(str, "]");
+    }
+
+    return 0;
+}
+
+/* Serialize a single op. Values that can't contain opaque types are left to
+ * the serializer of flecs. */
+static
+int flecs_rust_expr_ser_op(
+    const ecs_world_t *world,
+    ecs_meta_type_op_t *op,
+    const void *ptr,
+    ecs_strbuf_t *str)
+{
+    ptr = ECS_OFFSET(ptr, op->offset);
+
+    switch(op->kind) {
+    case EcsOpArray: {
+        const EcsArray *a = ecs_get(world, op->type, EcsArray);
+        ecs_assert(a != NULL, ECS_INTERNAL_ERROR, NULL);
+        return flecs_rust_expr_ser_type_elements(
+            world, a->type, ptr, a->count, str);
+    }
+    case EcsOpVector: {
+        const ecs_vec_t *value = ptr;
+        const EcsVector *v = ecs_get(world, op->type, EcsVector);
+        ecs_assert(v != NULL, ECS_INTERNAL_ERROR, NULL);
+        return flecs_rust_expr_ser_type_elements(world, v->type,
+            ecs_vec_first(value), ecs_vec_count(value), str);
+    }
+    case EcsOpOpaque:
+        return flecs_rust_expr_ser_opaque(world, op, ptr, str);
+    default:
+        return ecs_ptr_to_expr_buf(world, op->type, ptr, str);
+    }
+}
+
+/* Iterate over a slice of the type ops array */
+static
+int flecs_rust_expr_ser_ops(
+    const ecs_world_t *world,
+    ecs_meta_type_op_t *ops,
+    int32_t op_count,
+    const void *base,
+    ecs_strbuf_t *str,
+    int32_t in_array)
+{
+    int32_t i;
+    for (i = 0; i < op_count; i ++) {
+        ecs_meta_type_op_t *op = &ops[i];
+
+        if (in_array <= 0) {
+            if (op->name) {
+                ecs_strbuf_list_next(str);
+                ecs_strbuf_append(str, "%s: ", op->name);
+            }
+
+            if (op->count > 1) {
+                /* Serialize inline array */
+                if (flecs_rust_expr_ser_elements(world, op, op->op_count,
+                    base, op->count, op->size, str))
+                {
+                    return -1;
+                }
+
+                i += op->op_count - 1;
+                continue;
+            }
+        }
+
+        switch(op->kind) {
+        case EcsOpPush:
+            ecs_strbuf_list_push(str, "{", ", ");
+            in_array --;
+            break;
+        case EcsOpPop:
+            ecs_strbuf_list_pop(str, "}");
+            in_array ++;
+            break;
+        default:
+            if (flecs_rust_expr_ser_op(world, op, base, str)) {
+                return -1;
+            }
+            break;
+        }
+    }
+
+    return 0;
+}
+
+int ecs_rust_ptr_to_expr_buf(
+    const ecs_world_t *world,
+    ecs_entity_t type,
+    const void *ptr,
+    ecs_strbuf_t *buf_out)
+{
+    const EcsTypeSerializer *ser = ecs_get(world, type, EcsTypeSerializer);
+    if (ser == NULL) {
+        char *path = ecs_get_path(world, type);
+        ecs_err("cannot serialize value for type '%s'", path);
+        ecs_os_free(path);
+        return -1;
+    }
+
+    ecs_meta_type_op_t *ops = ecs_vec_first_t(&ser->ops, ecs_meta_type_op_t);
+    int32_t op_count = ecs_vec_count(&ser->ops);
+    return flecs_rust_expr_ser_ops(world, ops, op_count, ptr, buf_out, 0);
+}
+
+char* ecs_rust_ptr_to_expr(
+    const ecs_world_t *world,
+    ecs_entity_t type,
+    const void *ptr)
+{
+    ecs_strbuf_t str = ECS_STRBUF_INIT;
+
+    if (ecs_rust_ptr_to_expr_buf(world, type, ptr, &str) != 0) {
+        ecs_strbuf_reset(&str);
+        return NULL;
+    }
+
+    return ecs_strbuf_get(&str);
+}
+
+int ecs_rust_script_eval(
+    ecs_script_t *script,
+    ecs_script_vars_t *vars)
+{
+    ecs_script_eval_visitor_t v;
+    ecs_script_impl_t *impl = flecs_script_impl(script);
+    flecs_script_eval_visit_init(impl, &v);
+
+    if (vars) {
+        v.vars = flecs_script_vars_push(NULL, &v.stack, v.allocator);
+        v.vars->parent = vars;
+        v.vars->world = vars->world;
+    }
+
+    int result = ecs_script_visit(impl, &v, flecs_script_eval_node);
+
+    if (vars) {
+        ecs_script_vars_pop(v.vars);
+    }
+
+    flecs_script_eval_visit_fini(&v);
+    return result;
+}
+
+int ecs_rust_script_update(
+    ecs_world_t *world,
+    ecs_entity_t e,
+    ecs_entity_t instance,
+    const char *code,
+    ecs_script_vars_t *vars)
+{
+    ecs_assert(world != NULL, ECS_INTERNAL_ERROR, NULL);
+    ecs_assert(code != NULL, ECS_INTERNAL_ERROR, NULL);
+
+    const char *name = ecs_get_name(world, e);
+    EcsScript *s = ecs_ensure(world, e, EcsScript);
+    if (s->template_) {
+        char *template_name = ecs_get_path(world, s->template_->entity);
+        ecs_err("cannot update scripts for individual templates, "
+            "update parent script instead (tried to update '%s')",
+                template_name);
+        ecs_os_free(template_name);
+        return -1;
+    }
+
+    if (s->script) {
+        ecs_script_free(s->script);
+    }
+
+    s->script = ecs_script_parse(world, name, code);
+    if (!s->script) {
+        return -1;
+    }
+
+    int result = 0;
+    bool is_defer = ecs_is_deferred(world);
+    ecs_suspend_readonly_state_t srs;
+    ecs_world_t *real_world = NULL;
+    if (is_defer) {
+        ecs_assert(flecs_poly_is(world, ecs_world_t), ECS_INTERNAL_ERROR, NULL);
+        real_world = flecs_suspend_readonly(world, &srs);
+        ecs_assert(real_world != NULL, ECS_INTERNAL_ERROR, NULL);
+    }
+
+    ecs_script_clear(world, e, instance);
+
+    ecs_entity_t prev = ecs_set_with(world, flecs_script_tag(e, instance));
+
+    if (ecs_rust_script_eval(s->script, vars)) {
+        ecs_delete_with(world, ecs_pair_t(EcsScript, e));
+        result = -1;
+    }
+
+    ecs_set_with(world, prev);
+
+    if (is_defer) {
+        flecs_resume_readonly(real_world, &srs);
+    }
+
+    return result;
+}
+#endif
@@ -22,3 +22,39 @@ FLECS_API
     ecs_id_t id,
     ecs_table_t* table);
 
+
+#ifdef FLECS_SCRIPT
+/* Same as ecs_ptr_to_expr, but also serializes opaque types, which the
+ * expression serializer of flecs doesn't support. */
+FLECS_API
+char* ecs_rust_ptr_to_expr(
+    const ecs_world_t *world,
+    ecs_entity_t type,
+    const void *ptr);
+
+/* Same as ecs_rust_ptr_to_expr, but serializes to an ecs_strbuf_t instance. */
+FLECS_API
+int ecs_rust_ptr_to_expr_buf(
+    const ecs_world_t *world,
+    ecs_entity_t type,
+    const void *ptr,
+    ecs_strbuf_t *buf_out);
+
+/* Same as ecs_script_eval, but the script can access the provided variables.
+ * The variables may be NULL. */
+FLECS_API
+int ecs_rust_script_eval(
+    ecs_script_t *script,
+    ecs_script_vars_t *vars);
+
+/* Same as ecs_script_update, but evaluates the script with the provided
+ * variables. The variables may be NULL. */
+FLECS_API
+int ecs_rust_script_update(
+    ecs_world_t *world,
+    ecs_entity_t entity,
+    ecs_entity_t instance,
+    const char *code,
+    ecs_script_vars_t *vars);
+#endif
+
@@ -9,6 +9,17 @@ compile_error!(
     "Features 'force_build_release' and 'force_build_debug' cannot be enabled at the same time."
 );
 
+/// Declares functions of the C library. With the `abort_panic` feature, the
+/// abort hook of the OS API may panic, so the functions are declared to unwind.
+macro_rules! extern_flecs {
+    ($($item:tt)*) => {
+        #[cfg(not(feature = "abort_panic"))]
+        extern "C" { $($item)* }
+        #[cfg(feature = "abort_panic")]
+        extern "C-unwind" { $($item)* }
+    };
+}
+
 mod bindings;
 mod extensions;
 mod mbindings;
@@ -5,7 +5,25 @@
 
 use super::*;
 
-extern "C" {
+/// Function pointer type of the `next` functions of iterators, such as
+/// [`ecs_query_next`].
+#[cfg(not(feature = "abort_panic"))]
+pub type ecs_iter_next_fn_t = unsafe extern "C" fn(*mut ecs_iter_t) -> bool;
+/// Function pointer type of the `next` functions of iterators, such as
+/// [`ecs_query_next`].
+#[cfg(feature = "abort_panic")]
+pub type ecs_iter_next_fn_t = unsafe extern "C-unwind" fn(*mut ecs_iter_t) -> bool;
+
+/// Initialization action for modules, such as `FlecsStatsImport`.
+#[cfg(not(feature = "abort_panic"))]
+pub type ecs_module_action_t =
+    ::core::option::Option<unsafe extern "C" fn(world: *mut ecs_world_t)>;
+/// Initialization action for modules, such as `FlecsStatsImport`.
+#[cfg(feature = "abort_panic")]
+pub type ecs_module_action_t =
+    ::core::option::Option<unsafe extern "C-unwind" fn(world: *mut ecs_world_t)>;
+
+extern_flecs! {
     pub fn ecs_rust_mut_get_id(
         world: *const ecs_world_t,
         entity: ecs_entity_t,
@@ -14,7 +32,7 @@ extern "C" {
         id: ecs_id_t,
     ) -> *mut ::core::ffi::c_void;
 }
-extern "C" {
+extern_flecs! {
     pub fn ecs_rust_get_id(
         world: *const ecs_world_t,
         entity: ecs_entity_t,
@@ -23,13 +41,53 @@ extern "C" {
         id: ecs_id_t,
     ) -> *mut ::core::ffi::c_void;
 }
-extern "C" {
+extern_flecs! {
     pub fn ecs_rust_rel_count(
         world: *const ecs_world_t,
         id: ecs_id_t,
         table: *mut ecs_table_t,
     ) -> i32;
 }
+extern_flecs! {
+    /// Same as `ecs_ptr_to_expr`, but also serializes opaque types.
+    #[cfg(feature = "flecs_script")]
+    pub fn ecs_rust_ptr_to_expr(
+        world: *const ecs_world_t,
+        type_: ecs_entity_t,
+        ptr: *const ::core::ffi::c_void,
+    ) -> *mut ::core::ffi::c_char;
+}
+extern_flecs! {
+    /// Same as `ecs_rust_ptr_to_expr`, but serializes to an `ecs_strbuf_t` instance.
+    #[cfg(feature = "flecs_script")]
+    pub fn ecs_rust_ptr_to_expr_buf(
+        world: *const ecs_world_t,
+        type_: ecs_entity_t,
+        ptr: *const ::core::ffi::c_void,
+        buf_out: *mut ecs_strbuf_t,
+    ) -> ::core::ffi::c_int;
+}
+extern_flecs! {
+    /// Same as `ecs_script_eval`, but the script can access the provided variables.
+    /// The variables may be null.
+    #[cfg(feature = "flecs_script")]
+    pub fn ecs_rust_script_eval(
+        script: *mut ecs_script_t,
+        vars: *mut ecs_script_vars_t,
+    ) -> ::core::ffi::c_int;
+}
+extern_flecs! {
+    /// Same as `ecs_script_update`, but evaluates the script with the provided variables.
+    /// The variables may be null.
+    #[cfg(feature = "flecs_script")]
+    pub fn ecs_rust_script_update(
+        world: *mut ecs_world_t,
+        entity: ecs_entity_t,
+        instance: ecs_entity_t,
+        code: *const ::core::ffi::c_char,
+        vars: *mut ecs_script_vars_t,
+    ) -> ::core::ffi::c_int;
+}
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -69,7 +127,7 @@ pub struct ecs_alert_desc_t {
     pub var: *const ::core::ffi::c_char,
 }
 
-extern "C" {
+extern_flecs! {
     /// Enable/disable logging time since last log.
     ///
     /// By default, deltatime is disabled. Note that enabling timestamps introduces
@@ -93,7 +151,7 @@ extern "C" {
     pub fn ecs_log_enable_timedelta(enabled: bool) -> bool;
 }
 
-extern "C" {
+extern_flecs! {
     /// Emulate a request.
     ///
     /// The request string must be a valid HTTP request. A minimal example:
@@ -219,7 +277,7 @@ pub struct WorldInfoCmd {
     pub batched_command_count: i64,
 }
 
-extern "C" {
+extern_flecs! {
     #[doc = "Get world info.\n\n @param world The world.\n @return Pointer to the world info. Valid for as long as the world exists."]
     pub fn ecs_get_world_info(world: *const ecs_world_t) -> *const WorldInfo;
 }